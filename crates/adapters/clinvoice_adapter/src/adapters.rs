@@ -17,6 +17,11 @@ pub enum Adapters
 	/// A bincode filesystem.
 	Bincode,
 
+	/// # Summary
+	///
+	/// An append-only journal of events, which is replayed to determine the contents of a store.
+	Journal,
+
 	/// # Summary
 	///
 	/// A bincode filesystem.
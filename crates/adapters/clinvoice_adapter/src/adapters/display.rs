@@ -12,6 +12,7 @@ impl Display for Adapters
 		write!(formatter, "{}", match self
 		{
 			Adapters::Bincode => "Bincode",
+			Adapters::Journal => "Journal",
 			Adapters::Postgres => "Postgres",
 		})
 	}
@@ -32,6 +33,7 @@ mod tests
 	{
 		let start = Instant::now();
 		assert_eq!(format!("{}", Adapters::Bincode), "Bincode");
+		assert_eq!(format!("{}", Adapters::Journal), "Journal");
 		println!("\n>>>>> Adapters::fmt {}us <<<<<\n", Instant::now().duration_since(start).as_micros());
 	}
 }
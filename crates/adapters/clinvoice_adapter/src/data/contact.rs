@@ -40,7 +40,10 @@ where
 		},
 
 		Contact::Email {email, export} => ContactView::Email {email, export},
+		Contact::Fax {fax, export} => ContactView::Fax {fax, export},
+		Contact::Messaging {handle, service, export} => ContactView::Messaging {handle, service, export},
 		Contact::Phone {phone, export} => ContactView::Phone {phone, export},
+		Contact::Website {url, export} => ContactView::Website {url, export},
 	})
 }
 
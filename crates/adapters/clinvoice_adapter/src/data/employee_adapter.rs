@@ -68,6 +68,8 @@ pub trait EmployeeAdapter :
 		Ok(EmployeeView
 		{
 			contact_info: contact_info_view,
+			employment_changes: employee.employment_changes,
+			hourly_rate: employee.hourly_rate,
 			id: employee.id,
 			organization: organization_view,
 			person: person_view,
@@ -2,15 +2,15 @@
 
 use
 {
-	std::{borrow::Cow::Borrowed, error::Error},
+	std::{borrow::Cow::{Borrowed, Owned}, error::Error, path::Path},
 
-	super::{Deletable, EmployeeAdapter, Initializable, LocationAdapter, OrganizationAdapter, PersonAdapter, timesheet, Updatable},
+	super::{Deletable, EmployeeAdapter, Initializable, LocationAdapter, OrganizationAdapter, PersonAdapter, Sequence, timesheet, Updatable},
 	crate::Store,
 
 	clinvoice_data::
 	{
 		chrono::{DateTime, Utc},
-		Job, finance::Money, Organization,
+		Attachment, BillingModel, Id, Job, Organization,
 		views::{JobView, TimesheetView},
 	},
 	clinvoice_query as query,
@@ -23,6 +23,21 @@ pub trait JobAdapter :
 {
 	type Error : From<super::Error> + Error;
 
+	/// # Summary
+	///
+	/// Copy the file at some `path` into the attachments of the `store`, so that it may be kept
+	/// alongside an [`Expense`](clinvoice_data::Expense).
+	///
+	/// # Remarks
+	///
+	/// Attachments are identified by their contents, so a file which has already been attached is
+	/// not copied again.
+	///
+	/// # Returns
+	///
+	/// The [`Attachment`] which refers to the copy of the file.
+	fn attach(path: &Path, store: &Store) -> Result<Attachment, <Self as JobAdapter>::Error>;
+
 	/// # Summary
 	///
 	/// Create a new [`Person`] on the active [`Store`](crate::Store).
@@ -37,7 +52,7 @@ pub trait JobAdapter :
 	fn create(
 		client: Organization,
 		date_open: DateTime<Utc>,
-		hourly_rate: Money,
+		billing_model: BillingModel,
 		objectives: String,
 		store: &Store,
 	) -> Result<Job, <Self as JobAdapter>::Error>;
@@ -72,8 +87,11 @@ pub trait JobAdapter :
 
 				v.push(TimesheetView
 				{
+					billable: t.billable,
 					employee: employee_view,
 					expenses: t.expenses,
+					hourly_rate: t.hourly_rate,
+					invoice_id: t.invoice_id,
 					time_begin: t.time_begin,
 					time_end: t.time_end,
 					work_notes: t.work_notes,
@@ -85,17 +103,86 @@ pub trait JobAdapter :
 
 		Ok(JobView
 		{
+			adjustments: job.adjustments,
+			bill_to: job.bill_to,
+			billing_model: job.billing_model,
 			client: organization_view,
 			date_close: job.date_close,
 			date_open: job.date_open,
+			employee_default_rates: job.employee_default_rates,
+			employee_rates: job.employee_rates,
+			expense_markup: job.expense_markup,
 			id: job.id,
-			invoice: job.invoice,
+			invoices: job.invoices,
 			notes: job.notes,
 			objectives: job.objectives,
+			overtime: job.overtime,
+			rate_changes: job.rate_changes,
 			timesheets: timesheet_views,
 		})
 	}
 
+	/// # Summary
+	///
+	/// Take the position of the next [`CreditNote`](clinvoice_data::CreditNote) to be issued in
+	/// the sequence of [`CreditNote`](clinvoice_data::CreditNote) numbers of the `store`.
+	///
+	/// # Parameters
+	///
+	/// * `client_id`, if the sequence is only of the [`Job`]s for some client.
+	///
+	/// # Remarks
+	///
+	/// See [`JobAdapter::take_seq`].
+	fn next_credit_note_seq(client_id: Option<Id>, store: &Store) -> Result<u64, <Self as JobAdapter>::Error>
+	{
+		let results = Self::retrieve(&Self::client_query(client_id), store)?;
+		let issued = results.iter().flat_map(|j| j.invoices.iter()).map(|i| i.credit_notes.len() as u64).sum();
+		Self::take_seq(Sequence::CreditNotes {client_id}, issued, store)
+	}
+
+	/// # Summary
+	///
+	/// Take the position of the next [`Invoice`](clinvoice_data::Invoice) to be issued in the
+	/// sequence of [`Invoice`](clinvoice_data::Invoice) numbers of the `store`.
+	///
+	/// # Parameters
+	///
+	/// * `client_id`, if the sequence is only of the [`Job`]s for some client.
+	///
+	/// # Remarks
+	///
+	/// See [`JobAdapter::take_seq`].
+	fn next_invoice_seq(client_id: Option<Id>, store: &Store) -> Result<u64, <Self as JobAdapter>::Error>
+	{
+		let results = Self::retrieve(&Self::client_query(client_id), store)?;
+		let issued = results.iter().flat_map(|j| j.invoices.iter()).filter(|i| i.number.is_some()).count() as u64;
+		Self::take_seq(Sequence::Invoices {client_id}, issued, store)
+	}
+
+	/// # Summary
+	///
+	/// A query for every [`Job`] of the client with some `client_id`, or every [`Job`] if there is
+	/// none.
+	fn client_query(client_id: Option<Id>) -> query::Job<'static>
+	{
+		query::Job
+		{
+			client: query::Organization
+			{
+				id: client_id.map(|id| query::Match::EqualTo(Owned(id))).unwrap_or_default(),
+				..Default::default()
+			},
+			..Default::default()
+		}
+	}
+
+	/// # Summary
+	///
+	/// Read the contents of some `attachment` from the `store` it was [attached](JobAdapter::attach)
+	/// to.
+	fn read_attachment(attachment: &Attachment, store: &Store) -> Result<Vec<u8>, <Self as JobAdapter>::Error>;
+
 	/// # Summary
 	///
 	/// Retrieve some [`Person`] from the active [`Store`](crate::Store).
@@ -113,6 +200,21 @@ pub trait JobAdapter :
 		store: &Store,
 	) -> Result<Vec<Job>, <Self as JobAdapter>::Error>;
 
+	/// # Summary
+	///
+	/// Take the next position in some `sequence` of the `store`, and record that it was taken.
+	///
+	/// # Parameters
+	///
+	/// * `issued`, how many positions of the `sequence` are known to have been given out already.
+	///   Stores which have not recorded the `sequence` before continue from here.
+	///
+	/// # Remarks
+	///
+	/// A position is never given out twice, even if whatever it was given to is deleted, or is
+	/// never stored at all.
+	fn take_seq(sequence: Sequence, issued: u64, store: &Store) -> Result<u64, <Self as JobAdapter>::Error>;
+
 	/// # Summary
 	///
 	/// Convert some `employee` into a [`Person`].
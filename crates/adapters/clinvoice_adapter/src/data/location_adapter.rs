@@ -57,16 +57,20 @@ pub trait LocationAdapter :
 
 		Ok(LocationView
 		{
+			address: location.address,
 			id: location.id,
 			name: location.name,
 			outer: outer_locations.into_iter().fold(None,
 				|previous: Option<LocationView>, outer_location| Some(LocationView
 				{
+					address: outer_location.address,
 					id: outer_location.id,
 					name: outer_location.name,
 					outer: previous.map(|l| l.into()),
+					taxes: outer_location.taxes,
 				}),
 			).map(|l| l.into()),
+			taxes: location.taxes,
 		})
 	}
 
@@ -17,6 +17,7 @@ mod job_adapter;
 mod location_adapter;
 mod organization_adapter;
 mod person_adapter;
+mod sequence;
 mod updatable;
 pub mod timesheet;
 
@@ -30,5 +31,6 @@ pub use
 	location_adapter::LocationAdapter,
 	organization_adapter::OrganizationAdapter,
 	person_adapter::PersonAdapter,
+	sequence::Sequence,
 	updatable::Updatable,
 };
@@ -44,9 +44,16 @@ pub trait OrganizationAdapter  :
 
 		Ok(OrganizationView
 		{
+			bill_to: organization.bill_to,
+			expense_markup: organization.expense_markup,
 			id: organization.id,
 			location: location_view_result?,
 			name: organization.name,
+			overtime: organization.overtime,
+			payment_methods: organization.payment_methods,
+			payment_terms: organization.payment_terms,
+			tax_ids: organization.tax_ids,
+			taxes: organization.taxes,
 		})
 	}
 
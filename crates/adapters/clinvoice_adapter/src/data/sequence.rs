@@ -0,0 +1,36 @@
+use clinvoice_data::Id;
+
+#[cfg(feature="serde_support")]
+use serde::{Deserialize, Serialize};
+
+/// # Summary
+///
+/// A sequence of numbers which a [`Store`](crate::Store) gives out, such as the numbers of
+/// [`Invoice`](clinvoice_data::Invoice)s.
+///
+/// # Remarks
+///
+/// Once a position in a [`Sequence`] has been taken, it is never given out again, even if what
+/// it was given to is deleted.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature="serde_support", derive(Deserialize, Serialize))]
+pub enum Sequence
+{
+	/// # Summary
+	///
+	/// The numbers of [`CreditNote`](clinvoice_data::CreditNote)s, for the client with some
+	/// `client_id` (or every client, if [`None`]).
+	CreditNotes
+	{
+		client_id: Option<Id>,
+	},
+
+	/// # Summary
+	///
+	/// The numbers of [`Invoice`](clinvoice_data::Invoice)s, for the client with some `client_id`
+	/// (or every client, if [`None`]).
+	Invoices
+	{
+		client_id: Option<Id>,
+	},
+}
@@ -28,6 +28,13 @@ pub enum Error
 	/// [adapter]: crate::Adapters
 	#[error("Using this adapter requires the {0} feature")]
 	FeatureNotFound(Adapters),
+
+	/// # Summary
+	///
+	/// An operation would have modified a [`Store`](crate::Store) which is marked as
+	/// [`read_only`](crate::Store::read_only).
+	#[error("This operation would modify the store, but the store is read-only")]
+	ReadOnly,
 }
 
 clinvoice_error::AliasResult!();
@@ -13,12 +13,16 @@
 //! 3. Create a new feature flag for the adapter on `clinvoice`.
 //! 4. Write new `match` arms in `clinvoice`'s `Create` and `Retrieve` types for the adapter and
 //!    conditionally compile them based on the feature flag.
+//! 5. Enable the `test-kit` feature as a dev-dependency and instantiate the conformance suite
+//!    with the [`TestKit`] macro.
 
 mod adapters;
 pub mod data;
 mod error;
 mod macros;
 mod store;
+#[cfg(feature="test-kit")]
+pub mod test_kit;
 
 pub use
 {
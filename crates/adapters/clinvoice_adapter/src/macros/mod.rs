@@ -12,3 +12,4 @@ mod adapt_job;
 mod adapt_location;
 mod adapt_organization;
 mod adapt_person;
+mod test_kit;
@@ -0,0 +1,144 @@
+/// # Summary
+///
+/// `TestKit!` is a macro which instantiates the [conformance suite](crate::test_kit) for an
+/// adapter, generating one `#[test]` per case.
+///
+/// # Parameters
+///
+/// * `$temp_store` is the path to a function which calls some `impl FnOnce(&Store)` with a
+///   [`Store`](crate::Store) that no other test is using.
+/// * `$emp`, `$job`, `$loc`, `$org`, and `$per` are the adapter's wrapper types, in that order.
+///
+/// # Remarks
+///
+/// Requires the `test-kit` feature.
+///
+/// # Examples
+///
+/// ```ignore
+/// clinvoice_adapter::TestKit!(crate::util::temp_store => FooEmployee, FooJob, FooLocation, FooOrganization, FooPerson);
+/// ```
+#[macro_export]
+macro_rules! TestKit
+{
+	($temp_store: path => $emp: ident, $job: ident, $loc: ident, $org: ident, $per: ident) =>
+	{
+		#[cfg(test)]
+		mod test_kit
+		{
+			use
+			{
+				super::{$emp, $job, $loc, $org, $per},
+
+				clinvoice_adapter::
+				{
+					data::{Deletable, EmployeeAdapter, LocationAdapter, Updatable},
+					Store,
+				},
+				clinvoice_data::{Employee, Job, Location, Organization, Person},
+			};
+
+			struct Kit;
+
+			impl clinvoice_adapter::test_kit::Kit for Kit
+			{
+				type Error = <$emp<'static, 'static> as EmployeeAdapter>::Error;
+
+				type Employee = $emp<'static, 'static>;
+				type Job = $job<'static, 'static>;
+				type Location = $loc<'static, 'static>;
+				type Organization = $org<'static, 'static>;
+				type Person = $per<'static, 'static>;
+
+				fn create_inner(location: &Location, name: String, store: &Store) -> Result<Location, Self::Error>
+				{
+					$loc {location, store}.create_inner(name)
+				}
+
+				fn delete_employee(employee: &Employee, cascade: bool, store: &Store) -> Result<(), Self::Error>
+				{
+					$emp {employee, store}.delete(cascade)
+				}
+
+				fn delete_job(job: &Job, cascade: bool, store: &Store) -> Result<(), Self::Error>
+				{
+					$job {job, store}.delete(cascade)
+				}
+
+				fn delete_location(location: &Location, cascade: bool, store: &Store) -> Result<(), Self::Error>
+				{
+					$loc {location, store}.delete(cascade)
+				}
+
+				fn delete_organization(organization: &Organization, cascade: bool, store: &Store) -> Result<(), Self::Error>
+				{
+					$org {organization, store}.delete(cascade)
+				}
+
+				fn delete_person(person: &Person, cascade: bool, store: &Store) -> Result<(), Self::Error>
+				{
+					$per {person, store}.delete(cascade)
+				}
+
+				fn update_employee(employee: &Employee, store: &Store) -> Result<(), Self::Error>
+				{
+					$emp {employee, store}.update()
+				}
+
+				fn update_job(job: &Job, store: &Store) -> Result<(), Self::Error>
+				{
+					$job {job, store}.update()
+				}
+
+				fn update_location(location: &Location, store: &Store) -> Result<(), Self::Error>
+				{
+					$loc {location, store}.update()
+				}
+
+				fn update_organization(organization: &Organization, store: &Store) -> Result<(), Self::Error>
+				{
+					$org {organization, store}.update()
+				}
+
+				fn update_person(person: &Person, store: &Store) -> Result<(), Self::Error>
+				{
+					$per {person, store}.update()
+				}
+			}
+
+			clinvoice_adapter::TestKit!(@cases $temp_store => employee, job, location, organization, person);
+
+			#[test]
+			fn read_only()
+			{
+				$temp_store(clinvoice_adapter::test_kit::read_only::<Kit>);
+			}
+		}
+	};
+
+	(@cases $temp_store: path => $($entity: ident),+) =>
+	{
+		$(
+			mod $entity
+			{
+				#[test]
+				fn create_retrieve()
+				{
+					$temp_store(clinvoice_adapter::test_kit::$entity::create_retrieve::<super::Kit>);
+				}
+
+				#[test]
+				fn delete()
+				{
+					$temp_store(clinvoice_adapter::test_kit::$entity::delete::<super::Kit>);
+				}
+
+				#[test]
+				fn update()
+				{
+					$temp_store(clinvoice_adapter::test_kit::$entity::update::<super::Kit>);
+				}
+			}
+		)+
+	};
+}
@@ -1,4 +1,4 @@
-use crate::Adapters;
+use crate::{Adapters, Error};
 
 #[cfg(feature="serde_support")]
 use serde::{Deserialize, Serialize};
@@ -43,6 +43,21 @@ pub struct Store
 	/// a consistent format which is documented for the adapter.
 	pub path: String,
 
+	/// # Summary
+	///
+	/// Whether or not this [`Store`] may only be read from.
+	///
+	/// # Remarks
+	///
+	/// When `true`, any operation which would create, update, or delete data in this [`Store`] is
+	/// rejected with an [`Error::ReadOnly`]. This is useful for sharing a [`Store`] with someone who
+	/// should be able to see its contents, but not change them (e.g. an accountant).
+	///
+	/// This is enforced by CLInvoice, and not by the storage itself. Permissions based on the role
+	/// of the `username` are out of scope until there is a database adapter which could check them.
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub read_only: bool,
+
 	/// # Summary
 	///
 	/// The username needed to acces the filesystem.
@@ -53,3 +68,24 @@ pub struct Store
 	/// mandated that each instance of a user account be protected by a password.
 	pub username: Option<String>,
 }
+
+impl Store
+{
+	/// # Summary
+	///
+	/// Report an [`Error::ReadOnly`] if this [`Store`] is [`read_only`](Store::read_only).
+	///
+	/// # Returns
+	///
+	/// * `()`, if this [`Store`] may be written to.
+	/// * An [`Error::ReadOnly`], if it may not.
+	pub fn assert_writable(&self) -> Result<(), Error>
+	{
+		if self.read_only
+		{
+			return Err(Error::ReadOnly);
+		}
+
+		Ok(())
+	}
+}
@@ -0,0 +1,184 @@
+//! # Summary
+//!
+//! This module contains a conformance suite which every adapter should pass. It is only available
+//! with the `test-kit` feature.
+//!
+//! # Remarks
+//!
+//! The suite is not meant to be used directly. Instead, use the [`TestKit`](crate::TestKit) macro
+//! to instantiate it for an adapter's types.
+
+pub mod employee;
+pub mod job;
+pub mod location;
+pub mod organization;
+pub mod person;
+
+use
+{
+	std::{error::Error as StdError, fmt::Display, fs, path::Path},
+
+	crate::
+	{
+		data::{EmployeeAdapter, Error, JobAdapter, LocationAdapter, OrganizationAdapter, PersonAdapter},
+		Error as AdapterError,
+		Store,
+	},
+
+	clinvoice_data::
+	{
+		chrono::Utc,
+		finance::{Currency, Money},
+		BillingModel, Employee, Job, Location, Organization, Person,
+	},
+};
+
+/// # Summary
+///
+/// The types of an adapter which are being tested, and how to wrap [`clinvoice_data`] in them.
+///
+/// # Remarks
+///
+/// This is implemented automatically by the [`TestKit`](crate::TestKit) macro.
+pub trait Kit
+{
+	/// # Summary
+	///
+	/// The error which is shared by every adapter type.
+	type Error : From<Error> + StdError;
+
+	type Employee : EmployeeAdapter<Error=Self::Error>;
+	type Job : JobAdapter<Error=Self::Error>;
+	type Location : LocationAdapter<Error=Self::Error>;
+	type Organization : OrganizationAdapter<Error=Self::Error>;
+	type Person : PersonAdapter<Error=Self::Error>;
+
+	/// # Summary
+	///
+	/// Call [`LocationAdapter::create_inner`] on some `location`.
+	fn create_inner(location: &Location, name: String, store: &Store) -> Result<Location, Self::Error>;
+
+	/// # Summary
+	///
+	/// Call [`Deletable::delete`](crate::data::Deletable::delete) on some `employee`.
+	fn delete_employee(employee: &Employee, cascade: bool, store: &Store) -> Result<(), Self::Error>;
+
+	/// # Summary
+	///
+	/// Call [`Deletable::delete`](crate::data::Deletable::delete) on some `job`.
+	fn delete_job(job: &Job, cascade: bool, store: &Store) -> Result<(), Self::Error>;
+
+	/// # Summary
+	///
+	/// Call [`Deletable::delete`](crate::data::Deletable::delete) on some `location`.
+	fn delete_location(location: &Location, cascade: bool, store: &Store) -> Result<(), Self::Error>;
+
+	/// # Summary
+	///
+	/// Call [`Deletable::delete`](crate::data::Deletable::delete) on some `organization`.
+	fn delete_organization(organization: &Organization, cascade: bool, store: &Store) -> Result<(), Self::Error>;
+
+	/// # Summary
+	///
+	/// Call [`Deletable::delete`](crate::data::Deletable::delete) on some `person`.
+	fn delete_person(person: &Person, cascade: bool, store: &Store) -> Result<(), Self::Error>;
+
+	/// # Summary
+	///
+	/// Call [`Updatable::update`](crate::data::Updatable::update) on some `employee`.
+	fn update_employee(employee: &Employee, store: &Store) -> Result<(), Self::Error>;
+
+	/// # Summary
+	///
+	/// Call [`Updatable::update`](crate::data::Updatable::update) on some `job`.
+	fn update_job(job: &Job, store: &Store) -> Result<(), Self::Error>;
+
+	/// # Summary
+	///
+	/// Call [`Updatable::update`](crate::data::Updatable::update) on some `location`.
+	fn update_location(location: &Location, store: &Store) -> Result<(), Self::Error>;
+
+	/// # Summary
+	///
+	/// Call [`Updatable::update`](crate::data::Updatable::update) on some `organization`.
+	fn update_organization(organization: &Organization, store: &Store) -> Result<(), Self::Error>;
+
+	/// # Summary
+	///
+	/// Call [`Updatable::update`](crate::data::Updatable::update) on some `person`.
+	fn update_person(person: &Person, store: &Store) -> Result<(), Self::Error>;
+}
+
+/// # Summary
+///
+/// Create a [`Location`], an [`Organization`] there, and a [`Person`] who is an [`Employee`] of
+/// it.
+fn create_employee<K>(store: &Store) -> (Location, Organization, Person, Employee) where
+	K : Kit,
+{
+	let earth = K::Location::create("Earth".into(), store).unwrap();
+	let big_old_test = K::Organization::create(earth.clone(), "Big Old Test Corporation".into(), store).unwrap();
+	let testy = K::Person::create("Testy Mćtesterson".into(), store).unwrap();
+	let ceo_testy = K::Employee::create(
+		vec![("Office".into(), clinvoice_data::Contact::Address {location_id: earth.id, export: false})].into_iter().collect(),
+		big_old_test.clone(),
+		testy.clone(),
+		clinvoice_data::EmployeeStatus::Employed,
+		"CEO of Tests".into(),
+		store,
+	).unwrap();
+
+	(earth, big_old_test, testy, ceo_testy)
+}
+
+/// # Summary
+///
+/// Assert that a [`Store`] which is [`read_only`](Store::read_only) rejects every change with an
+/// [`Error::ReadOnly`](AdapterError::ReadOnly), but can still be read from.
+pub fn read_only<K>(store: &Store) where
+	K : Kit,
+{
+	/// # Summary
+	///
+	/// Assert that some `result` was rejected because the [`Store`] is read-only.
+	fn assert_rejected<T>(result: Result<T, impl Display>)
+	{
+		assert_eq!(result.map(drop).unwrap_err().to_string(), AdapterError::ReadOnly.to_string());
+	}
+
+	let (earth, big_old_test, testy, ceo_testy) = create_employee::<K>(store);
+	let job = K::Job::create(big_old_test.clone(), Utc::now(), BillingModel::Hourly(Money::new(20_00, 2, Currency::USD)), "Test".into(), store).unwrap();
+
+	let receipt = Path::new(&store.path).join("receipt.txt");
+	fs::write(&receipt, "Lunch: 20.00 USD").unwrap();
+
+	let read_only = Store {read_only: true, ..store.clone()};
+
+	assert_rejected(K::Location::create("Mars".into(), &read_only));
+	assert_rejected(K::create_inner(&earth, "Europe".into(), &read_only));
+	assert_rejected(K::Organization::create(earth.clone(), "Another Test Corporation".into(), &read_only));
+	assert_rejected(K::Person::create("Testy McTesterson Jr.".into(), &read_only));
+	assert_rejected(K::Job::create(big_old_test.clone(), Utc::now(), BillingModel::Hourly(Money::new(20_00, 2, Currency::USD)), "Test".into(), &read_only));
+	assert_rejected(K::Job::attach(&receipt, &read_only));
+	assert_rejected(K::Job::next_invoice_seq(None, &read_only));
+
+	assert_rejected(K::update_employee(&ceo_testy, &read_only));
+	assert_rejected(K::update_job(&job, &read_only));
+	assert_rejected(K::update_location(&earth, &read_only));
+	assert_rejected(K::update_organization(&big_old_test, &read_only));
+	assert_rejected(K::update_person(&testy, &read_only));
+
+	assert_rejected(K::delete_employee(&ceo_testy, true, &read_only));
+	assert_rejected(K::delete_job(&job, true, &read_only));
+	assert_rejected(K::delete_location(&earth, true, &read_only));
+	assert_rejected(K::delete_organization(&big_old_test, true, &read_only));
+	assert_rejected(K::delete_person(&testy, true, &read_only));
+
+	// Assert that nothing was changed, and that it can all still be read.
+	assert_eq!(employee::retrieve_by_id::<K>(&ceo_testy, &read_only), Some(ceo_testy));
+	assert_eq!(job::retrieve_by_id::<K>(&job, &read_only), Some(job));
+	assert_eq!(location::retrieve_by_id::<K>(&earth, &read_only), Some(earth));
+	assert_eq!(organization::retrieve_by_id::<K>(&big_old_test, &read_only), Some(big_old_test));
+	assert_eq!(person::retrieve_by_id::<K>(&testy, &read_only), Some(testy));
+	assert_eq!(K::Job::next_invoice_seq(None, store).unwrap(), 1);
+}
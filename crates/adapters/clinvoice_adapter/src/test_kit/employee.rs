@@ -0,0 +1,175 @@
+use
+{
+	std::borrow::Cow::Borrowed,
+
+	super::Kit,
+	crate::{data::{EmployeeAdapter, JobAdapter, LocationAdapter, OrganizationAdapter, PersonAdapter}, Store},
+
+	clinvoice_data::
+	{
+		chrono::Utc,
+		finance::{Currency, Money},
+		BillingModel, Contact, Employee, EmployeeStatus,
+		views::{ContactView, EmployeeView, PersonView},
+	},
+	clinvoice_query::{self as query, Match, MatchStr},
+};
+
+/// # Summary
+///
+/// Retrieve the [`Employee`] which has the same [`Id`](clinvoice_data::Id) as `employee`.
+pub(super) fn retrieve_by_id<K>(employee: &Employee, store: &Store) -> Option<Employee> where
+	K : Kit,
+{
+	K::Employee::retrieve(
+		&query::Employee
+		{
+			id: Match::EqualTo(Borrowed(&employee.id)),
+			..Default::default()
+		},
+		store,
+	).unwrap().into_iter().next()
+}
+
+/// # Summary
+///
+/// Assert that [`EmployeeAdapter::create`]d [`Employee`]s can be
+/// [retrieved](EmployeeAdapter::retrieve), [viewed](EmployeeAdapter::into_view), and resolved to
+/// their [`Organization`](clinvoice_data::Organization) and [`Person`](clinvoice_data::Person).
+pub fn create_retrieve<K>(store: &Store) where
+	K : Kit,
+{
+	let (earth, big_old_test, testy, ceo_testy) = super::create_employee::<K>(store);
+
+	let mr_flu = K::Person::create("Mr. Flu".into(), store).unwrap();
+	let janitor_flu = K::Employee::create(
+		vec![
+			("Email".into(), Contact::Email {email: "flu@test.com".into(), export: true}),
+			("Phone".into(), Contact::Phone {phone: "555-555-5555".into(), export: false}),
+		].into_iter().collect(),
+		big_old_test.clone(),
+		mr_flu.clone(),
+		EmployeeStatus::NotEmployed,
+		"Janitor".into(),
+		store,
+	).unwrap();
+
+	// Assert that every field was stored.
+	let retrieved_janitor = retrieve_by_id::<K>(&janitor_flu, store).unwrap();
+	assert_eq!(retrieved_janitor.contact_info, janitor_flu.contact_info);
+	assert_eq!(retrieved_janitor.id, janitor_flu.id);
+	assert_eq!(retrieved_janitor.organization_id, big_old_test.id);
+	assert_eq!(retrieved_janitor.person_id, mr_flu.id);
+	assert_eq!(retrieved_janitor.status, EmployeeStatus::NotEmployed);
+	assert_eq!(retrieved_janitor.title, janitor_flu.title);
+
+	// Retrieve the employees of `big_old_test` who are still employed
+	let employed = K::Employee::retrieve(
+		&query::Employee
+		{
+			organization: query::Organization
+			{
+				id: Match::EqualTo(Borrowed(&big_old_test.id)),
+				..Default::default()
+			},
+			status: Match::EqualTo(Borrowed(&EmployeeStatus::Employed)),
+			..Default::default()
+		},
+		store,
+	).unwrap();
+
+	assert_eq!(employed.iter().map(|e| e.id).collect::<Vec<_>>(), vec![ceo_testy.id]);
+
+	// Retrieve the employees by title
+	let janitors = K::Employee::retrieve(
+		&query::Employee
+		{
+			organization: query::Organization
+			{
+				id: Match::EqualTo(Borrowed(&big_old_test.id)),
+				..Default::default()
+			},
+			title: MatchStr::Regex("^Jan".into()),
+			..Default::default()
+		},
+		store,
+	).unwrap();
+
+	assert_eq!(janitors.iter().map(|e| e.id).collect::<Vec<_>>(), vec![janitor_flu.id]);
+
+	assert_eq!(K::Employee::to_organization::<K::Organization>(&ceo_testy, store).unwrap(), big_old_test);
+	assert_eq!(K::Employee::to_person::<K::Person>(&ceo_testy, store).unwrap().id, testy.id);
+
+	let earth_view = K::Location::into_view(earth, store).unwrap();
+	assert_eq!(K::Employee::into_view::<K::Location, K::Organization, K::Person>(ceo_testy.clone(), store).unwrap(), EmployeeView
+	{
+		contact_info: vec![("Office".into(), ContactView::Address {location: earth_view, export: false})].into_iter().collect(),
+		employment_changes: Vec::new(),
+		hourly_rate: None,
+		id: ceo_testy.id,
+		organization: K::Organization::into_view::<K::Location>(big_old_test, store).unwrap(),
+		person: PersonView
+		{
+			id: testy.id,
+			name: testy.name,
+		},
+		status: ceo_testy.status,
+		title: ceo_testy.title,
+	});
+}
+
+/// # Summary
+///
+/// Assert that deleting an [`Employee`] is restricted by the [`Job`](clinvoice_data::Job)s which
+/// they have worked on, and that cascading the deletion removes only their
+/// [`Timesheet`](clinvoice_data::Timesheet)s.
+pub fn delete<K>(store: &Store) where
+	K : Kit,
+{
+	let (_, big_old_test, testy, ceo_testy) = super::create_employee::<K>(store);
+
+	let mut job = K::Job::create(big_old_test.clone(), Utc::now(), BillingModel::Hourly(Money::new(20_00, 2, Currency::USD)), "Test".into(), store).unwrap();
+	job.start_timesheet(ceo_testy.id);
+	K::update_job(&job, store).unwrap();
+
+	// Assert that the deletion fails when restricted
+	assert!(K::delete_employee(&ceo_testy, false, store).is_err());
+	assert!(retrieve_by_id::<K>(&ceo_testy, store).is_some());
+
+	// Assert that the deletion works when cascading
+	K::delete_employee(&ceo_testy, true, store).unwrap();
+	assert!(retrieve_by_id::<K>(&ceo_testy, store).is_none());
+
+	// Assert that the job remains, but without the employee's timesheet.
+	assert!(super::job::retrieve_by_id::<K>(&job, store).unwrap().timesheets.is_empty());
+
+	// Assert that the organization and person remain.
+	assert!(super::organization::retrieve_by_id::<K>(&big_old_test, store).is_some());
+	assert!(!K::Person::retrieve(
+		&query::Person
+		{
+			id: Match::EqualTo(Borrowed(&testy.id)),
+			..Default::default()
+		},
+		store,
+	).unwrap().is_empty());
+}
+
+/// # Summary
+///
+/// Assert that [`Updatable::update`](crate::data::Updatable::update) changes an [`Employee`].
+pub fn update<K>(store: &Store) where
+	K : Kit,
+{
+	let (_, _, _, mut ceo_testy) = super::create_employee::<K>(store);
+
+	ceo_testy.contact_info.insert("Email".into(), Contact::Email {email: "testy@test.com".into(), export: true});
+	ceo_testy.status = EmployeeStatus::NotEmployed;
+	ceo_testy.title = "Retired CEO of Tests".into();
+	K::update_employee(&ceo_testy, store).unwrap();
+
+	let retrieved = retrieve_by_id::<K>(&ceo_testy, store).unwrap();
+	assert_eq!(retrieved.contact_info, ceo_testy.contact_info);
+	assert_eq!(retrieved.status, ceo_testy.status);
+	assert_eq!(retrieved.title, ceo_testy.title);
+}
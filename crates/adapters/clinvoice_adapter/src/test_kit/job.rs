@@ -0,0 +1,378 @@
+use
+{
+	std::{borrow::Cow::Borrowed, fs, path::Path},
+
+	super::Kit,
+	crate::{data::{EmployeeAdapter, JobAdapter, OrganizationAdapter}, Store},
+
+	clinvoice_data::
+	{
+		chrono::{Duration, Utc},
+		finance::{Currency, Decimal, Money},
+		BillingModel, CreditNote, CreditNoteLine, Expense, ExpenseCategory, Id, Invoice, InvoiceDate, Job, Payment, Recurrence, Retainer, Rollover, Tax, Taxes,
+		views::{JobView, TimesheetView},
+	},
+	clinvoice_query::{self as query, Match, MatchStr},
+};
+
+/// # Summary
+///
+/// Retrieve the [`Job`] which has the same [`Id`](clinvoice_data::Id) as `job`.
+pub(super) fn retrieve_by_id<K>(job: &Job, store: &Store) -> Option<Job> where
+	K : Kit,
+{
+	K::Job::retrieve(
+		&query::Job
+		{
+			id: Match::EqualTo(Borrowed(&job.id)),
+			..Default::default()
+		},
+		store,
+	).unwrap().into_iter().next()
+}
+
+/// # Summary
+///
+/// Assert that [`JobAdapter::create`]d [`Job`]s can be [retrieved](JobAdapter::retrieve),
+/// [viewed](JobAdapter::into_view), and resolved to their client.
+pub fn create_retrieve<K>(store: &Store) where
+	K : Kit,
+{
+	let (_, big_old_test, _, ceo_testy) = super::create_employee::<K>(store);
+
+	let date_open = Utc::now();
+	let mut first = K::Job::create(
+		big_old_test.clone(),
+		date_open,
+		BillingModel::Hourly(Money::new(20_00, 2, Currency::USD)),
+		"Test the first job".into(),
+		store,
+	).unwrap();
+
+	let second = K::Job::create(
+		big_old_test.clone(),
+		date_open,
+		BillingModel::Hourly(Money::new(15_00, 2, Currency::EUR)),
+		"Test the second job".into(),
+		store,
+	).unwrap();
+
+	// Assert that every field was stored.
+	let retrieved_first = retrieve_by_id::<K>(&first, store).unwrap();
+	assert_eq!(retrieved_first.billing_model, first.billing_model);
+	assert_eq!(retrieved_first.client_id, big_old_test.id);
+	assert_eq!(retrieved_first.date_close, None);
+	assert_eq!(retrieved_first.date_open, first.date_open);
+	assert!(retrieved_first.invoices.is_empty());
+	assert_eq!(retrieved_first.notes, first.notes);
+	assert_eq!(retrieved_first.objectives, first.objectives);
+	assert!(retrieved_first.timesheets.is_empty());
+
+	first.start_timesheet(ceo_testy.id);
+	first.timesheets[0].billable = false;
+	K::update_job(&first, store).unwrap();
+
+	// Retrieve the jobs which `ceo_testy` has worked on
+	let worked_on = K::Job::retrieve(
+		&query::Job
+		{
+			timesheets: query::Timesheet
+			{
+				employee: query::Employee
+				{
+					id: Match::HasAny(vec![Borrowed(&ceo_testy.id)].into_iter().collect()),
+					..Default::default()
+				},
+				..Default::default()
+			},
+			..Default::default()
+		},
+		store,
+	).unwrap();
+
+	assert!(worked_on.contains(&first));
+	assert!(!worked_on.contains(&second));
+
+	// Retrieve the jobs of `big_old_test` by objectives
+	let second_only = K::Job::retrieve(
+		&query::Job
+		{
+			client: query::Organization
+			{
+				id: Match::EqualTo(Borrowed(&big_old_test.id)),
+				..Default::default()
+			},
+			objectives: MatchStr::Contains("second".into()),
+			..Default::default()
+		},
+		store,
+	).unwrap();
+
+	assert_eq!(second_only, vec![second]);
+
+	assert_eq!(K::Job::to_organization::<K::Organization>(&first, store).unwrap(), big_old_test);
+
+	let timesheet = first.timesheets.first().unwrap();
+	let first_view = K::Job::into_view::<K::Employee, K::Location, K::Organization, K::Person>(first.clone(), store).unwrap();
+	assert_eq!(first_view, JobView
+	{
+		adjustments: first.adjustments.clone(),
+		bill_to: Default::default(),
+		billing_model: first.billing_model.clone(),
+		client: K::Organization::into_view::<K::Location>(big_old_test, store).unwrap(),
+		date_close: first.date_close,
+		date_open: first.date_open,
+		employee_default_rates: Default::default(),
+		employee_rates: Default::default(),
+		expense_markup: first.expense_markup.clone(),
+		id: first.id,
+		invoices: first.invoices.clone(),
+		notes: first.notes.clone(),
+		objectives: first.objectives.clone(),
+		overtime: None,
+		rate_changes: Vec::new(),
+		timesheets: vec![TimesheetView
+		{
+			billable: false,
+			employee: K::Employee::into_view::<K::Location, K::Organization, K::Person>(ceo_testy, store).unwrap(),
+			expenses: Vec::new(),
+			hourly_rate: None,
+			invoice_id: None,
+			time_begin: timesheet.time_begin,
+			time_end: None,
+			work_notes: timesheet.work_notes.clone(),
+		}],
+	});
+
+	// Assert that nothing about the timesheets, such as whether they are billable, is lost when
+	// the view is converted back.
+	assert_eq!(Job::from(first_view).timesheets, first.timesheets);
+}
+
+/// # Summary
+///
+/// Assert that deleting a [`Job`] removes only it.
+pub fn delete<K>(store: &Store) where
+	K : Kit,
+{
+	let (_, big_old_test, _, ceo_testy) = super::create_employee::<K>(store);
+
+	let mut job = K::Job::create(big_old_test.clone(), Utc::now(), BillingModel::Hourly(Money::new(20_00, 2, Currency::USD)), "Test".into(), store).unwrap();
+	job.start_timesheet(ceo_testy.id);
+	K::update_job(&job, store).unwrap();
+
+	// Issue an invoice, taking its number from both the store's and the client's sequence.
+	assert_eq!(K::Job::next_invoice_seq(None, store).unwrap(), 1);
+	assert_eq!(K::Job::next_invoice_seq(Some(job.client_id), store).unwrap(), 1);
+
+	let issued = Utc::now();
+	job.bill(
+		Invoice
+		{
+			credit_notes: Vec::new(),
+			date: Some(InvoiceDate {issued, paid: None}),
+			id: Id::new_v4(),
+			number: Some("INV-0001".into()),
+			payment_terms: None,
+			payments: Vec::new(),
+			taxes: None,
+		},
+		issued,
+	);
+
+	// Credit the client for the invoice.
+	assert_eq!(K::Job::next_credit_note_seq(None, store).unwrap(), 1);
+	job.invoices[0].credit_notes.push(CreditNote
+	{
+		date: Utc::now(),
+		id: Id::new_v4(),
+		lines: vec![CreditNoteLine {amount: Decimal::new(-5_00, 2), category: None, description: "Billed by mistake".into()}],
+		number: "CN-0001".into(),
+		reason: "".into(),
+	});
+	K::update_job(&job, store).unwrap();
+
+	K::delete_job(&job, false, store).unwrap();
+	assert!(retrieve_by_id::<K>(&job, store).is_none());
+
+	// Assert that the numbers of the deleted job's invoice and credit note are not given out again.
+	assert_eq!(K::Job::next_credit_note_seq(None, store).unwrap(), 2);
+	assert_eq!(K::Job::next_invoice_seq(None, store).unwrap(), 2);
+	assert_eq!(K::Job::next_invoice_seq(Some(job.client_id), store).unwrap(), 2);
+
+	// Assert that the client and employee remain.
+	assert!(super::organization::retrieve_by_id::<K>(&big_old_test, store).is_some());
+	assert!(super::employee::retrieve_by_id::<K>(&ceo_testy, store).is_some());
+}
+
+/// # Summary
+///
+/// Assert that [`Updatable::update`](crate::data::Updatable::update) changes a [`Job`] in the ways
+/// that `clinvoice` does.
+pub fn update<K>(store: &Store) where
+	K : Kit,
+{
+	let (earth, big_old_test, _, ceo_testy) = super::create_employee::<K>(store);
+
+	let mut job = K::Job::create(big_old_test.clone(), Utc::now(), BillingModel::Hourly(Money::new(20_00, 2, Currency::USD)), "Test".into(), store).unwrap();
+
+	// Start, and then stop, a timesheet.
+	job.start_timesheet(ceo_testy.id);
+	K::update_job(&job, store).unwrap();
+
+	// Attach a receipt, which is only kept once no matter how many times it is attached.
+	let receipt = Path::new(&store.path).join("receipt.txt");
+	fs::write(&receipt, "Lunch: 20.00 USD").unwrap();
+	let attachment = K::Job::attach(&receipt, store).unwrap();
+	assert_eq!(K::Job::attach(&receipt, store).unwrap(), attachment);
+	assert_eq!(attachment.name, "receipt.txt");
+	assert_eq!(K::Job::read_attachment(&attachment, store).unwrap(), b"Lunch: 20.00 USD");
+
+	let timesheet = &mut job.timesheets[0];
+	timesheet.expenses.push(Expense
+	{
+		attachments: vec![attachment],
+		billable: true,
+		category: ExpenseCategory::new("Food"),
+		cost: Money::new(20_00, 2, Currency::USD),
+		description: "Lunch".into(),
+		kind: Default::default(),
+	});
+	timesheet.time_begin = timesheet.time_begin - Duration::minutes(1);
+	timesheet.time_end = Some(Utc::now());
+	timesheet.work_notes = "* Wrote tests".into();
+	K::update_job(&job, store).unwrap();
+
+	let retrieved = retrieve_by_id::<K>(&job, store).unwrap();
+	assert_eq!(retrieved.timesheets, job.timesheets);
+
+	// Close the job and issue an invoice for its work.
+	assert_eq!(K::Job::next_invoice_seq(None, store).unwrap(), 1);
+	assert!(job.has_unbilled_work(Utc::now()));
+
+	let issued = Utc::now();
+	job.date_close = Some(issued);
+	job.bill(
+		Invoice
+		{
+			credit_notes: Vec::new(),
+			date: Some(InvoiceDate {issued, paid: None}),
+			id: Id::new_v4(),
+			number: Some("INV-0001".into()),
+			payment_terms: None,
+			payments: Vec::new(),
+			taxes: Some(Taxes {rates: vec![Tax::Rate {name: "VAT".into(), percent: Decimal::new(19, 0)}], ..Default::default()}),
+		},
+		issued,
+	);
+	job.notes = "* Finished".into();
+	K::update_job(&job, store).unwrap();
+
+	assert!(!job.has_unbilled_work(Utc::now()));
+	assert_eq!(job.timesheets[0].invoice_id, Some(job.invoices[0].id));
+
+	let retrieved = retrieve_by_id::<K>(&job, store).unwrap();
+	assert_eq!(retrieved.date_close, job.date_close);
+	assert_eq!(retrieved.invoices, job.invoices);
+	assert_eq!(retrieved.notes, job.notes);
+	assert_eq!(retrieved.timesheets, job.timesheets);
+
+	// Assert that the issued invoice is counted, but only for its client, and that each number is
+	// only given out once.
+	assert_eq!(K::Job::next_invoice_seq(Some(job.client_id), store).unwrap(), 2);
+	assert_eq!(K::Job::next_invoice_seq(Some(job.client_id), store).unwrap(), 3);
+
+	let other_client = K::Organization::create(earth, "Other Client".into(), store).unwrap();
+	assert_eq!(K::Job::next_invoice_seq(Some(other_client.id), store).unwrap(), 1);
+
+	// Pay the invoice in two parts.
+	let payment = Payment {amount: Money::new(10_00, 2, Currency::USD), date: Utc::now(), method: "Cash".into(), reference: "".into()};
+	job.invoices[0].payments.push(payment.clone());
+	K::update_job(&job, store).unwrap();
+	assert_eq!(retrieve_by_id::<K>(&job, store).unwrap().invoices, job.invoices);
+
+	let balance = job.invoice_balance(&job.invoices[0], job.invoices[0].taxes.as_ref().unwrap()).unwrap();
+	job.invoices[0].payments.push(Payment {amount: balance, method: "Bank Transfer".into(), reference: "TX-1".into(), ..payment});
+	job.invoices[0].date.as_mut().unwrap().paid = Some(Utc::now());
+	K::update_job(&job, store).unwrap();
+	assert_eq!(retrieve_by_id::<K>(&job, store).unwrap().invoices, job.invoices);
+
+	// Retrieve the jobs which have been paid in full.
+	let paid = K::Job::retrieve(
+		&query::Job
+		{
+			invoices: query::Invoice
+			{
+				paid_in_full: Match::EqualTo(Borrowed(&true)),
+				..Default::default()
+			},
+			..Default::default()
+		},
+		store,
+	).unwrap();
+	assert_eq!(paid, vec![job.clone()]);
+
+	// Credit the client for part of the invoice, and refund what they overpaid.
+	assert_eq!(K::Job::next_credit_note_seq(None, store).unwrap(), 1);
+	job.invoices[0].credit_notes.push(CreditNote
+	{
+		date: Utc::now(),
+		id: Id::new_v4(),
+		lines: vec![CreditNoteLine {amount: Decimal::new(-5_00, 2), category: None, description: "Billed twice for one hour".into()}],
+		number: "CN-0001".into(),
+		reason: "".into(),
+	});
+	let balance = job.invoice_balance(&job.invoices[0], job.invoices[0].taxes.as_ref().unwrap()).unwrap();
+	assert_eq!(balance, Money::new(-5_95, 2, Currency::USD));
+	job.invoices[0].payments.push(Payment {amount: balance, method: "Refund".into(), reference: "".into(), ..payment});
+	K::update_job(&job, store).unwrap();
+	assert_eq!(retrieve_by_id::<K>(&job, store).unwrap().invoices, job.invoices);
+	assert_eq!(K::Job::next_credit_note_seq(None, store).unwrap(), 2);
+
+	// Reopen the job.
+	job.date_close = None;
+	K::update_job(&job, store).unwrap();
+	assert_eq!(retrieve_by_id::<K>(&job, store).unwrap().date_close, None);
+
+	// Bill each week of a retainer which has ended.
+	let mut retainer = K::Job::create(
+		big_old_test,
+		Utc::now() - Duration::weeks(3),
+		BillingModel::Retainer(Retainer
+		{
+			amount: Money::new(80_00, 2, Currency::USD),
+			included_hours: Decimal::new(2, 0),
+			overage_rate: Money::new(12_00, 2, Currency::USD),
+			period: Recurrence::Weekly,
+			periods_billed: Vec::new(),
+			rollover: Rollover::Unlimited,
+		}),
+		"Retainer".into(),
+		store,
+	).unwrap();
+
+	let issued = Utc::now();
+	while retainer.next_retainer_period().filter(|(_, end)| *end <= issued).is_some()
+	{
+		let seq = K::Job::next_invoice_seq(None, store).unwrap();
+		retainer.bill(
+			Invoice
+			{
+				credit_notes: Vec::new(),
+				date: Some(InvoiceDate {issued, paid: None}),
+				id: Id::new_v4(),
+				number: Some(format!("INV-{:04}", seq)),
+				payment_terms: None,
+				payments: Vec::new(),
+				taxes: None,
+			},
+			issued,
+		);
+		K::update_job(&retainer, store).unwrap();
+	}
+
+	let retrieved = retrieve_by_id::<K>(&retainer, store).unwrap();
+	assert_eq!(retrieved.billing_model, retainer.billing_model);
+	assert_eq!(retrieved.invoices, retainer.invoices);
+	assert_eq!(retrieved.invoices.iter().filter_map(|i| i.number.as_deref()).collect::<Vec<_>>(), vec!["INV-0002", "INV-0003", "INV-0004"]);
+}
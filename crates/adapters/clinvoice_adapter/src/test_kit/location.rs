@@ -0,0 +1,174 @@
+use
+{
+	std::borrow::Cow::Borrowed,
+
+	super::Kit,
+	crate::{data::{LocationAdapter, OrganizationAdapter}, Store},
+
+	clinvoice_data::{finance::Decimal, Location, Tax, Taxes, views::LocationView},
+	clinvoice_query::{self as query, Match, MatchStr},
+};
+
+/// # Summary
+///
+/// Retrieve the [`Location`] which has the same [`Id`](clinvoice_data::Id) as `location`.
+pub(super) fn retrieve_by_id<K>(location: &Location, store: &Store) -> Option<Location> where
+	K : Kit,
+{
+	K::Location::retrieve(
+		&query::Location
+		{
+			id: Match::EqualTo(Borrowed(&location.id)),
+			..Default::default()
+		},
+		store,
+	).unwrap().into_iter().next()
+}
+
+/// # Summary
+///
+/// Assert that [`LocationAdapter::create`]d and [`LocationAdapter::create_inner`]ed [`Location`]s
+/// can be [retrieved](LocationAdapter::retrieve), and [viewed](LocationAdapter::into_view).
+pub fn create_retrieve<K>(store: &Store) where
+	K : Kit,
+{
+	let earth = K::Location::create("Earth".into(), store).unwrap();
+	let usa = K::create_inner(&earth, "USA".into(), store).unwrap();
+	let arizona = K::create_inner(&usa, "Arizona".into(), store).unwrap();
+	let utah = K::create_inner(&usa, "Utah".into(), store).unwrap();
+
+	// Assert that every field was stored.
+	let retrieved_arizona = retrieve_by_id::<K>(&arizona, store).unwrap();
+	assert_eq!(retrieved_arizona.id, arizona.id);
+	assert_eq!(retrieved_arizona.name, arizona.name);
+	assert_eq!(retrieved_arizona.outer_id, Some(usa.id));
+	assert_eq!(retrieve_by_id::<K>(&earth, store).unwrap().outer_id, None);
+
+	// Retrieve the locations inside of the USA
+	let in_usa = K::Location::retrieve(
+		&query::Location
+		{
+			outer: query::OuterLocation::Some(
+				query::Location
+				{
+					id: Match::EqualTo(Borrowed(&usa.id)),
+					..Default::default()
+				}.into()
+			),
+			..Default::default()
+		},
+		store,
+	).unwrap();
+
+	assert!(!in_usa.contains(&earth));
+	assert!(!in_usa.contains(&usa));
+	assert!(in_usa.contains(&arizona));
+	assert!(in_usa.contains(&utah));
+
+	// Retrieve the locations which are not inside anything
+	let outermost = K::Location::retrieve(
+		&query::Location
+		{
+			outer: query::OuterLocation::None,
+			name: MatchStr::EqualTo("Earth".into()),
+			..Default::default()
+		},
+		store,
+	).unwrap();
+
+	assert!(outermost.contains(&earth));
+	assert!(!outermost.contains(&usa));
+
+	// Assert that the outer locations are found in order.
+	assert_eq!(K::Location::outers(&arizona, store).unwrap(), vec![usa.clone(), earth.clone()]);
+
+	assert_eq!(K::Location::into_view(arizona.clone(), store).unwrap(), LocationView
+	{
+		address: None,
+		id: arizona.id,
+		name: arizona.name,
+		outer: Some(LocationView
+		{
+			address: None,
+			id: usa.id,
+			name: usa.name,
+			outer: Some(LocationView
+			{
+				address: None,
+				id: earth.id,
+				name: earth.name,
+				outer: None,
+				taxes: None,
+			}.into()),
+			taxes: None,
+		}.into()),
+		taxes: None,
+	});
+}
+
+/// # Summary
+///
+/// Assert that deleting a [`Location`] is restricted by the
+/// [`Organization`](clinvoice_data::Organization)s and [`Location`]s inside of it, and that
+/// cascading the deletion removes them too.
+pub fn delete<K>(store: &Store) where
+	K : Kit,
+{
+	let earth = K::Location::create("Earth".into(), store).unwrap();
+	let usa = K::create_inner(&earth, "USA".into(), store).unwrap();
+	let arizona = K::create_inner(&usa, "Arizona".into(), store).unwrap();
+	let big_old_test = K::Organization::create(usa.clone(), "Big Old Test Corporation".into(), store).unwrap();
+
+	// Assert that the deletion fails when restricted by an inner location only.
+	assert!(K::delete_location(&earth, false, store).is_err());
+	assert!(retrieve_by_id::<K>(&earth, store).is_some());
+
+	// Assert that the deletion fails when restricted by an organization only.
+	let mars = K::Location::create("Mars".into(), store).unwrap();
+	let martian_test = K::Organization::create(mars.clone(), "Martian Test Corporation".into(), store).unwrap();
+	assert!(K::delete_location(&mars, false, store).is_err());
+	assert!(retrieve_by_id::<K>(&mars, store).is_some());
+
+	// Assert that the deletion works when cascading
+	K::delete_location(&earth, true, store).unwrap();
+	K::delete_location(&mars, true, store).unwrap();
+
+	assert!(retrieve_by_id::<K>(&earth, store).is_none());
+	assert!(retrieve_by_id::<K>(&usa, store).is_none());
+	assert!(retrieve_by_id::<K>(&arizona, store).is_none());
+	assert!(retrieve_by_id::<K>(&mars, store).is_none());
+	assert!(super::organization::retrieve_by_id::<K>(&big_old_test, store).is_none());
+	assert!(super::organization::retrieve_by_id::<K>(&martian_test, store).is_none());
+
+	// Assert that a location which nothing references can be deleted without cascading.
+	let pluto = K::Location::create("Pluto".into(), store).unwrap();
+	K::delete_location(&pluto, false, store).unwrap();
+	assert!(retrieve_by_id::<K>(&pluto, store).is_none());
+}
+
+/// # Summary
+///
+/// Assert that [`Updatable::update`](crate::data::Updatable::update) changes a [`Location`].
+pub fn update<K>(store: &Store) where
+	K : Kit,
+{
+	let earth = K::Location::create("Earth".into(), store).unwrap();
+	let mut usa = K::Location::create("USA".into(), store).unwrap();
+
+	usa.name = "United States of America".into();
+	usa.outer_id = Some(earth.id);
+	usa.taxes = Some(Taxes
+	{
+		expenses: vec![("Food".into(), Vec::new())].into_iter().collect(),
+		rates: vec![Tax::Rate {name: "Sales Tax".into(), percent: Decimal::new(6, 0)}],
+	});
+	K::update_location(&usa, store).unwrap();
+
+	let retrieved_usa = retrieve_by_id::<K>(&usa, store).unwrap();
+	assert_eq!(retrieved_usa.name, usa.name);
+	assert_eq!(retrieved_usa.outer_id, usa.outer_id);
+	assert_eq!(retrieved_usa.taxes, usa.taxes);
+
+	// Assert that the taxes are part of the view.
+	assert_eq!(K::Location::into_view(usa.clone(), store).unwrap().applicable_taxes(), usa.taxes.as_ref());
+}
@@ -0,0 +1,154 @@
+use
+{
+	std::borrow::Cow::Borrowed,
+
+	super::Kit,
+	crate::{data::{JobAdapter, LocationAdapter, OrganizationAdapter}, Store},
+
+	clinvoice_data::
+	{
+		chrono::Utc,
+		finance::{Currency, Money},
+		BillingModel, Organization, Tax, Taxes,
+		views::OrganizationView,
+	},
+	clinvoice_query::{self as query, Match, MatchStr},
+};
+
+/// # Summary
+///
+/// Retrieve the [`Organization`] which has the same [`Id`](clinvoice_data::Id) as `organization`.
+pub(super) fn retrieve_by_id<K>(organization: &Organization, store: &Store) -> Option<Organization> where
+	K : Kit,
+{
+	K::Organization::retrieve(
+		&query::Organization
+		{
+			id: Match::EqualTo(Borrowed(&organization.id)),
+			..Default::default()
+		},
+		store,
+	).unwrap().into_iter().next()
+}
+
+/// # Summary
+///
+/// Assert that [`OrganizationAdapter::create`]d [`Organization`]s can be
+/// [retrieved](OrganizationAdapter::retrieve), [viewed](OrganizationAdapter::into_view), and
+/// resolved to their [`Location`](clinvoice_data::Location) and
+/// [`Employee`](clinvoice_data::Employee)s.
+pub fn create_retrieve<K>(store: &Store) where
+	K : Kit,
+{
+	let (earth, big_old_test, _, ceo_testy) = super::create_employee::<K>(store);
+	let usa = K::create_inner(&earth, "USA".into(), store).unwrap();
+	let aaa = K::Organization::create(usa.clone(), "AAA".into(), store).unwrap();
+	let ibm = K::Organization::create(usa.clone(), "IBM".into(), store).unwrap();
+
+	// Assert that every field was stored.
+	assert_eq!(retrieve_by_id::<K>(&big_old_test, store), Some(big_old_test.clone()));
+
+	// Retrieve the organizations in the USA
+	let in_usa = K::Organization::retrieve(
+		&query::Organization
+		{
+			location: query::Location
+			{
+				id: Match::EqualTo(Borrowed(&usa.id)),
+				..Default::default()
+			},
+			..Default::default()
+		},
+		store,
+	).unwrap();
+
+	assert!(in_usa.contains(&aaa));
+	assert!(in_usa.contains(&ibm));
+	assert!(!in_usa.contains(&big_old_test));
+
+	// Retrieve AAA by name
+	let only_aaa = K::Organization::retrieve(
+		&query::Organization
+		{
+			location: query::Location
+			{
+				id: Match::EqualTo(Borrowed(&usa.id)),
+				..Default::default()
+			},
+			name: MatchStr::Contains("A".into()),
+			..Default::default()
+		},
+		store,
+	).unwrap();
+
+	assert_eq!(only_aaa, vec![aaa.clone()]);
+
+	assert_eq!(K::Organization::to_location::<K::Location>(&aaa, store).unwrap(), usa);
+	assert_eq!(K::Organization::to_employees::<K::Employee>(&big_old_test, store).unwrap(), vec![ceo_testy]);
+	assert!(K::Organization::to_employees::<K::Employee>(&aaa, store).unwrap().is_empty());
+
+	assert_eq!(K::Organization::into_view::<K::Location>(aaa.clone(), store).unwrap(), OrganizationView
+	{
+		bill_to: Default::default(),
+		expense_markup: aaa.expense_markup,
+		id: aaa.id,
+		location: K::Location::into_view(usa, store).unwrap(),
+		name: aaa.name,
+		overtime: None,
+		payment_methods: Vec::new(),
+		payment_terms: None,
+		tax_ids: Default::default(),
+		taxes: None,
+	});
+}
+
+/// # Summary
+///
+/// Assert that deleting an [`Organization`] is restricted by the [`Job`](clinvoice_data::Job)s
+/// and [`Employee`](clinvoice_data::Employee)s which reference it, and that cascading the deletion
+/// removes them too.
+pub fn delete<K>(store: &Store) where
+	K : Kit,
+{
+	let (earth, big_old_test, _, ceo_testy) = super::create_employee::<K>(store);
+
+	// Assert that the deletion fails when restricted by an employee only.
+	assert!(K::delete_organization(&big_old_test, false, store).is_err());
+	assert!(retrieve_by_id::<K>(&big_old_test, store).is_some());
+
+	// Assert that the deletion fails when restricted by a job only.
+	let client = K::Organization::create(earth.clone(), "Client".into(), store).unwrap();
+	let job = K::Job::create(client.clone(), Utc::now(), BillingModel::Hourly(Money::new(20_00, 2, Currency::USD)), "Test".into(), store).unwrap();
+	assert!(K::delete_organization(&client, false, store).is_err());
+	assert!(retrieve_by_id::<K>(&client, store).is_some());
+
+	// Assert that the deletion works when cascading
+	K::delete_organization(&big_old_test, true, store).unwrap();
+	K::delete_organization(&client, true, store).unwrap();
+
+	assert!(retrieve_by_id::<K>(&big_old_test, store).is_none());
+	assert!(retrieve_by_id::<K>(&client, store).is_none());
+	assert!(super::employee::retrieve_by_id::<K>(&ceo_testy, store).is_none());
+	assert!(super::job::retrieve_by_id::<K>(&job, store).is_none());
+
+	// Assert that the location is still there.
+	assert!(super::location::retrieve_by_id::<K>(&earth, store).is_some());
+}
+
+/// # Summary
+///
+/// Assert that [`Updatable::update`](crate::data::Updatable::update) changes an [`Organization`].
+pub fn update<K>(store: &Store) where
+	K : Kit,
+{
+	let earth = K::Location::create("Earth".into(), store).unwrap();
+	let mars = K::Location::create("Mars".into(), store).unwrap();
+	let mut big_old_test = K::Organization::create(earth, "Big Old Test Corporation".into(), store).unwrap();
+
+	big_old_test.location_id = mars.id;
+	big_old_test.name = "Martian Test Corporation".into();
+	big_old_test.taxes = Some(Taxes {rates: vec![Tax::ReverseCharge], ..Default::default()});
+	K::update_organization(&big_old_test, store).unwrap();
+
+	assert_eq!(retrieve_by_id::<K>(&big_old_test, store), Some(big_old_test));
+}
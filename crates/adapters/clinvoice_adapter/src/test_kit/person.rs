@@ -0,0 +1,125 @@
+use
+{
+	std::borrow::Cow::Borrowed,
+
+	super::Kit,
+	crate::{data::{EmployeeAdapter, PersonAdapter}, Store},
+
+	clinvoice_data::Person,
+	clinvoice_query::{self as query, Match, MatchStr},
+};
+
+/// # Summary
+///
+/// Retrieve the [`Person`] which has the same [`Id`](clinvoice_data::Id) as `person`.
+pub(super) fn retrieve_by_id<K>(person: &Person, store: &Store) -> Option<Person> where
+	K : Kit,
+{
+	K::Person::retrieve(
+		&query::Person
+		{
+			id: Match::EqualTo(Borrowed(&person.id)),
+			..Default::default()
+		},
+		store,
+	).unwrap().into_iter().next()
+}
+
+/// # Summary
+///
+/// Assert that [`PersonAdapter::create`]d [`Person`]s can be [retrieved](PersonAdapter::retrieve).
+pub fn create_retrieve<K>(store: &Store) where
+	K : Kit,
+{
+	let flingo = K::Person::create("flingo".into(), store).unwrap();
+	let bob = K::Person::create("bob".into(), store).unwrap();
+	let slimdi = K::Person::create("slimdi".into(), store).unwrap();
+	let longone = K::Person::create("longone".into(), store).unwrap();
+
+	// Assert that every field was stored.
+	let retrieved_bob = retrieve_by_id::<K>(&bob, store).unwrap();
+	assert_eq!(retrieved_bob.id, bob.id);
+	assert_eq!(retrieved_bob.name, bob.name);
+
+	// Retrieve longone and slimdi
+	let longone_slimdi = K::Person::retrieve(
+		&query::Person
+		{
+			name: MatchStr::Regex(format!("^({}|{})$", longone.name, slimdi.name)),
+			..Default::default()
+		},
+		store,
+	).unwrap();
+
+	assert!(!longone_slimdi.contains(&flingo));
+	assert!(!longone_slimdi.contains(&bob));
+	assert!(longone_slimdi.contains(&slimdi));
+	assert!(longone_slimdi.contains(&longone));
+
+	// Retrieve everyone but bob
+	let not_bob = K::Person::retrieve(
+		&query::Person
+		{
+			id: Match::Not(Match::EqualTo(Borrowed(&bob.id)).into()),
+			..Default::default()
+		},
+		store,
+	).unwrap();
+
+	assert!(not_bob.contains(&flingo));
+	assert!(!not_bob.contains(&bob));
+	assert!(not_bob.contains(&slimdi));
+	assert!(not_bob.contains(&longone));
+
+	// Retrieve everyone
+	let everyone = K::Person::retrieve(&Default::default(), store).unwrap();
+	assert!([&flingo, &bob, &slimdi, &longone].iter().all(|p| everyone.contains(p)));
+}
+
+/// # Summary
+///
+/// Assert that deleting a [`Person`] is restricted by the [`Employee`](clinvoice_data::Employee)s
+/// which reference it, and that cascading the deletion removes them too.
+pub fn delete<K>(store: &Store) where
+	K : Kit,
+{
+	let (_, big_old_test, testy, ceo_testy) = super::create_employee::<K>(store);
+
+	// Assert that the deletion fails when restricted
+	assert!(K::delete_person(&testy, false, store).is_err());
+	assert!(retrieve_by_id::<K>(&testy, store).is_some());
+
+	// Assert that the deletion works when cascading
+	K::delete_person(&testy, true, store).unwrap();
+	assert!(retrieve_by_id::<K>(&testy, store).is_none());
+
+	// Assert that the referencing employee is gone, but its organization is not.
+	assert!(K::Employee::retrieve(
+		&query::Employee
+		{
+			id: Match::EqualTo(Borrowed(&ceo_testy.id)),
+			..Default::default()
+		},
+		store,
+	).unwrap().is_empty());
+	assert!(super::organization::retrieve_by_id::<K>(&big_old_test, store).is_some());
+
+	// Assert that a person which nothing references can be deleted without cascading.
+	let lonely = K::Person::create("Lonely".into(), store).unwrap();
+	K::delete_person(&lonely, false, store).unwrap();
+	assert!(retrieve_by_id::<K>(&lonely, store).is_none());
+}
+
+/// # Summary
+///
+/// Assert that [`Updatable::update`](crate::data::Updatable::update) changes a [`Person`].
+pub fn update<K>(store: &Store) where
+	K : Kit,
+{
+	let mut testy = K::Person::create("Testy Mćtesterson".into(), store).unwrap();
+
+	testy.name = "Mr. Testy".into();
+	K::update_person(&testy, store).unwrap();
+
+	assert_eq!(retrieve_by_id::<K>(&testy, store).unwrap().name, "Mr. Testy");
+}
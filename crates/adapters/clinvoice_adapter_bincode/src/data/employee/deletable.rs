@@ -15,6 +15,8 @@ impl Deletable for BincodeEmployee<'_, '_>
 
 	fn delete(&self, cascade: bool) -> Result<()>
 	{
+		self.store.assert_writable()?;
+
 		let associated_jobs = BincodeJob::retrieve(
 			&query::Job
 			{
@@ -61,121 +63,3 @@ impl Deletable for BincodeEmployee<'_, '_>
 		Ok(())
 	}
 }
-
-#[cfg(test)]
-mod tests
-{
-	use
-	{
-		std::time::Instant,
-
-		super::{BincodeEmployee, BincodeJob, Borrowed, Deletable, JobAdapter, query, Updatable},
-		crate::
-		{
-			data::{BincodeLocation, BincodeOrganization, BincodePerson},
-			util,
-		},
-
-		clinvoice_adapter::data::{EmployeeAdapter, LocationAdapter, OrganizationAdapter, PersonAdapter},
-		clinvoice_data::
-		{
-			chrono::Utc,
-			finance::{Currency, Money},
-			Contact, EmployeeStatus,
-		},
-	};
-
-	#[test]
-	fn delete()
-	{
-		util::temp_store(|store|
-		{
-			let earth = BincodeLocation
-			{
-				location: &BincodeLocation::create("Earth".into(), &store).unwrap(),
-				store,
-			};
-
-			let mut big_old_test = BincodeOrganization::create(
-				earth.location.clone(),
-				"Big Old Test Corporation".into(),
-				&store,
-			).unwrap();
-
-			let testy = BincodePerson
-			{
-				person: &BincodePerson::create(
-					"Testy Mćtesterson".into(),
-					&store,
-				).unwrap(),
-				store,
-			};
-
-			let ceo_testy = BincodeEmployee
-			{
-				employee: &BincodeEmployee::create(
-					vec![("Work".into(), Contact::Address {location_id: earth.location.id, export: false})].into_iter().collect(),
-					big_old_test.clone(),
-					testy.person.clone(),
-					EmployeeStatus::Employed,
-					"CEO of Tests".into(),
-					&store,
-				).unwrap(),
-				store,
-			};
-
-			let mut creation = BincodeJob::create(
-				big_old_test.clone(),
-				Utc::now(),
-				Money::new(2_00, 2, Currency::USD),
-				"Test the job creation function".into(),
-				&store,
-			).unwrap();
-
-			creation.start_timesheet(ceo_testy.employee.id);
-			BincodeJob {job: &creation, store}.update().unwrap();
-
-			let start = Instant::now();
-			// Assert that the deletion fails when restricted
-			assert!(ceo_testy.delete(false).is_err());
-			// Assert that the deletion works when cascading
-			assert!(ceo_testy.delete(true).is_ok());
-			println!("\n>>>>> BincodeEmployee::delete {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / 2);
-
-			// Assert the deleted file is gone.
-			assert!(!ceo_testy.filepath().is_file());
-
-			// Assert that the relevant files still exist
-			assert!(BincodeOrganization {organization: &big_old_test, store}.filepath().is_file());
-			assert!(BincodeJob {job: &creation, store}.filepath().is_file());
-			assert!(earth.filepath().is_file());
-			assert!(testy.filepath().is_file());
-
-			big_old_test = BincodeOrganization::retrieve(
-				&query::Organization
-				{
-					id: query::Match::EqualTo(Borrowed(&big_old_test.id)),
-					..Default::default()
-				},
-				&store,
-			).unwrap().iter().next().unwrap().clone();
-
-			creation = BincodeJob::retrieve(
-				&query::Job
-				{
-					client: query::Organization
-					{
-						id: query::Match::EqualTo(Borrowed(&big_old_test.id)),
-						..Default::default()
-					},
-					id: query::Match::EqualTo(Borrowed(&creation.id)),
-					..Default::default()
-				},
-				&store,
-			).unwrap().iter().next().unwrap().clone();
-
-			// Assert that no references to the deleted entity remain.
-			assert!(creation.timesheets.iter().all(|t| t.employee_id != ceo_testy.employee.id));
-		});
-	}
-}
@@ -6,7 +6,7 @@ use
 	crate::
 	{
 		data::{Error, Result},
-		util,
+		migrate, util,
 	},
 };
 
@@ -19,6 +19,7 @@ impl Initializable for BincodeEmployee<'_, '_>
 	/// Initialize the database for a given [`Store`].
 	fn init(store: &Store) -> Result<()>
 	{
+		migrate::migrate(store)?;
 		util::create_store_dir(&Self::path(store))?;
 		Ok(())
 	}
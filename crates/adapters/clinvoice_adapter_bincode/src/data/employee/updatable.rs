@@ -14,6 +14,8 @@ impl Updatable for BincodeEmployee<'_, '_>
 
 	fn update(&self) -> Result<()>
 	{
+		self.store.assert_writable()?;
+
 		let serialized = bincode::serialize(&self.employee)?;
 		fs::write(self.filepath(), serialized)?;
 		Ok(())
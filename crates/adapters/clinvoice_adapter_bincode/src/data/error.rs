@@ -2,7 +2,7 @@ use
 {
 	std::io,
 
-	clinvoice_adapter::data,
+	clinvoice_adapter::{data, Error as AdapterError},
 
 	thiserror::Error,
 };
@@ -10,6 +10,9 @@ use
 #[derive(Debug, Error)]
 pub enum Error
 {
+	#[error("{0}")]
+	Adapter(#[from] AdapterError),
+
 	#[error("{0}")]
 	Bincode(#[from] bincode::Error),
 
@@ -1,5 +1,7 @@
 use
 {
+	std::{collections::BTreeMap, fs, io, path::Path},
+
 	super::BincodeJob,
 	crate::
 	{
@@ -9,13 +11,13 @@ use
 
 	clinvoice_adapter::
 	{
-		data::{Error as DataError, Initializable, JobAdapter, Updatable},
+		data::{Error as DataError, Initializable, JobAdapter, Sequence, Updatable},
 		Store
 	},
 	clinvoice_data::
 	{
 		chrono::{DateTime, Utc},
-		Invoice, Job, finance::Money, Organization
+		Attachment, BillingModel, Job, Organization
 	},
 	clinvoice_query as query,
 };
@@ -24,6 +26,16 @@ impl JobAdapter for BincodeJob<'_, '_>
 {
 	type Error = Error;
 
+	/// # Summary
+	///
+	/// Copy the file at some `path` into the attachments of the `store`.
+	fn attach(path: &Path, store: &Store) -> Result<Attachment>
+	{
+		store.assert_writable()?;
+
+		util::attach(path, store).map_err(Error::from)
+	}
+
 	/// # Summary
 	///
 	/// Create a new [`Person`] on the active [`Store`](crate::Store).
@@ -38,26 +50,32 @@ impl JobAdapter for BincodeJob<'_, '_>
 	fn create(
 		client: Organization,
 		date_open: DateTime<Utc>,
-		hourly_rate: Money,
+		billing_model: BillingModel,
 		objectives: String,
 		store: &Store,
 	) -> Result<Job>
 	{
+		store.assert_writable()?;
+
 		Self::init(&store)?;
 
 		let job = Job
 		{
+			adjustments: Vec::new(),
+			bill_to: Default::default(),
+			billing_model,
 			client_id: client.id,
 			date_close: None,
 			date_open,
+			employee_default_rates: Default::default(),
+			employee_rates: Default::default(),
+			expense_markup: client.expense_markup,
 			id: util::unique_id(&Self::path(&store))?,
-			invoice: Invoice
-			{
-				date: None,
-				hourly_rate,
-			},
+			invoices: Vec::new(),
 			objectives,
 			notes: "".into(),
+			overtime: client.overtime,
+			rate_changes: Vec::new(),
 			timesheets: Vec::new(),
 		};
 
@@ -68,6 +86,14 @@ impl JobAdapter for BincodeJob<'_, '_>
 		Ok(job)
 	}
 
+	/// # Summary
+	///
+	/// Read the contents of some `attachment` from the `store`.
+	fn read_attachment(attachment: &Attachment, store: &Store) -> Result<Vec<u8>>
+	{
+		fs::read(util::attachments_path(store).join(&attachment.hash)).map_err(Error::from)
+	}
+
 	/// # Summary
 	///
 	/// Retrieve some [`Person`] from the active [`Store`](crate::Store).
@@ -86,176 +112,28 @@ impl JobAdapter for BincodeJob<'_, '_>
 
 		util::retrieve(Self::path(store), |j| query.matches(j).map_err(|e| DataError::from(e).into()))
 	}
-}
 
-#[cfg(test)]
-mod tests
-{
-	use
+	/// # Summary
+	///
+	/// Take the next position in some `sequence` of the `store`, and record that it was taken.
+	fn take_seq(sequence: Sequence, issued: u64, store: &Store) -> Result<u64>
 	{
-		std::{borrow::Cow::Borrowed, fs, time::Instant},
+		store.assert_writable()?;
 
-		super::{BincodeJob, Job, JobAdapter, Money, Organization, query, Store, Utc, util},
+		Self::init(store)?;
 
-		clinvoice_query::Match,
-		clinvoice_data::{finance::Currency, Id},
-	};
-
-	#[test]
-	fn create()
-	{
-		let organization = Organization
+		let filepath = util::expand_store_path(store).join("Sequences");
+		let mut sequences: BTreeMap<Sequence, u64> = match fs::File::open(&filepath)
 		{
-			id: Id::new_v4(),
-			location_id: Id::new_v4(),
-			name: "Big Old Test Corporation".into(),
+			Ok(file) => bincode::deserialize_from(io::BufReader::new(file))?,
+			Err(e) if e.kind() == io::ErrorKind::NotFound => BTreeMap::new(),
+			Err(e) => return Err(e.into()),
 		};
 
-		util::temp_store(|store|
-		{
-			let start = Instant::now();
-
-			create_assertion(
-				BincodeJob::create(
-					organization.clone(),
-					Utc::now(),
-					Money::new(2_00, 2, Currency::USD),
-					"Test the job creation function".into(),
-					&store,
-				).unwrap(),
-				&store,
-			);
-
-			create_assertion(
-				BincodeJob::create(
-					organization.clone(),
-					Utc::now(),
-					Money::new(2_00, 2, Currency::USD),
-					"Test the job creation function".into(),
-					&store,
-				).unwrap(),
-				&store,
-			);
-
-			create_assertion(
-				BincodeJob::create(
-					organization.clone(),
-					Utc::now(),
-					Money::new(20000, 0, Currency::JPY),
-					"TEST THE JOB CREATION FUNCTION".into(),
-					&store,
-				).unwrap(),
-				&store,
-			);
-
-			create_assertion(
-				BincodeJob::create(
-					organization.clone(),
-					Utc::now(),
-					Money::new(5_00, 2, Currency::CAD),
-					"test the job creation function".into(),
-					&store,
-				).unwrap(),
-				&store,
-			);
-
-			create_assertion(
-				BincodeJob::create(
-					organization.clone(),
-					Utc::now(),
-					Money::new(10_00, 2, Currency::EUR),
-					"TeSt ThE jOb CrEaTiOn FuNcTiOn".into(),
-					&store,
-				).unwrap(),
-				&store,
-			);
-
-			println!("\n>>>>> BincodeJob::create {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / 5);
-		});
-	}
-
-	fn create_assertion(job: Job, store: &Store)
-	{
-		let read_result = fs::read(BincodeJob {job: &job, store}.filepath()).unwrap();
-		assert_eq!(job, bincode::deserialize(&read_result).unwrap());
-	}
-
-	#[test]
-	fn retrieve()
-	{
-		let organization = Organization
-		{
-			id: Id::new_v4(),
-			location_id: Id::new_v4(),
-			name: "Big Old Test Corporation".into(),
-		};
-
-		util::temp_store(|store|
-		{
-			let creation = BincodeJob::create(
-				organization.clone(),
-				Utc::now(),
-				Money::new(2_00, 2, Currency::USD),
-				"Test the job creation function".into(),
-				&store,
-			).unwrap();
-
-			let retrieval = BincodeJob::create(
-				organization.clone(),
-				Utc::now(),
-				Money::new(2_00, 2, Currency::USD),
-				"Test the job retrieval function".into(),
-				&store,
-			).unwrap();
-
-			let assertion = BincodeJob::create(
-				organization.clone(),
-				Utc::now(),
-				Money::new(20000, 0, Currency::JPY),
-				"Assert something".into(),
-				&store,
-			).unwrap();
-
-			let start = Instant::now();
-
-			// retrieve everything
-			let everything = BincodeJob::retrieve(
-				&query::Job
-				{
-					client: query::Organization
-					{
-						id: Match::EqualTo(Borrowed(&organization.id)),
-						..Default::default()
-					},
-					..Default::default()
-				},
-				&store,
-			).unwrap();
-
-			// retrieve retrieval and assertion
-			let not_creation = BincodeJob::retrieve(
-				&query::Job
-				{
-					date_open: Match::Not(Match::HasAny(vec![
-					  Borrowed(&creation.date_open.naive_local()),
-					].into_iter().collect()).into()),
-					id: Match::HasAny(vec![Borrowed(&retrieval.id), Borrowed(&assertion.id)].into_iter().collect()),
-					..Default::default()
-				},
-				&store,
-			).unwrap();
-
-			println!("\n>>>>> BincodeJob::retrieve {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / 2);
-
-			// assert the results are as expected
-			assert!(everything.contains(&assertion));
-			assert!(everything.contains(&creation));
-			assert!(everything.contains(&retrieval));
+		let position = sequences.get(&sequence).copied().unwrap_or_default().max(issued) + 1;
+		sequences.insert(sequence, position);
+		fs::write(filepath, bincode::serialize(&sequences)?)?;
 
-			// assert the results are as expected
-			assert!(not_creation.contains(&assertion));
-			assert!(!not_creation.contains(&creation));
-			assert!(not_creation.contains(&retrieval));
-		});
+		Ok(position)
 	}
 }
@@ -14,6 +14,8 @@ impl Updatable for BincodeJob<'_, '_>
 
 	fn update(&self) -> Result<()>
 	{
+		self.store.assert_writable()?;
+
 		let serialized = bincode::serialize(&self.job)?;
 		fs::write(self.filepath(), serialized)?;
 		Ok(())
@@ -16,6 +16,8 @@ impl Deletable for BincodeLocation<'_, '_>
 
 	fn delete(&self, cascade: bool) -> Result<()>
 	{
+		self.store.assert_writable()?;
+
 		let associated_locations = || -> Result<Vec<Location>>
 		{
 			BincodeLocation::retrieve(
@@ -58,7 +60,7 @@ impl Deletable for BincodeLocation<'_, '_>
 				|l| BincodeLocation {location: &l, store: self.store}.delete(cascade)
 			)?;
 		}
-		else if !(associated_organizations.is_empty() || associated_locations()?.is_empty())
+		else if !(associated_organizations.is_empty() && associated_locations()?.is_empty())
 		{
 			return Err(DataError::DeleteRestricted(self.location.id).into());
 		}
@@ -75,87 +77,3 @@ impl Deletable for BincodeLocation<'_, '_>
 		Ok(())
 	}
 }
-
-#[cfg(test)]
-mod tests
-{
-	use
-	{
-		std::time::Instant,
-
-		super::{BincodeLocation, Deletable, LocationAdapter},
-		crate::{data::BincodeOrganization, util},
-
-		clinvoice_adapter::data::OrganizationAdapter,
-	};
-
-	#[test]
-	fn delete()
-	{
-		util::temp_store(|store|
-		{
-			let earth = BincodeLocation
-			{
-				location: &BincodeLocation::create("Earth".into(), store).unwrap(),
-				store,
-			};
-
-			let usa = BincodeLocation
-			{
-				location: &earth.create_inner("USA".into()).unwrap(),
-				store,
-			};
-
-			let arizona = BincodeLocation
-			{
-				location: &usa.create_inner("Arizona".into()).unwrap(),
-				store,
-			};
-
-			let phoenix = BincodeLocation
-			{
-				location: &arizona.create_inner("Phoenix".into()).unwrap(),
-				store,
-			};
-
-			let dogood = BincodeOrganization
-			{
-				organization: &BincodeOrganization::create(
-					arizona.location.clone(),
-					"DoGood Inc".into(),
-					&store
-				).unwrap(),
-				store,
-			};
-
-			let start = Instant::now();
-
-			// delete just phoenix.
-			phoenix.delete(false).unwrap();
-
-			// assert that phoenix is gone.
-			assert!(!phoenix.filepath().is_file());
-
-			// Assert that every location inside the USA is there
-			assert!(earth.filepath().is_file());
-			assert!(usa.filepath().is_file());
-			assert!(arizona.filepath().is_file());
-
-			// assert that `dogood`, located in arizona, is there
-			assert!(dogood.filepath().is_file());
-
-			// delete the usa and everything in it.
-			usa.delete(true).unwrap();
-
-			println!("\n>>>>> BincodeLocation::delete {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / 2);
-
-			// Assert that every location inside the USA is gone
-			assert!(earth.filepath().is_file());
-			assert!(!usa.filepath().is_file());
-			assert!(!arizona.filepath().is_file());
-
-			// assert that `dogood`, located in arizona, is gone.
-			assert!(!dogood.filepath().is_file());
-		});
-	}
-}
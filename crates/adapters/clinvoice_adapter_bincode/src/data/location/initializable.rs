@@ -4,7 +4,7 @@ use
 	crate::
 	{
 		data::{Error, Result},
-		util,
+		migrate, util,
 	},
 
 	clinvoice_adapter::{data::Initializable, Store},
@@ -19,6 +19,7 @@ impl Initializable for BincodeLocation<'_, '_>
 	/// Initialize the database for a given [`Store`].
 	fn init(store: &Store) -> Result<()>
 	{
+		migrate::migrate(store)?;
 		util::create_store_dir(&Self::path(store))?;
 		Ok(())
 	}
@@ -35,13 +35,17 @@ impl LocationAdapter for BincodeLocation<'_, '_>
 	/// ```
 	fn create(name: String, store: &Store) -> Result<Location>
 	{
+		store.assert_writable()?;
+
 		Self::init(&store)?;
 
 		let location = Location
 		{
+			address: None,
 			id: util::unique_id(&Self::path(&store))?,
 			name,
 			outer_id: None,
+			taxes: None,
 		};
 
 		BincodeLocation {location: &location, store}.update()?;
@@ -66,9 +70,11 @@ impl LocationAdapter for BincodeLocation<'_, '_>
 	{
 		let inner_location = Location
 		{
+			address: None,
 			id: util::unique_id(&Self::path(&self.store))?,
 			name,
 			outer_id: Some(self.location.id),
+			taxes: None,
 		};
 
 		BincodeLocation {location: &inner_location, store: self.store}.update()?;
@@ -95,87 +101,3 @@ impl LocationAdapter for BincodeLocation<'_, '_>
 		util::retrieve(Self::path(store), |l| query.matches(l).map_err(|e| DataError::from(e).into()))
 	}
 }
-
-#[cfg(test)]
-mod tests
-{
-	use
-	{
-		std::{borrow::Cow::Borrowed, fs, time::Instant},
-
-		super::{BincodeLocation, Location, LocationAdapter, query, Store, util},
-
-		clinvoice_query::Match,
-	};
-
-	#[test]
-	fn create()
-	{
-		util::temp_store(|store|
-		{
-			let start = Instant::now();
-			let earth = BincodeLocation::create("Earth".into(), &store).unwrap();
-			let usa = BincodeLocation {location: &earth, store}.create_inner("USA".into()).unwrap();
-			let arizona = BincodeLocation {location: &usa, store}.create_inner("Arizona".into()).unwrap();
-			let phoenix = BincodeLocation {location: &arizona, store}.create_inner("Phoenix".into()).unwrap();
-			println!("\n>>>>> BincodeLocation::start {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / 4);
-
-			assert_eq!(usa.outer_id, Some(earth.id));
-			assert_eq!(arizona.outer_id, Some(usa.id));
-			assert_eq!(phoenix.outer_id, Some(arizona.id));
-			create_assertion(earth, &store);
-			create_assertion(usa, &store);
-			create_assertion(arizona, &store);
-			create_assertion(phoenix, &store);
-		});
-	}
-
-	/// The assertion most commonly used for the [`create` test](test_create).
-	fn create_assertion(location: Location, store: &Store)
-	{
-		let read_result = fs::read(BincodeLocation {location: &location, store}.filepath()).unwrap();
-		assert_eq!(location, bincode::deserialize(&read_result).unwrap());
-	}
-
-	#[test]
-	fn retrieve()
-	{
-		util::temp_store(|store|
-		{
-			let earth = BincodeLocation::create("Earth".into(), &store).unwrap();
-			let usa = BincodeLocation {location: &earth, store}.create_inner("USA".into()).unwrap();
-			let arizona = BincodeLocation {location: &usa, store}.create_inner("Arizona".into()).unwrap();
-			let phoenix = BincodeLocation {location: &arizona, store}.create_inner("Phoenix".into()).unwrap();
-
-			let start = Instant::now();
-
-			// Retrieve everything.
-			let everything = BincodeLocation::retrieve(&Default::default(), &store).unwrap();
-
-			// Retrieve Arizona
-			let only_arizona = BincodeLocation::retrieve(
-				&query::Location
-				{
-					id: Match::HasAny(vec![Borrowed(&earth.id), Borrowed(&arizona.id)].into_iter().collect()),
-					outer: query::OuterLocation::Some(query::Location::default().into()),
-					..Default::default()
-				},
-				&store,
-			).unwrap();
-
-			println!("\n>>>>> BincodeLocation::retrieve {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / 2);
-
-			// Assert the results contains all values
-			assert!(everything.contains(&earth));
-			assert!(everything.contains(&usa));
-			assert!(everything.contains(&arizona));
-			assert!(everything.contains(&phoenix));
-
-			// Assert the results contains all values
-			assert!(!only_arizona.contains(&earth));
-			assert!(!only_arizona.contains(&usa));
-			assert!(only_arizona.contains(&arizona));
-			assert!(!only_arizona.contains(&phoenix));
-		})
-	}
-}
@@ -14,6 +14,8 @@ impl Updatable for BincodeLocation<'_, '_>
 
 	fn update(&self) -> Result<()>
 	{
+		self.store.assert_writable()?;
+
 		let serialized = bincode::serialize(&self.location)?;
 		fs::write(self.filepath(), serialized)?;
 		Ok(())
@@ -20,3 +20,5 @@ pub use
 	organization::BincodeOrganization,
 	person::BincodePerson,
 };
+
+clinvoice_adapter::TestKit!(crate::util::temp_store => BincodeEmployee, BincodeJob, BincodeLocation, BincodeOrganization, BincodePerson);
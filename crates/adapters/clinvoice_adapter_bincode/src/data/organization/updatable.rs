@@ -14,6 +14,8 @@ impl Updatable for BincodeOrganization<'_, '_>
 
 	fn update(&self) -> Result<()>
 	{
+		self.store.assert_writable()?;
+
 		let serialized = bincode::serialize(&self.organization)?;
 		fs::write(self.filepath(), serialized)?;
 		Ok(())
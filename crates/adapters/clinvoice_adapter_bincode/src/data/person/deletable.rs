@@ -15,6 +15,8 @@ impl Deletable for BincodePerson<'_, '_>
 
 	fn delete(&self, cascade: bool) -> Result<()>
 	{
+		self.store.assert_writable()?;
+
 		let associated_employees = BincodeEmployee::retrieve(
 			&query::Employee
 			{
@@ -51,82 +53,3 @@ impl Deletable for BincodePerson<'_, '_>
 		Ok(())
 	}
 }
-
-#[cfg(test)]
-mod tests
-{
-	use
-	{
-		std::time::Instant,
-
-		super::{BincodeEmployee, BincodePerson, Deletable, EmployeeAdapter},
-		crate::
-		{
-			data::{BincodeLocation, BincodeOrganization},
-			util,
-		},
-
-		clinvoice_adapter::data::{LocationAdapter, OrganizationAdapter, PersonAdapter},
-		clinvoice_data::{Contact, EmployeeStatus},
-	};
-
-	#[test]
-	fn delete()
-	{
-		util::temp_store(|store|
-		{
-			let earth = BincodeLocation
-			{
-				location: &BincodeLocation::create("Earth".into(), &store).unwrap(),
-				store,
-			};
-
-			let big_old_test = BincodeOrganization
-			{
-				organization: &BincodeOrganization::create(
-					earth.location.clone(),
-					"Big Old Test Corporation".into(),
-					&store,
-				).unwrap(),
-				store,
-			};
-
-			let testy = BincodePerson
-			{
-				person: &BincodePerson::create(
-					"Testy Mćtesterson".into(),
-					&store,
-				).unwrap(),
-				store,
-			};
-
-			let ceo_testy = BincodeEmployee
-			{
-				employee: &BincodeEmployee::create(
-					vec![("Office".into(), Contact::Address {location_id: earth.location.id, export: false})].into_iter().collect(),
-					big_old_test.organization.clone(),
-					testy.person.clone(),
-					EmployeeStatus::Employed,
-					"CEO of Tests".into(),
-					&store,
-				).unwrap(),
-				store,
-			};
-
-			let start = Instant::now();
-			// Assert that the deletion fails when restricted
-			assert!(testy.delete(false).is_err());
-			// Assert that the deletion works when cascading
-			assert!(testy.delete(true).is_ok());
-			println!("\n>>>>> BincodePerson::delete {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / 2);
-
-			// Assert that `testy` and its referencing employee is gone.
-			assert!(!testy.filepath().is_file());
-			assert!(!ceo_testy.filepath().is_file());
-
-			// Assert that the independent files still exist.
-			assert!(big_old_test.filepath().is_file());
-			assert!(earth.filepath().is_file());
-		});
-	}
-}
@@ -33,6 +33,8 @@ impl PersonAdapter for BincodePerson<'_, '_>
 	/// The newly created [`Person`].
 	fn create(name: String, store: &Store,) -> Result<Person>
 	{
+		store.assert_writable()?;
+
 		Self::init(&store)?;
 
 		let person = Person
@@ -65,136 +67,3 @@ impl PersonAdapter for BincodePerson<'_, '_>
 		util::retrieve(Self::path(store), |p| query.matches(p).map_err(|e| DataError::from(e).into()))
 	}
 }
-
-#[cfg(test)]
-mod tests
-{
-	use
-	{
-		std::{borrow::Cow::Borrowed, fs, time::Instant},
-
-		super::{BincodePerson, Person, PersonAdapter, query, Store, util},
-
-		clinvoice_query::{Match, MatchStr},
-	};
-
-	#[test]
-	fn create()
-	{
-		util::temp_store(|store|
-		{
-			let start = Instant::now();
-
-			create_assertion(
-				BincodePerson::create(
-					"Widdle".into(),
-					&store,
-				).unwrap(),
-				&store,
-			);
-
-			create_assertion(
-				BincodePerson::create(
-					"Long".into(),
-					&store,
-				).unwrap(),
-				&store,
-			);
-
-			create_assertion(
-				BincodePerson::create(
-					"Steven".into(),
-					&store,
-				).unwrap(),
-				&store,
-			);
-
-			create_assertion(
-				BincodePerson::create(
-					"JingleBob".into(),
-					&store,
-				).unwrap(),
-				&store,
-			);
-
-			create_assertion(
-				BincodePerson::create(
-					"asldkj jdsoai".into(),
-					&store,
-				).unwrap(),
-				&store,
-			);
-
-			println!("\n>>>>> BincodePerson::create {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / 5);
-		});
-	}
-
-	fn create_assertion(person: Person, store: &Store)
-	{
-		let read_result = fs::read(BincodePerson {person: &person, store}.filepath()).unwrap();
-		assert_eq!(person, bincode::deserialize(&read_result).unwrap());
-	}
-
-	#[test]
-	fn retrieve()
-	{
-		util::temp_store(|store|
-		{
-			let flingo = BincodePerson::create(
-				"flingo".into(),
-				&store
-			).unwrap();
-
-			let bob = BincodePerson::create(
-				"bob".into(),
-				&store
-			).unwrap();
-
-			let slimdi = BincodePerson::create(
-				"slimdi".into(),
-				&store
-			).unwrap();
-
-			let longone = BincodePerson::create(
-				"longone".into(),
-				&store
-			).unwrap();
-
-			let start = Instant::now();
-
-			// Retrieve bob
-			let only_bob = BincodePerson::retrieve(
-				&query::Person
-				{
-					id: Match::EqualTo(Borrowed(&bob.id)),
-					..Default::default()
-				},
-				&store,
-			).unwrap();
-
-			// Retrieve longone and slimdi
-			let longone_slimdi = BincodePerson::retrieve(
-				&query::Person
-				{
-					name: MatchStr::Regex(format!("^({}|{})$", longone.name, slimdi.name)),
-					..Default::default()
-				},
-				&store,
-			).unwrap();
-
-			println!("\n>>>>> BincodePerson::retrieve {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / 2);
-
-			// Assert bob is the only one retrieved
-			assert!(!only_bob.contains(&flingo));
-			assert!(only_bob.contains(&bob));
-			assert!(!only_bob.contains(&slimdi));
-			assert!(!only_bob.contains(&longone));
-
-			// Assert bob is the only one retrieved
-			assert!(!longone_slimdi.contains(&flingo));
-			assert!(!longone_slimdi.contains(&bob));
-			assert!(longone_slimdi.contains(&slimdi));
-			assert!(longone_slimdi.contains(&longone));
-		});
-	}
-}
@@ -14,6 +14,8 @@ impl Updatable for BincodePerson<'_, '_>
 
 	fn update(&self) -> Result<()>
 	{
+		self.store.assert_writable()?;
+
 		let serialized = bincode::serialize(&self.person)?;
 		fs::write(self.filepath(), serialized)?;
 		Ok(())
@@ -5,4 +5,5 @@
 #![allow(clippy::from_over_into)]
 
 pub mod data;
+mod migrate;
 mod util;
@@ -0,0 +1,242 @@
+//! # Summary
+//!
+//! This module upgrades [`Store`]s which were written with an older layout to the current one.
+//!
+//! # Remarks
+//!
+//! Bincode is not self-describing, so a [`Store`] can only be read with the layout it was written
+//! in. The version of that layout is kept in the `Version` file of the [`Store`], and a [`Store`]
+//! which has data but no `Version` was written before it was versioned.
+
+mod v0;
+
+use
+{
+	std::{fs, io, path::{Path, PathBuf}},
+
+	crate::
+	{
+		data::{BincodeEmployee, BincodeJob, BincodeLocation, BincodeOrganization, Result},
+		util,
+	},
+
+	clinvoice_adapter::Store,
+	clinvoice_data::{Employee, Job, Location, Organization},
+
+	serde::{de::DeserializeOwned, Serialize},
+};
+
+/// # Summary
+///
+/// The version of the layout which this adapter writes.
+const VERSION: u32 = 1;
+
+/// # Summary
+///
+/// Upgrade the `store` to the current layout, if it was written with an older one.
+///
+/// # Errors
+///
+/// * An [`Error::ReadOnly`](clinvoice_adapter::Error::ReadOnly), if the `store` must be upgraded
+///   but may not be written to.
+/// * When something in the `store` cannot be read in the layout it claims to have been written in.
+pub fn migrate(store: &Store) -> Result<()>
+{
+	let root = util::expand_store_path(store);
+	let version_path = root.join("Version");
+
+	let version: u32 = match fs::read(&version_path)
+	{
+		Ok(serialized) => bincode::deserialize(&serialized)?,
+		Err(e) if e.kind() == io::ErrorKind::NotFound =>
+		{
+			let has_data = [BincodeEmployee::path(store), BincodeJob::path(store), BincodeLocation::path(store), BincodeOrganization::path(store)]
+				.iter()
+				.any(|dir| fs::read_dir(dir).map(|mut nodes| nodes.next().is_some()).unwrap_or(false));
+
+			if has_data { 0 } else { VERSION }
+		},
+		Err(e) => return Err(e.into()),
+	};
+
+	match version
+	{
+		VERSION if version_path.is_file() || store.read_only => return Ok(()),
+		_ => store.assert_writable()?,
+	};
+
+	if version == 0
+	{
+		// Everything is read before anything is written, so that a `store` which cannot be read is
+		// left as it was.
+		let employees = read::<v0::Employee, Employee>(&BincodeEmployee::path(store))?;
+		let jobs = read::<v0::Job, Job>(&BincodeJob::path(store))?;
+		let locations = read::<v0::Location, Location>(&BincodeLocation::path(store))?;
+		let organizations = read::<v0::Organization, Organization>(&BincodeOrganization::path(store))?;
+
+		write(employees)?;
+		write(jobs)?;
+		write(locations)?;
+		write(organizations)?;
+	}
+
+	util::create_store_dir(&root)?;
+	fs::write(version_path, bincode::serialize(&VERSION)?)?;
+
+	Ok(())
+}
+
+/// # Summary
+///
+/// Read every `Old` entity in some `dir`, and upgrade it to a `New` one.
+///
+/// # Returns
+///
+/// The path of each entity, along with its upgraded version.
+fn read<Old, New>(dir: &Path) -> Result<Vec<(PathBuf, New)>> where
+	New : From<Old>,
+	Old : DeserializeOwned,
+{
+	if !dir.is_dir()
+	{
+		return Ok(Vec::new());
+	}
+
+	fs::read_dir(dir)?.filter_map(|node| node.ok().map(|n| n.path()).filter(|path| path.is_file())).map(|path|
+	{
+		let old: Old = bincode::deserialize(&fs::read(&path)?)?;
+		Ok((path, old.into()))
+	}).collect()
+}
+
+/// # Summary
+///
+/// Overwrite each entity which was [`read`] with its upgraded version.
+fn write<T>(entities: Vec<(PathBuf, T)>) -> Result<()> where
+	T : Serialize,
+{
+	entities.into_iter().try_for_each(|(path, entity)|
+	{
+		fs::write(path, bincode::serialize(&entity)?)?;
+		Ok(())
+	})
+}
+
+#[cfg(test)]
+mod tests
+{
+	use
+	{
+		std::{borrow::Cow::Borrowed, collections::HashMap, fs, time::Instant},
+
+		super::{v0, PathBuf, BincodeEmployee, BincodeJob, BincodeLocation, BincodeOrganization, util},
+		crate::data::BincodePerson,
+
+		clinvoice_adapter::data::{EmployeeAdapter, JobAdapter, LocationAdapter, OrganizationAdapter, PersonAdapter},
+		clinvoice_data::
+		{
+			chrono::{Duration, Utc},
+			finance::{Currency, Money},
+			BillingModel, Contact, EmployeeStatus, ExpenseCategory, Id, InvoiceDate, Person,
+		},
+		clinvoice_query::{self as query, Match},
+	};
+
+	#[test]
+	fn migrate()
+	{
+		util::temp_store(|store|
+		{
+			let earth = v0::Location {id: Id::new_v4(), outer_id: None, name: "Earth".into()};
+			let big_old_test = v0::Organization {id: Id::new_v4(), location_id: earth.id, name: "Big Old Test Corporation".into()};
+			let testy = Person {id: Id::new_v4(), name: "Testy Mćtesterson".into()};
+			let ceo_testy = v0::Employee
+			{
+				contact_info: vec![("Office".into(), v0::Contact::Phone {phone: "555-555-5555".into(), export: true})].into_iter().collect::<HashMap<_, _>>(),
+				id: Id::new_v4(),
+				organization_id: big_old_test.id,
+				person_id: testy.id,
+				status: EmployeeStatus::Employed,
+				title: "CEO of Tests".into(),
+			};
+
+			let issued = Utc::now();
+			let job = v0::Job
+			{
+				client_id: big_old_test.id,
+				date_close: Some(issued),
+				date_open: issued - Duration::hours(2),
+				id: Id::new_v4(),
+				invoice: v0::Invoice
+				{
+					date: Some(InvoiceDate {issued, paid: None}),
+					hourly_rate: Money::new(20_00, 2, Currency::USD),
+				},
+				notes: "".into(),
+				objectives: "Test the migration".into(),
+				timesheets: vec![v0::Timesheet
+				{
+					employee_id: ceo_testy.id,
+					expenses: vec![v0::Expense
+					{
+						category: v0::ExpenseCategory::Travel,
+						cost: Money::new(5_00, 2, Currency::USD),
+						description: "Bus fare".into(),
+					}],
+					time_begin: issued - Duration::hours(1),
+					time_end: Some(issued),
+					work_notes: "Wrote tests".into(),
+				}],
+			};
+
+			// Write the store as it was before it was versioned.
+			let write = |dir: PathBuf, id: Id, serialized: Vec<u8>|
+			{
+				util::create_store_dir(&dir).unwrap();
+				fs::write(dir.join(id.to_string()), serialized).unwrap();
+			};
+
+			write(BincodeEmployee::path(store), ceo_testy.id, bincode::serialize(&ceo_testy).unwrap());
+			write(BincodeJob::path(store), job.id, bincode::serialize(&job).unwrap());
+			write(BincodeLocation::path(store), earth.id, bincode::serialize(&earth).unwrap());
+			write(BincodeOrganization::path(store), big_old_test.id, bincode::serialize(&big_old_test).unwrap());
+			write(BincodePerson::path(store), testy.id, bincode::serialize(&testy).unwrap());
+
+			let start = Instant::now();
+			let jobs = BincodeJob::retrieve(&Default::default(), store).unwrap();
+			println!("\n>>>>> migrate::migrate {}us <<<<<\n", Instant::now().duration_since(start).as_micros());
+
+			assert_eq!(jobs.len(), 1);
+			assert_eq!(jobs[0].billing_model, BillingModel::Hourly(job.invoice.hourly_rate));
+			assert_eq!(jobs[0].date_close, job.date_close);
+			assert_eq!(jobs[0].invoices.len(), 1);
+			assert_eq!(jobs[0].invoices[0].date, job.invoice.date);
+			assert_eq!(jobs[0].timesheets[0].expenses[0].category, ExpenseCategory::new("Travel"));
+			assert_eq!(jobs[0].timesheets[0].invoice_id, Some(jobs[0].invoices[0].id));
+			assert!(!jobs[0].has_unbilled_work(Utc::now()));
+
+			let employees = BincodeEmployee::retrieve(&Default::default(), store).unwrap();
+			assert_eq!(employees[0].contact_info["Office"], Contact::Phone {phone: "555-555-5555".into(), export: true});
+			assert_eq!(employees[0].status, EmployeeStatus::Employed);
+
+			let organizations = BincodeOrganization::retrieve(&Default::default(), store).unwrap();
+			assert_eq!(organizations[0].name, big_old_test.name);
+
+			let locations = BincodeLocation::retrieve(&Default::default(), store).unwrap();
+			assert_eq!(locations[0].name, earth.name);
+
+			let people = BincodePerson::retrieve(
+				&query::Person
+				{
+					id: Match::EqualTo(Borrowed(&testy.id)),
+					..Default::default()
+				},
+				store,
+			).unwrap();
+			assert_eq!(people, vec![testy]);
+
+			// The store is only migrated once.
+			assert_eq!(BincodeJob::retrieve(&Default::default(), store).unwrap(), jobs);
+		});
+	}
+}
@@ -0,0 +1,296 @@
+//! # Summary
+//!
+//! The layout of a [`Store`](clinvoice_adapter::Store) before it was versioned, when every
+//! [`Job`] was billed by the hour on a single [`Invoice`], and [`ExpenseCategory`]s were fixed.
+//!
+//! # Remarks
+//!
+//! [`Person`](clinvoice_data::Person)s have not changed since, so they are not declared here.
+
+use
+{
+	std::collections::HashMap,
+
+	clinvoice_data::
+	{
+		chrono::{DateTime, Utc},
+		finance::Money,
+		BillingModel, EmployeeStatus, Id, InvoiceDate,
+	},
+
+	serde::Deserialize,
+};
+
+#[cfg(test)]
+use serde::Serialize;
+
+/// # Summary
+///
+/// A [`Contact`](clinvoice_data::Contact), before fax, messaging, and website contacts.
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub enum Contact
+{
+	Address {location_id: Id, export: bool},
+	Email {email: String, export: bool},
+	Phone {phone: String, export: bool},
+}
+
+/// # Summary
+///
+/// An [`Employee`](clinvoice_data::Employee), before hourly rates and employment changes.
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct Employee
+{
+	pub contact_info: HashMap<String, Contact>,
+	pub id: Id,
+	pub organization_id: Id,
+	pub person_id: Id,
+	pub status: EmployeeStatus,
+	pub title: String,
+}
+
+/// # Summary
+///
+/// An [`Expense`](clinvoice_data::Expense), before it could be attached to or left unbilled.
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct Expense
+{
+	pub category: ExpenseCategory,
+	pub cost: Money,
+	pub description: String,
+}
+
+/// # Summary
+///
+/// The fixed categories of an [`Expense`], before they could be defined.
+#[derive(Copy, Clone, Debug, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub enum ExpenseCategory
+{
+	Food,
+	Item,
+	Other,
+	Service,
+	Software,
+	Travel,
+}
+
+/// # Summary
+///
+/// The only [`Invoice`](clinvoice_data::Invoice) of a [`Job`], which also held its rate.
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct Invoice
+{
+	pub date: Option<InvoiceDate>,
+	pub hourly_rate: Money,
+}
+
+/// # Summary
+///
+/// A [`Job`](clinvoice_data::Job), before it could be billed more than once.
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct Job
+{
+	pub client_id: Id,
+	pub date_close: Option<DateTime<Utc>>,
+	pub date_open: DateTime<Utc>,
+	pub id: Id,
+	pub invoice: Invoice,
+	pub notes: String,
+	pub objectives: String,
+	pub timesheets: Vec<Timesheet>,
+}
+
+/// # Summary
+///
+/// A [`Location`](clinvoice_data::Location), before addresses and taxes.
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct Location
+{
+	pub id: Id,
+	pub outer_id: Option<Id>,
+	pub name: String,
+}
+
+/// # Summary
+///
+/// An [`Organization`](clinvoice_data::Organization), before billing details and defaults for its
+/// [`Job`]s.
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct Organization
+{
+	pub id: Id,
+	pub location_id: Id,
+	pub name: String,
+}
+
+/// # Summary
+///
+/// A [`Timesheet`](clinvoice_data::Timesheet), before it could have its own rate.
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(test, derive(Serialize))]
+pub struct Timesheet
+{
+	pub employee_id: Id,
+	pub expenses: Vec<Expense>,
+	pub time_begin: DateTime<Utc>,
+	pub time_end: Option<DateTime<Utc>>,
+	pub work_notes: String,
+}
+
+impl From<Contact> for clinvoice_data::Contact
+{
+	fn from(old: Contact) -> Self
+	{
+		match old
+		{
+			Contact::Address {location_id, export} => Self::Address {location_id, export},
+			Contact::Email {email, export} => Self::Email {email, export},
+			Contact::Phone {phone, export} => Self::Phone {phone, export},
+		}
+	}
+}
+
+impl From<Employee> for clinvoice_data::Employee
+{
+	fn from(old: Employee) -> Self
+	{
+		Self
+		{
+			contact_info: old.contact_info.into_iter().map(|(label, contact)| (label, contact.into())).collect(),
+			employment_changes: Vec::new(),
+			hourly_rate: None,
+			id: old.id,
+			organization_id: old.organization_id,
+			person_id: old.person_id,
+			status: old.status,
+			title: old.title,
+		}
+	}
+}
+
+impl From<Expense> for clinvoice_data::Expense
+{
+	fn from(old: Expense) -> Self
+	{
+		Self
+		{
+			attachments: Vec::new(),
+			billable: true,
+			category: old.category.into(),
+			cost: old.cost,
+			description: old.description,
+			kind: Default::default(),
+		}
+	}
+}
+
+impl From<ExpenseCategory> for clinvoice_data::ExpenseCategory
+{
+	fn from(old: ExpenseCategory) -> Self
+	{
+		Self::new(match old
+		{
+			ExpenseCategory::Food => "Food",
+			ExpenseCategory::Item => "Item",
+			ExpenseCategory::Other => "Other",
+			ExpenseCategory::Service => "Service",
+			ExpenseCategory::Software => "Software",
+			ExpenseCategory::Travel => "Travel",
+		})
+	}
+}
+
+impl From<Job> for clinvoice_data::Job
+{
+	/// # Summary
+	///
+	/// Bill the [`Job`] by the hour at the rate of its [`Invoice`]. If the [`Invoice`] was issued,
+	/// it bills every [`Timesheet`] which had been stopped.
+	fn from(old: Job) -> Self
+	{
+		let invoice = old.invoice.date.map(|date| clinvoice_data::Invoice
+		{
+			credit_notes: Vec::new(),
+			date: Some(date),
+			id: Id::new_v4(),
+			number: None,
+			payment_terms: None,
+			payments: Vec::new(),
+			taxes: None,
+		});
+		let invoice_id = invoice.as_ref().map(|i| i.id);
+
+		Self
+		{
+			adjustments: Vec::new(),
+			bill_to: Default::default(),
+			billing_model: BillingModel::Hourly(old.invoice.hourly_rate),
+			client_id: old.client_id,
+			date_close: old.date_close,
+			date_open: old.date_open,
+			employee_default_rates: Default::default(),
+			employee_rates: Default::default(),
+			expense_markup: Default::default(),
+			id: old.id,
+			invoices: invoice.into_iter().collect(),
+			notes: old.notes,
+			objectives: old.objectives,
+			overtime: None,
+			rate_changes: Vec::new(),
+			timesheets: old.timesheets.into_iter().map(|timesheet| clinvoice_data::Timesheet
+			{
+				billable: true,
+				employee_id: timesheet.employee_id,
+				expenses: timesheet.expenses.into_iter().map(|e| e.into()).collect(),
+				hourly_rate: None,
+				invoice_id: timesheet.time_end.and(invoice_id),
+				time_begin: timesheet.time_begin,
+				time_end: timesheet.time_end,
+				work_notes: timesheet.work_notes,
+			}).collect(),
+		}
+	}
+}
+
+impl From<Location> for clinvoice_data::Location
+{
+	fn from(old: Location) -> Self
+	{
+		Self
+		{
+			address: None,
+			id: old.id,
+			outer_id: old.outer_id,
+			name: old.name,
+			taxes: None,
+		}
+	}
+}
+
+impl From<Organization> for clinvoice_data::Organization
+{
+	fn from(old: Organization) -> Self
+	{
+		Self
+		{
+			bill_to: Default::default(),
+			expense_markup: Default::default(),
+			id: old.id,
+			location_id: old.location_id,
+			name: old.name,
+			overtime: None,
+			payment_methods: Vec::new(),
+			payment_terms: None,
+			tax_ids: Default::default(),
+			taxes: None,
+		}
+	}
+}
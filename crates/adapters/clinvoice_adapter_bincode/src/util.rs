@@ -9,7 +9,7 @@ use
 	crate::data::Result as DataResult,
 
 	clinvoice_adapter::Store,
-	clinvoice_data::{Id, UUID_NAMESPACE},
+	clinvoice_data::{Attachment, Id, UUID_NAMESPACE},
 
 	serde::de::DeserializeOwned,
 };
@@ -21,6 +21,41 @@ use
 	std::env,
 };
 
+/// # Summary
+///
+/// Copy the file at some `path` into the attachments of a `store`, unless a file with the same
+/// contents was already attached.
+///
+/// # Errors
+///
+/// * When [`fs::read`] or [`fs::write`] does.
+/// * When the attachments of the `store` can't be created.
+pub fn attach(path: &Path, store: &Store) -> io::Result<Attachment>
+{
+	let contents = fs::read(path)?;
+	let name = path.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default();
+	let attachment = Attachment::new(name, &contents);
+
+	let attachments_path = attachments_path(store);
+	create_store_dir(&attachments_path)?;
+
+	let filepath = attachments_path.join(&attachment.hash);
+	if !filepath.is_file()
+	{
+		fs::write(filepath, contents)?;
+	}
+
+	Ok(attachment)
+}
+
+/// # Summary
+///
+/// Get the [`PathBuf`] pointing to where the attachments of a `store` are kept.
+pub fn attachments_path(store: &Store) -> PathBuf
+{
+	expand_store_path(store).join("Attachments")
+}
+
 /// # Summary
 ///
 /// Create some `dir` within `store`.
@@ -89,37 +124,33 @@ pub fn retrieve<T>(path: impl AsRef<Path>, query: impl Fn(&T) -> DataResult<bool
 
 /// # Summary
 ///
-/// Test some `assertion` using a `root` directory within the OS's [temp dir][fn_temp_dir].
+/// Test some `assertion` using a new directory within the OS's [temp dir][fn_temp_dir].
 ///
 /// # Remarks
 ///
-/// `root` is joined with the [temp dir][fn_temp_dir] (e.g. "foo" -> "%temp%/foo").
-///
-/// # Returns
-///
-/// * Nothing, if the `assertion` passed.
-/// * An [`Error`](io::Error), if [temp dir][fn_temp_dir] could not be read.
+/// Every call receives its own directory so that tests running in parallel do not see each
+/// other's entities. The directory is removed once the `assertion` passes, and kept for inspection
+/// otherwise.
 ///
 /// [fn_temp_dir]: std::env::temp_dir
 #[cfg(test)]
 pub fn temp_store(assertion: impl FnOnce(&Store))
 {
-	let temp_path = env::temp_dir().join("clinvoice_adapter_bincode_data");
+	let temp_path = env::temp_dir().join("clinvoice_adapter_bincode_data").join(Id::new_v4().to_string());
 
 	assertion(&Store
 	{
 		adapter: Adapters::Bincode,
 		password: None,
-		path: match temp_path.to_str()
-		{
-			Some(s) => s.into(),
-			_ => Err(io::Error::new(
-				io::ErrorKind::InvalidInput,
-				"`env::temp_path` did not resolve to a valid path"
-			)).unwrap(),
-		},
+		path: temp_path.to_str().expect("`env::temp_path` did not resolve to a valid path").into(),
+		read_only: false,
 		username: None,
 	});
+
+	if temp_path.is_dir()
+	{
+		fs::remove_dir_all(temp_path).unwrap();
+	}
 }
 
 /// # Summary
@@ -0,0 +1,6 @@
+mod deletable;
+mod initializable;
+mod employee_adapter;
+mod updatable;
+
+clinvoice_adapter::Adapt!(Employee => JournalEmployee);
@@ -0,0 +1,57 @@
+use
+{
+	std::borrow::Cow::Borrowed,
+
+	super::JournalEmployee,
+	crate::
+	{
+		data::{Error, JournalJob, Result},
+		journal::{self, Event},
+	},
+
+	clinvoice_adapter::data::{Deletable, Error as DataError, JobAdapter, Updatable},
+	clinvoice_query as query,
+};
+
+impl Deletable for JournalEmployee<'_, '_>
+{
+	type Error = Error;
+
+	fn delete(&self, cascade: bool) -> Result<()>
+	{
+		self.store.assert_writable()?;
+
+		let associated_jobs = JournalJob::retrieve(
+			&query::Job
+			{
+				timesheets: query::Timesheet
+				{
+					employee: query::Employee
+					{
+						id: query::Match::HasAny(vec![Borrowed(&self.employee.id)].into_iter().collect()),
+						..Default::default()
+					},
+					..Default::default()
+				},
+				..Default::default()
+			},
+			self.store,
+		)?;
+
+		if cascade
+		{
+			associated_jobs.into_iter().try_for_each(|mut result|
+			{
+				result.timesheets.retain(|t| t.employee_id != self.employee.id);
+
+				JournalJob {job: &result, store: self.store}.update()
+			})?;
+		}
+		else if !associated_jobs.is_empty()
+		{
+			return Err(DataError::DeleteRestricted(self.employee.id).into());
+		}
+
+		journal::append(self.store, vec![Event::EmployeeDeleted(self.employee.id)])
+	}
+}
@@ -0,0 +1,83 @@
+use
+{
+	std::collections::HashMap,
+
+	super::JournalEmployee,
+	crate::
+	{
+		data::{Error, Result},
+		journal, util,
+	},
+
+	clinvoice_adapter::
+	{
+		data::{EmployeeAdapter, Error as DataError, Initializable, Updatable},
+		Store,
+	},
+	clinvoice_data::{Contact, Employee, EmployeeStatus, Organization, Person},
+	clinvoice_query as query,
+};
+
+impl EmployeeAdapter for JournalEmployee<'_, '_>
+{
+	type Error = Error;
+
+	/// # Summary
+	///
+	/// Create some [`Employee`] on an active [`Store`](crate::Store).
+	///
+	/// # Parameters
+	///
+	/// See [`Employee`].
+	///
+	/// # Returns
+	///
+	/// * The created [`Employee`], if there were no errors.
+	/// * An [`Error`], if something goes wrong.
+	fn create(
+		contact_info: HashMap<String, Contact>,
+		organization: Organization,
+		person: Person,
+		status: EmployeeStatus,
+		title: String,
+		store: &Store,
+	) -> Result<Employee>
+	{
+		store.assert_writable()?;
+
+		Self::init(store)?;
+
+		let employee = Employee
+		{
+			contact_info,
+			employment_changes: Vec::new(),
+			hourly_rate: None,
+			id: util::unique_id(&journal::materialize(store)?.employees),
+			organization_id: organization.id,
+			person_id: person.id,
+			title,
+			status,
+		};
+
+		JournalEmployee {employee: &employee, store}.update()?;
+
+		Ok(employee)
+	}
+
+	/// # Summary
+	///
+	/// Retrieve some [`Employee`] from an active [`Store`](crate::Store).
+	///
+	/// # Parameters
+	///
+	/// See [`Employee`].
+	///
+	/// # Returns
+	///
+	/// * Any matching [`Employee`]s.
+	/// * An [`Error`], should something go wrong.
+	fn retrieve(query: &query::Employee, store: &Store) -> Result<Vec<Employee>>
+	{
+		util::retrieve(journal::materialize(store)?.employees, |e| query.matches(e).map_err(|e| DataError::from(e).into()))
+	}
+}
@@ -0,0 +1,34 @@
+use
+{
+	super::JournalEmployee,
+	crate::
+	{
+		data::{Error, Result},
+		journal::{self, Event},
+		util,
+	},
+
+	clinvoice_adapter::data::Updatable,
+};
+
+impl Updatable for JournalEmployee<'_, '_>
+{
+	type Error = Error;
+
+	/// # Summary
+	///
+	/// Append an [`Event`] which describes how this [`Employee`](clinvoice_data::Employee) has changed to
+	/// the journal. Nothing is appended if it has not changed.
+	fn update(&self) -> Result<()>
+	{
+		self.store.assert_writable()?;
+
+		let state = journal::materialize(self.store)?;
+		journal::append(self.store, match state.employees.get(&self.employee.id)
+		{
+			Some(previous) if util::same(previous, self.employee) => Vec::new(),
+			Some(_) => vec![Event::EmployeeUpdated(self.employee.clone())],
+			_ => vec![Event::EmployeeCreated(self.employee.clone())],
+		})
+	}
+}
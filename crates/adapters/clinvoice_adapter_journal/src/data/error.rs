@@ -0,0 +1,29 @@
+use
+{
+	std::{io, path::PathBuf},
+
+	clinvoice_adapter::{data, Error as AdapterError},
+
+	thiserror::Error,
+};
+
+#[derive(Debug, Error)]
+pub enum Error
+{
+	#[error("{0}")]
+	Adapter(#[from] AdapterError),
+
+	#[error("{0}")]
+	Data(#[from] data::Error),
+
+	#[error("{0}")]
+	Io(#[from] io::Error),
+
+	#[error("{0}")]
+	Json(#[from] serde_json::Error),
+
+	#[error("{0} is not a journal, or was written by a newer version of CLInvoice")]
+	UnknownFormat(PathBuf),
+}
+
+clinvoice_error::AliasResult!();
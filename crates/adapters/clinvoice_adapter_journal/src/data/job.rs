@@ -0,0 +1,6 @@
+mod deletable;
+mod initializable;
+mod job_adapter;
+mod updatable;
+
+clinvoice_adapter::Adapt!(Job => JournalJob);
@@ -0,0 +1,23 @@
+use
+{
+	super::JournalJob,
+	crate::
+	{
+		data::{Error, Result},
+		journal::{self, Event},
+	},
+
+	clinvoice_adapter::data::Deletable,
+};
+
+impl Deletable for JournalJob<'_, '_>
+{
+	type Error = Error;
+
+	fn delete(&self, _cascade: bool) -> Result<()>
+	{
+		self.store.assert_writable()?;
+
+		journal::append(self.store, vec![Event::JobDeleted(self.job.id)])
+	}
+}
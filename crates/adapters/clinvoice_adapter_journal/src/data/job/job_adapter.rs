@@ -0,0 +1,229 @@
+use
+{
+	std::{fs, path::Path},
+
+	super::JournalJob,
+	crate::
+	{
+		data::{Error, Result},
+		journal::{self, Event},
+		util,
+	},
+
+	clinvoice_adapter::
+	{
+		data::{Error as DataError, Initializable, JobAdapter, Sequence, Updatable},
+		Store
+	},
+	clinvoice_data::
+	{
+		chrono::{DateTime, Utc},
+		Attachment, BillingModel, Job, Organization
+	},
+	clinvoice_query as query,
+};
+
+impl JobAdapter for JournalJob<'_, '_>
+{
+	type Error = Error;
+
+	/// # Summary
+	///
+	/// Copy the file at some `path` into the attachments of the `store`.
+	fn attach(path: &Path, store: &Store) -> Result<Attachment>
+	{
+		store.assert_writable()?;
+
+		util::attach(path, store).map_err(Error::from)
+	}
+
+	/// # Summary
+	///
+	/// Create a new [`Job`] on the active [`Store`](crate::Store).
+	///
+	/// # Paramters
+	///
+	/// See [`Job`].
+	///
+	/// # Returns
+	///
+	/// The newly created [`Job`].
+	fn create(
+		client: Organization,
+		date_open: DateTime<Utc>,
+		billing_model: BillingModel,
+		objectives: String,
+		store: &Store,
+	) -> Result<Job>
+	{
+		store.assert_writable()?;
+
+		Self::init(store)?;
+
+		let job = Job
+		{
+			adjustments: Vec::new(),
+			bill_to: Default::default(),
+			billing_model,
+			client_id: client.id,
+			date_close: None,
+			date_open,
+			employee_default_rates: Default::default(),
+			employee_rates: Default::default(),
+			expense_markup: client.expense_markup,
+			id: util::unique_id(&journal::materialize(store)?.jobs),
+			invoices: Vec::new(),
+			objectives,
+			notes: "".into(),
+			overtime: client.overtime,
+			rate_changes: Vec::new(),
+			timesheets: Vec::new(),
+		};
+
+		JournalJob {job: &job, store}.update()?;
+
+		Ok(job)
+	}
+
+	/// # Summary
+	///
+	/// Read the contents of some `attachment` from the `store`.
+	fn read_attachment(attachment: &Attachment, store: &Store) -> Result<Vec<u8>>
+	{
+		fs::read(util::attachments_path(store).join(&attachment.hash)).map_err(Error::from)
+	}
+
+	/// # Summary
+	///
+	/// Retrieve some [`Job`] from the active [`Store`](crate::Store).
+	///
+	/// # Parameters
+	///
+	/// See [`Job`].
+	///
+	/// # Returns
+	///
+	/// * An `Error`, if something goes wrong.
+	/// * A list of matching [`Job`]s.
+	fn retrieve(query: &query::Job, store: &Store) -> Result<Vec<Job>>
+	{
+		util::retrieve(journal::materialize(store)?.jobs, |j| query.matches(j).map_err(|e| DataError::from(e).into()))
+	}
+
+	/// # Summary
+	///
+	/// Take the next position in some `sequence` of the `store`, and record that it was taken.
+	fn take_seq(sequence: Sequence, issued: u64, store: &Store) -> Result<u64>
+	{
+		store.assert_writable()?;
+
+		let state = journal::materialize(store)?;
+		let taken = state.sequences.iter().find(|(s, _)| *s == sequence).map(|(_, t)| *t).unwrap_or_default();
+
+		let position = taken.max(issued) + 1;
+		journal::append(store, vec![Event::SequenceTaken {position, sequence}])?;
+
+		Ok(position)
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use
+	{
+		std::{borrow::Cow::Borrowed, time::Instant},
+
+		super::{JournalJob, BillingModel, JobAdapter, Organization, query, Updatable, Utc, util},
+		crate::journal::{self, Event},
+
+		clinvoice_data::{chrono::Duration, Expense, ExpenseCategory, finance::{Currency, Money}, Id},
+		clinvoice_query::Match,
+	};
+
+	#[test]
+	fn create_retrieve()
+	{
+		util::temp_store(|store|
+		{
+			let organization = Organization
+			{
+				bill_to: Default::default(),
+				expense_markup: Default::default(),
+				id: Id::new_v4(),
+				location_id: Id::new_v4(),
+				name: "Big Old Test Corporation".into(),
+				overtime: None,
+				payment_methods: Vec::new(),
+				payment_terms: None,
+				tax_ids: Default::default(),
+				taxes: None,
+			};
+
+			let start = Instant::now();
+
+			let mut job = JournalJob::create(
+				organization.clone(),
+				Utc::now(),
+				BillingModel::Hourly(Money::new(13_27, 2, Currency::USD)),
+				"Test the job creation function".into(),
+				store,
+			).unwrap();
+
+			// Start and then stop some work, as `clinvoice time` would.
+			let employee_id = Id::new_v4();
+			job.start_timesheet(employee_id);
+			JournalJob {job: &job, store}.update().unwrap();
+
+			job.timesheets[0].expenses.push(Expense
+			{
+				attachments: Vec::new(),
+				billable: true,
+				category: ExpenseCategory::new("Food"),
+				cost: Money::new(20_00, 2, Currency::USD),
+				description: "Lunch".into(),
+				kind: Default::default(),
+			});
+			job.timesheets[0].time_begin = job.timesheets[0].time_begin - Duration::seconds(1);
+			job.timesheets[0].time_end = Some(Utc::now());
+			job.timesheets[0].work_notes = "Wrote tests".into();
+			JournalJob {job: &job, store}.update().unwrap();
+
+			job.date_close = Some(Utc::now());
+			JournalJob {job: &job, store}.update().unwrap();
+
+			let retrieved = JournalJob::retrieve(
+				&query::Job
+				{
+					id: Match::EqualTo(Borrowed(&job.id)),
+					..Default::default()
+				},
+				store,
+			).unwrap();
+
+			println!("\n>>>>> JournalJob::create_retrieve {}us <<<<<\n", Instant::now().duration_since(start).as_micros());
+
+			assert_eq!(retrieved.len(), 1);
+			assert!(util::same(&retrieved[0], &job));
+
+			// Each change was recorded as the domain event it represents.
+			let events: Vec<_> = journal::records(store).unwrap().into_iter().map(|r| r.event).collect();
+			assert!(matches!(events.as_slice(), [
+				Event::JobCreated(_),
+				Event::TimesheetStarted {..},
+				Event::ExpenseAdded {..},
+				Event::TimesheetStopped {..},
+				Event::JobClosed {..},
+			]));
+
+			// Nothing is appended when nothing changes.
+			JournalJob {job: &job, store}.update().unwrap();
+			assert_eq!(journal::records(store).unwrap().len(), events.len());
+
+			// Changes which are not domain events are recorded as snapshots.
+			job.notes = "Some notes".into();
+			JournalJob {job: &job, store}.update().unwrap();
+			assert!(matches!(journal::records(store).unwrap().last().map(|r| &r.event), Some(Event::JobUpdated(_))));
+		});
+	}
+}
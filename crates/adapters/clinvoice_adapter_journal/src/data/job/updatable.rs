@@ -0,0 +1,32 @@
+use
+{
+	super::JournalJob,
+	crate::
+	{
+		data::{Error, Result},
+		journal::{self, Event},
+	},
+
+	clinvoice_adapter::data::Updatable,
+};
+
+impl Updatable for JournalJob<'_, '_>
+{
+	type Error = Error;
+
+	/// # Summary
+	///
+	/// Append an [`Event`] which describes how this [`Job`](clinvoice_data::Job) has changed to
+	/// the journal. Nothing is appended if it has not changed.
+	fn update(&self) -> Result<()>
+	{
+		self.store.assert_writable()?;
+
+		let state = journal::materialize(self.store)?;
+		journal::append(self.store, match state.jobs.get(&self.job.id)
+		{
+			Some(j) => Event::from_job_update(j, self.job),
+			_ => vec![Event::JobCreated(self.job.clone())],
+		})
+	}
+}
@@ -0,0 +1,6 @@
+mod deletable;
+mod initializable;
+mod location_adapter;
+mod updatable;
+
+clinvoice_adapter::Adapt!(Location => JournalLocation);
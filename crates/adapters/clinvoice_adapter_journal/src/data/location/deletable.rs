@@ -0,0 +1,69 @@
+use
+{
+	std::borrow::Cow::Borrowed,
+
+	super::JournalLocation,
+	crate::
+	{
+		data::{Error, JournalOrganization, Result},
+		journal::{self, Event},
+	},
+
+	clinvoice_adapter::data::{Deletable, Error as DataError, LocationAdapter, OrganizationAdapter},
+	clinvoice_query as query,
+};
+
+impl Deletable for JournalLocation<'_, '_>
+{
+	type Error = Error;
+
+	fn delete(&self, cascade: bool) -> Result<()>
+	{
+		self.store.assert_writable()?;
+
+		let associated_locations = JournalLocation::retrieve(
+			&query::Location
+			{
+				outer: query::OuterLocation::Some(
+					query::Location
+					{
+						id: query::Match::EqualTo(Borrowed(&self.location.id)),
+						..Default::default()
+					}.into()
+				),
+				..Default::default()
+			},
+			self.store,
+		)?;
+
+		let associated_organizations = JournalOrganization::retrieve(
+			&query::Organization
+			{
+				location: query::Location
+				{
+					id: query::Match::EqualTo(Borrowed(&self.location.id)),
+					..Default::default()
+				},
+				..Default::default()
+			},
+			self.store,
+		)?;
+
+		if cascade
+		{
+			associated_organizations.into_iter().try_for_each(
+				|o| JournalOrganization {organization: &o, store: self.store}.delete(cascade)
+			)?;
+
+			associated_locations.into_iter().try_for_each(
+				|l| JournalLocation {location: &l, store: self.store}.delete(cascade)
+			)?;
+		}
+		else if !(associated_organizations.is_empty() && associated_locations.is_empty())
+		{
+			return Err(DataError::DeleteRestricted(self.location.id).into());
+		}
+
+		journal::append(self.store, vec![Event::LocationDeleted(self.location.id)])
+	}
+}
@@ -0,0 +1,25 @@
+use
+{
+	super::JournalLocation,
+	crate::
+	{
+		data::{Error, Result},
+		util,
+	},
+
+	clinvoice_adapter::{data::Initializable, Store},
+};
+
+impl Initializable for JournalLocation<'_, '_>
+{
+	type Error = Error;
+
+	/// # Summary
+	///
+	/// Initialize the database for a given [`Store`].
+	fn init(store: &Store) -> Result<()>
+	{
+		util::create_store_dir(&util::expand_store_path(store))?;
+		Ok(())
+	}
+}
@@ -0,0 +1,101 @@
+use
+{
+	super::JournalLocation,
+	crate::
+	{
+		data::{Error, Result},
+		journal, util,
+	},
+
+	clinvoice_adapter::
+	{
+		data::{Error as DataError, Initializable, LocationAdapter, Updatable},
+		Store,
+	},
+	clinvoice_data::Location,
+	clinvoice_query as query,
+};
+
+impl LocationAdapter for JournalLocation<'_, '_>
+{
+	type Error = Error;
+
+	/// # Summary
+	///
+	/// Create a new `Location` with a generated ID.
+	///
+	/// # Parameters
+	///
+	/// See [`Location`].
+	///
+	/// # Returns
+	///
+	/// ```ignore
+	/// Location {name, id: /* generated */};
+	/// ```
+	fn create(name: String, store: &Store) -> Result<Location>
+	{
+		store.assert_writable()?;
+
+		Self::init(store)?;
+
+		let location = Location
+		{
+			address: None,
+			id: util::unique_id(&journal::materialize(store)?.locations),
+			name,
+			outer_id: None,
+			taxes: None,
+		};
+
+		JournalLocation {location: &location, store}.update()?;
+
+		Ok(location)
+	}
+
+	/// # Summary
+	///
+	/// Create a new [`Location`] which is inside of `self`.
+	///
+	/// # Parameters
+	///
+	/// See [`Location`].
+	///
+	/// # Returns
+	///
+	/// ```ignore
+	/// Location {name, id: /* generated */, outside_id: self.unroll().id};
+	/// ```
+	fn create_inner(&self, name: String) -> Result<Location>
+	{
+		let inner_location = Location
+		{
+			address: None,
+			id: util::unique_id(&journal::materialize(self.store)?.locations),
+			name,
+			outer_id: Some(self.location.id),
+			taxes: None,
+		};
+
+		JournalLocation {location: &inner_location, store: self.store}.update()?;
+
+		Ok(inner_location)
+	}
+
+	/// # Summary
+	///
+	/// Retrieve a [`Location`] from an active [`Store`](core::Store).
+	///
+	/// # Parameters
+	///
+	/// See [`Location`].
+	///
+	/// # Returns
+	///
+	/// * An [`Error`], when something goes wrong.
+	/// * A list of matches, if there are any.
+	fn retrieve(query: &query::Location, store: &Store) -> Result<Vec<Location>>
+	{
+		util::retrieve(journal::materialize(store)?.locations, |l| query.matches(l).map_err(|e| DataError::from(e).into()))
+	}
+}
@@ -0,0 +1,34 @@
+use
+{
+	super::JournalLocation,
+	crate::
+	{
+		data::{Error, Result},
+		journal::{self, Event},
+		util,
+	},
+
+	clinvoice_adapter::data::Updatable,
+};
+
+impl Updatable for JournalLocation<'_, '_>
+{
+	type Error = Error;
+
+	/// # Summary
+	///
+	/// Append an [`Event`] which describes how this [`Location`](clinvoice_data::Location) has changed to
+	/// the journal. Nothing is appended if it has not changed.
+	fn update(&self) -> Result<()>
+	{
+		self.store.assert_writable()?;
+
+		let state = journal::materialize(self.store)?;
+		journal::append(self.store, match state.locations.get(&self.location.id)
+		{
+			Some(previous) if util::same(previous, self.location) => Vec::new(),
+			Some(_) => vec![Event::LocationUpdated(self.location.clone())],
+			_ => vec![Event::LocationCreated(self.location.clone())],
+		})
+	}
+}
@@ -0,0 +1,23 @@
+//! # Summary
+//!
+//! This module implements adapters (and associated adapter types such as
+//! [`Deletable`](clinvoice_adapter::data::Deletable)) for a [journal](crate::journal).
+
+mod employee;
+mod error;
+mod job;
+mod location;
+mod organization;
+mod person;
+
+pub use
+{
+	employee::JournalEmployee,
+	error::{Error, Result},
+	job::JournalJob,
+	location::JournalLocation,
+	organization::JournalOrganization,
+	person::JournalPerson,
+};
+
+clinvoice_adapter::TestKit!(crate::util::temp_store => JournalEmployee, JournalJob, JournalLocation, JournalOrganization, JournalPerson);
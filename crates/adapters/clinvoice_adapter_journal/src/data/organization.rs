@@ -0,0 +1,6 @@
+mod deletable;
+mod initializable;
+mod organization_adapter;
+mod updatable;
+
+clinvoice_adapter::Adapt!(Organization => JournalOrganization);
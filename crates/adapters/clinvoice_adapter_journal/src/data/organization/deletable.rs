@@ -0,0 +1,67 @@
+use
+{
+	std::borrow::Cow::Borrowed,
+
+	super::JournalOrganization,
+	crate::
+	{
+		data::{Error, JournalEmployee, JournalJob, Result},
+		journal::{self, Event},
+	},
+
+	clinvoice_adapter::data::{Deletable, EmployeeAdapter, Error as DataError, JobAdapter},
+	clinvoice_query as query,
+};
+
+impl Deletable for JournalOrganization<'_, '_>
+{
+	type Error = Error;
+
+	fn delete(&self, cascade: bool) -> Result<()>
+	{
+		self.store.assert_writable()?;
+
+		let associated_employees = JournalEmployee::retrieve(
+			&query::Employee
+			{
+				organization: query::Organization
+				{
+					id: query::Match::EqualTo(Borrowed(&self.organization.id)),
+					..Default::default()
+				},
+				..Default::default()
+			},
+			self.store,
+		)?;
+
+		let associated_jobs = JournalJob::retrieve(
+			&query::Job
+			{
+				client: query::Organization
+				{
+					id: query::Match::EqualTo(Borrowed(&self.organization.id)),
+					..Default::default()
+				},
+				..Default::default()
+			},
+			self.store,
+		)?;
+
+		if cascade
+		{
+			associated_jobs.into_iter().try_for_each(
+				|j| JournalJob {job: &j, store: self.store}.delete(cascade)
+			)?;
+
+			associated_employees.into_iter().try_for_each(
+				|e| JournalEmployee {employee: &e, store: self.store}.delete(cascade)
+			)?;
+		}
+		else if !(associated_employees.is_empty() && associated_jobs.is_empty())
+		{
+			return Err(DataError::DeleteRestricted(self.organization.id).into());
+		}
+
+		journal::append(self.store, vec![Event::OrganizationDeleted(self.organization.id)])
+	}
+}
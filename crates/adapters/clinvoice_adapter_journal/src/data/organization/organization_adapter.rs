@@ -0,0 +1,75 @@
+use
+{
+	super::JournalOrganization,
+	crate::
+	{
+		data::{Error, Result},
+		journal, util,
+	},
+
+	clinvoice_adapter::
+	{
+		data::{Error as DataError, Initializable, OrganizationAdapter, Updatable},
+		Store,
+	},
+	clinvoice_data::{Location, Organization},
+	clinvoice_query as query,
+};
+
+impl OrganizationAdapter for JournalOrganization<'_, '_>
+{
+	type Error = Error;
+
+	/// # Summary
+	///
+	/// Create a new [`Organization`] on the active [`Store`](crate::Store).
+	///
+	/// # Parameters
+	///
+	/// See [`Organization`].
+	///
+	/// # Returns
+	///
+	/// The newly created [`Organization`].
+	fn create(location: Location, name: String, store: &Store) -> Result<Organization>
+	{
+		store.assert_writable()?;
+
+		Self::init(store)?;
+
+		let organization = Organization
+		{
+			bill_to: Default::default(),
+			expense_markup: Default::default(),
+			id: util::unique_id(&journal::materialize(store)?.organizations),
+			location_id: location.id,
+			name,
+			overtime: None,
+			payment_methods: Vec::new(),
+			payment_terms: None,
+			tax_ids: Default::default(),
+			taxes: None,
+		};
+
+		JournalOrganization {organization: &organization, store}.update()?;
+
+		Ok(organization)
+	}
+
+	/// # Summary
+	///
+	/// Retrieve some [`Organization`] from the active [`Store`](crate::Store).
+	///
+	/// # Parameters
+	///
+	/// See [`Organization`].
+	///
+	/// # Returns
+	///
+	/// * An `Error`, if something goes wrong.
+	/// * A list of matching [`Organization`]s.
+	fn retrieve(query: &query::Organization, store: &Store) -> Result<Vec<Organization>>
+	{
+		util::retrieve(journal::materialize(store)?.organizations, |o| query.matches(o).map_err(|e| DataError::from(e).into()))
+	}
+}
@@ -0,0 +1,34 @@
+use
+{
+	super::JournalOrganization,
+	crate::
+	{
+		data::{Error, Result},
+		journal::{self, Event},
+		util,
+	},
+
+	clinvoice_adapter::data::Updatable,
+};
+
+impl Updatable for JournalOrganization<'_, '_>
+{
+	type Error = Error;
+
+	/// # Summary
+	///
+	/// Append an [`Event`] which describes how this [`Organization`](clinvoice_data::Organization) has changed to
+	/// the journal. Nothing is appended if it has not changed.
+	fn update(&self) -> Result<()>
+	{
+		self.store.assert_writable()?;
+
+		let state = journal::materialize(self.store)?;
+		journal::append(self.store, match state.organizations.get(&self.organization.id)
+		{
+			Some(previous) if util::same(previous, self.organization) => Vec::new(),
+			Some(_) => vec![Event::OrganizationUpdated(self.organization.clone())],
+			_ => vec![Event::OrganizationCreated(self.organization.clone())],
+		})
+	}
+}
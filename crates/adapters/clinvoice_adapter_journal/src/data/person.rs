@@ -0,0 +1,6 @@
+mod deletable;
+mod initializable;
+mod person_adapter;
+mod updatable;
+
+clinvoice_adapter::Adapt!(Person => JournalPerson);
@@ -0,0 +1,120 @@
+use
+{
+	std::borrow::Cow::Borrowed,
+
+	super::JournalPerson,
+	crate::
+	{
+		data::{Error, JournalEmployee, Result},
+		journal::{self, Event},
+	},
+
+	clinvoice_adapter::data::{Deletable, EmployeeAdapter, Error as DataError},
+	clinvoice_query as query,
+};
+
+impl Deletable for JournalPerson<'_, '_>
+{
+	type Error = Error;
+
+	fn delete(&self, cascade: bool) -> Result<()>
+	{
+		self.store.assert_writable()?;
+
+		let associated_employees = JournalEmployee::retrieve(
+			&query::Employee
+			{
+				person: query::Person
+				{
+					id: query::Match::EqualTo(Borrowed(&self.person.id)),
+					..Default::default()
+				},
+				..Default::default()
+			},
+			self.store,
+		)?;
+
+		if cascade
+		{
+			associated_employees.into_iter().try_for_each(
+				|e| JournalEmployee {employee: &e, store: self.store}.delete(true)
+			)?;
+		}
+		else if !associated_employees.is_empty()
+		{
+			return Err(DataError::DeleteRestricted(self.person.id).into());
+		}
+
+		journal::append(self.store, vec![Event::PersonDeleted(self.person.id)])
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use
+	{
+		std::time::Instant,
+
+		super::{Deletable, EmployeeAdapter, JournalEmployee, JournalPerson},
+		crate::
+		{
+			data::{Error, JournalLocation, JournalOrganization},
+			journal, util,
+		},
+
+		clinvoice_adapter::
+		{
+			data::{Error as DataError, LocationAdapter, OrganizationAdapter, PersonAdapter},
+			Error as AdapterError, Store,
+		},
+		clinvoice_data::EmployeeStatus,
+	};
+
+	#[test]
+	fn delete()
+	{
+		util::temp_store(|store|
+		{
+			let earth = JournalLocation::create("Earth".into(), store).unwrap();
+			let big_old_test = JournalOrganization::create(earth, "Big Old Test Corporation".into(), store).unwrap();
+			let testy = JournalPerson
+			{
+				person: &JournalPerson::create("Testy Mćtesterson".into(), store).unwrap(),
+				store,
+			};
+
+			let ceo_testy = JournalEmployee::create(
+				Default::default(),
+				big_old_test.clone(),
+				testy.person.clone(),
+				EmployeeStatus::Employed,
+				"CEO of Tests".into(),
+				store,
+			).unwrap();
+
+			// Assert that the deletion fails when the store is read-only
+			let read_only = Store {read_only: true, ..store.clone()};
+			assert!(matches!(
+				JournalPerson {person: testy.person, store: &read_only}.delete(true),
+				Err(Error::Adapter(AdapterError::ReadOnly))
+			));
+
+			let start = Instant::now();
+			// Assert that the deletion fails when restricted
+			assert!(matches!(testy.delete(false), Err(Error::Data(DataError::DeleteRestricted(_)))));
+			// Assert that the deletion works when cascading
+			assert!(testy.delete(true).is_ok());
+			println!("\n>>>>> JournalPerson::delete {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / 2);
+
+			// Assert that `testy` and its referencing employee is gone, but the organization is not.
+			let state = journal::materialize(store).unwrap();
+			assert!(!state.people.contains_key(&testy.person.id));
+			assert!(!state.employees.contains_key(&ceo_testy.id));
+			assert!(state.organizations.contains_key(&big_old_test.id));
+
+			// Assert that the deletion is still in the journal's history.
+			assert!(journal::replay(store, Some(journal::records(store).unwrap()[3].timestamp)).unwrap().people.contains_key(&testy.person.id));
+		});
+	}
+}
@@ -0,0 +1,67 @@
+use
+{
+	super::JournalPerson,
+	crate::
+	{
+		data::{Error, Result},
+		journal, util,
+	},
+
+	clinvoice_adapter::
+	{
+		data::{Error as DataError, Initializable, PersonAdapter, Updatable},
+		Store,
+	},
+	clinvoice_data::Person,
+	clinvoice_query as query,
+};
+
+impl PersonAdapter for JournalPerson<'_, '_>
+{
+	type Error = Error;
+
+	/// # Summary
+	///
+	/// Create a new [`Person`] on the active [`Store`](crate::Store).
+	///
+	/// # Parameters
+	///
+	/// See [`Person`].
+	///
+	/// # Returns
+	///
+	/// The newly created [`Person`].
+	fn create(name: String, store: &Store) -> Result<Person>
+	{
+		store.assert_writable()?;
+
+		Self::init(store)?;
+
+		let person = Person
+		{
+			id: util::unique_id(&journal::materialize(store)?.people),
+			name,
+		};
+
+		JournalPerson {person: &person, store}.update()?;
+
+		Ok(person)
+	}
+
+	/// # Summary
+	///
+	/// Retrieve some [`Person`] from the active [`Store`](crate::Store).
+	///
+	/// # Parameters
+	///
+	/// See [`Person`].
+	///
+	/// # Returns
+	///
+	/// * An `Error`, if something goes wrong.
+	/// * A list of matching [`Person`]s.
+	fn retrieve(query: &query::Person, store: &Store) -> Result<Vec<Person>>
+	{
+		util::retrieve(journal::materialize(store)?.people, |p| query.matches(p).map_err(|e| DataError::from(e).into()))
+	}
+}
@@ -0,0 +1,34 @@
+use
+{
+	super::JournalPerson,
+	crate::
+	{
+		data::{Error, Result},
+		journal::{self, Event},
+		util,
+	},
+
+	clinvoice_adapter::data::Updatable,
+};
+
+impl Updatable for JournalPerson<'_, '_>
+{
+	type Error = Error;
+
+	/// # Summary
+	///
+	/// Append an [`Event`] which describes how this [`Person`](clinvoice_data::Person) has changed to
+	/// the journal. Nothing is appended if it has not changed.
+	fn update(&self) -> Result<()>
+	{
+		self.store.assert_writable()?;
+
+		let state = journal::materialize(self.store)?;
+		journal::append(self.store, match state.people.get(&self.person.id)
+		{
+			Some(previous) if util::same(previous, self.person) => Vec::new(),
+			Some(_) => vec![Event::PersonUpdated(self.person.clone())],
+			_ => vec![Event::PersonCreated(self.person.clone())],
+		})
+	}
+}
@@ -0,0 +1,393 @@
+//! # Summary
+//!
+//! This module defines the append-only journal which is the source of truth for a journal
+//! [`Store`], and how its [`Event`]s are replayed to materialize a [`State`].
+//!
+//! # Remarks
+//!
+//! Replaying every [`Event`] each time a [`Store`] is read would get slower as the journal grows.
+//! Because of this, the most recently materialized [`State`] is cached as a _projection_ alongside
+//! the journal, and only the [`Event`]s which were appended since then are replayed on top of it.
+//! The projection can be thrown away and [rebuilt](rebuild) from the journal at any time.
+//!
+//! A journal begins with a [header](HEADER) which names the version of its format. After that,
+//! each [`Record`] is written on its own line as JSON. Since JSON records the name of every field
+//! and variant, a [`Record`] can still be read after fields have been added to the
+//! [`clinvoice_data`] types it contains, so long as those fields have a default.
+
+mod event;
+mod record;
+mod state;
+
+use
+{
+	std::{fs, io::{ErrorKind, Write}, path::PathBuf},
+
+	crate::{data::{Error, Result}, util},
+
+	clinvoice_adapter::Store,
+	clinvoice_data::chrono::{DateTime, Utc},
+
+	serde::{Deserialize, Serialize},
+};
+
+pub use
+{
+	event::Event,
+	record::Record,
+	state::State,
+};
+
+/// # Summary
+///
+/// The first line of every journal, which names the version of the format it is written in.
+pub const HEADER: &[u8] = b"clinvoice-journal 1\n";
+
+/// # Summary
+///
+/// A [`State`] which has been materialized from the first `offset` bytes of a journal.
+#[derive(Default, Deserialize, Serialize)]
+struct Projection
+{
+	offset: u64,
+	state: State,
+}
+
+/// # Summary
+///
+/// Append some `events` to the journal of a `store`.
+///
+/// # Remarks
+///
+/// All of the `events` are given the same [`Record::timestamp`], and are written at once.
+///
+/// # Returns
+///
+/// * Nothing, if the `events` were appended.
+/// * An [`Error`](crate::data::Error), if the `store` is read-only or could not be written to.
+pub fn append(store: &Store, events: Vec<Event>) -> Result<()>
+{
+	store.assert_writable()?;
+
+	if events.is_empty()
+	{
+		return Ok(());
+	}
+
+	util::create_store_dir(&util::expand_store_path(store))?;
+
+	let mut journal = fs::OpenOptions::new().append(true).create(true).open(path(store))?;
+	let mut serialized = match journal.metadata()?.len()
+	{
+		0 => HEADER.to_vec(),
+		_ => Vec::new(),
+	};
+
+	let timestamp = Utc::now();
+	events.into_iter().try_for_each(|event| write_record(&mut serialized, &Record {event, timestamp}))?;
+
+	journal.write_all(&serialized)?;
+
+	Ok(())
+}
+
+/// # Summary
+///
+/// Apply the [`Record`]s in some `journal` to a `state`, starting at some byte `offset` and
+/// stopping at the first which was appended after `until`.
+///
+/// # Returns
+///
+/// The number of bytes of the `journal` which have been applied, including those before the
+/// `offset`.
+fn fold(journal: &[u8], offset: usize, state: &mut State, until: Option<DateTime<Utc>>) -> Result<usize>
+{
+	let mut remaining = &journal[offset.max(HEADER.len()).min(journal.len())..];
+	while !remaining.is_empty()
+	{
+		let unread = remaining;
+		let record = read_record(&mut remaining)?;
+
+		if until.map(|u| record.timestamp > u).unwrap_or(false)
+		{
+			return Ok(journal.len() - unread.len());
+		}
+
+		record.event.apply(state)?;
+	}
+
+	Ok(journal.len())
+}
+
+/// # Summary
+///
+/// Get the current [`State`] of a `store`.
+///
+/// # Remarks
+///
+/// Only the [`Event`]s which were appended since the projection was last saved are replayed. If
+/// the projection is missing or does not match the journal, it is rebuilt.
+pub fn materialize(store: &Store) -> Result<State>
+{
+	let journal = read(store)?;
+
+	let mut projection = match fs::read(projection_path(store))
+	{
+		Ok(serialized) => serde_json::from_slice(&serialized).unwrap_or_default(),
+		Err(e) if e.kind() == ErrorKind::NotFound => Projection::default(),
+		Err(e) => return Err(e.into()),
+	};
+
+	if projection.offset as usize > journal.len()
+	{
+		projection = Projection::default();
+	}
+
+	let offset = projection.offset as usize;
+	if offset < journal.len()
+	{
+		fold(&journal, offset, &mut projection.state, None)?;
+		projection.offset = journal.len() as u64;
+
+		if !store.read_only
+		{
+			fs::write(projection_path(store), serde_json::to_vec(&projection)?)?;
+		}
+	}
+
+	Ok(projection.state)
+}
+
+/// # Summary
+///
+/// Get the [`PathBuf`] pointing to the journal of a `store`.
+pub fn path(store: &Store) -> PathBuf
+{
+	util::expand_store_path(store).join("Journal")
+}
+
+/// # Summary
+///
+/// Get the [`PathBuf`] pointing to the cached projection of a `store`'s journal.
+pub fn projection_path(store: &Store) -> PathBuf
+{
+	util::expand_store_path(store).join("Projection")
+}
+
+/// # Summary
+///
+/// Read the raw journal of a `store`. A journal which does not exist yet is empty.
+///
+/// # Errors
+///
+/// * When [`fs::read`] does.
+/// * When the journal does not begin with the [`HEADER`].
+fn read(store: &Store) -> Result<Vec<u8>>
+{
+	let journal = match fs::read(path(store))
+	{
+		Ok(journal) => journal,
+		Err(e) if e.kind() == ErrorKind::NotFound => Vec::new(),
+		Err(e) => return Err(e.into()),
+	};
+
+	if !(journal.is_empty() || journal.starts_with(HEADER))
+	{
+		return Err(Error::UnknownFormat(path(store)));
+	}
+
+	Ok(journal)
+}
+
+/// # Summary
+///
+/// Rebuild the projection of a `store` by replaying its entire journal.
+///
+/// # Remarks
+///
+/// The rebuilt projection is only saved if the `store` is not read-only.
+pub fn rebuild(store: &Store) -> Result<State>
+{
+	let journal = read(store)?;
+
+	let mut projection = Projection::default();
+	fold(&journal, 0, &mut projection.state, None)?;
+	projection.offset = journal.len() as u64;
+
+	if !store.read_only
+	{
+		fs::write(projection_path(store), serde_json::to_vec(&projection)?)?;
+	}
+
+	Ok(projection.state)
+}
+
+/// # Summary
+///
+/// Read the next [`Record`] from the `remaining` part of a journal, and advance past it.
+fn read_record(remaining: &mut &[u8]) -> Result<Record>
+{
+	let end = remaining.iter().position(|b| *b == b'\n').map(|i| i + 1).unwrap_or_else(|| remaining.len());
+	let record = serde_json::from_slice(&remaining[..end])?;
+	*remaining = &remaining[end..];
+
+	Ok(record)
+}
+
+/// # Summary
+///
+/// Get every [`Record`] in the journal of a `store`, in the order they were appended.
+pub fn records(store: &Store) -> Result<Vec<Record>>
+{
+	let journal = read(store)?;
+
+	let mut records = Vec::new();
+	let mut remaining = &journal[HEADER.len().min(journal.len())..];
+	while !remaining.is_empty()
+	{
+		records.push(read_record(&mut remaining)?);
+	}
+
+	Ok(records)
+}
+
+/// # Summary
+///
+/// Replay the journal of a `store`, ignoring the projection.
+///
+/// # Parameters
+///
+/// * `until`, the last moment which should be replayed. Every [`Event`] which was appended after
+///   this is ignored. When [`None`], the whole journal is replayed.
+///
+/// # Returns
+///
+/// The [`State`] of the `store` as of `until`.
+pub fn replay(store: &Store, until: Option<DateTime<Utc>>) -> Result<State>
+{
+	let mut state = State::default();
+	fold(&read(store)?, 0, &mut state, until)?;
+	Ok(state)
+}
+
+/// # Summary
+///
+/// Write some `record` to the end of a `journal`.
+fn write_record(journal: &mut Vec<u8>, record: &Record) -> Result<()>
+{
+	serde_json::to_writer(&mut *journal, record)?;
+	journal.push(b'\n');
+	Ok(())
+}
+
+#[cfg(test)]
+mod tests
+{
+	use
+	{
+		std::{fs, thread, time::{Duration, Instant}},
+
+		super::{Event, Utc},
+		crate::util,
+
+		clinvoice_data::{EmployeeStatus, Id, Person},
+
+		serde_json::json,
+	};
+
+	#[test]
+	fn materialize()
+	{
+		util::temp_store(|store|
+		{
+			let testy = Person {id: Id::new_v4(), name: "Testy McTesterson".into()};
+			super::append(store, vec![Event::PersonCreated(testy.clone())]).unwrap();
+
+			let start = Instant::now();
+			let state = super::materialize(store).unwrap();
+			println!("\n>>>>> journal::materialize {}us <<<<<\n", Instant::now().duration_since(start).as_micros());
+
+			assert_eq!(state.people.get(&testy.id).map(|p| &p.name), Some(&testy.name));
+			assert!(super::projection_path(store).is_file());
+
+			// Events appended after the projection was saved are still replayed.
+			super::append(store, vec![Event::PersonDeleted(testy.id)]).unwrap();
+			assert!(super::materialize(store).unwrap().people.is_empty());
+
+			// A corrupt projection is rebuilt.
+			fs::write(super::projection_path(store), "garbage").unwrap();
+			assert!(super::materialize(store).unwrap().people.is_empty());
+			assert!(super::rebuild(store).unwrap().people.is_empty());
+			assert_eq!(super::records(store).unwrap().len(), 2);
+
+			// A journal without a header is not read.
+			fs::write(super::path(store), "garbage").unwrap();
+			assert!(super::materialize(store).is_err());
+		});
+	}
+
+	#[test]
+	fn replay()
+	{
+		util::temp_store(|store|
+		{
+			let testy = Person {id: Id::new_v4(), name: "Testy McTesterson".into()};
+			super::append(store, vec![Event::PersonCreated(testy.clone())]).unwrap();
+
+			thread::sleep(Duration::from_millis(2));
+			let before_rename = Utc::now();
+			thread::sleep(Duration::from_millis(2));
+
+			super::append(store, vec![Event::PersonUpdated(Person {name: "Mr. Testy".into(), ..testy.clone()})]).unwrap();
+
+			let start = Instant::now();
+			let past = super::replay(store, Some(before_rename)).unwrap();
+			let present = super::replay(store, None).unwrap();
+			println!("\n>>>>> journal::replay {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / 2);
+
+			assert_eq!(past.people[&testy.id].name, testy.name);
+			assert_eq!(present.people[&testy.id].name, "Mr. Testy");
+		});
+	}
+
+	#[test]
+	fn replay_older_records()
+	{
+		util::temp_store(|store|
+		{
+			let employee_id = Id::new_v4();
+
+			// An `Employee` as it was recorded before it had an `hourly_rate` or `employment_changes`.
+			let mut journal = super::HEADER.to_vec();
+			serde_json::to_writer(&mut journal, &json!({
+				"event": {"EmployeeCreated": {
+					"contact_info": {"Work": {"Email": {"email": "foo@bar.io", "export": false}}},
+					"id": employee_id,
+					"organization_id": Id::new_v4(),
+					"person_id": Id::new_v4(),
+					"status": "Employed",
+					"title": "CEO of Tests",
+				}},
+				"timestamp": "2021-01-01T00:00:00Z",
+			})).unwrap();
+			journal.push(b'\n');
+
+			util::create_store_dir(&util::expand_store_path(store)).unwrap();
+			fs::write(super::path(store), journal).unwrap();
+
+			let start = Instant::now();
+			let state = super::replay(store, None).unwrap();
+			println!("\n>>>>> journal::replay_older_records {}us <<<<<\n", Instant::now().duration_since(start).as_micros());
+
+			let employee = &state.employees[&employee_id];
+			assert!(employee.employment_changes.is_empty());
+			assert_eq!(employee.hourly_rate, None);
+			assert_eq!(employee.status, EmployeeStatus::Employed);
+			assert_eq!(employee.title, "CEO of Tests");
+
+			// Events appended afterwards are read alongside the older ones.
+			super::append(store, vec![Event::EmployeeDeleted(employee_id)]).unwrap();
+			assert!(super::materialize(store).unwrap().employees.is_empty());
+		});
+	}
+}
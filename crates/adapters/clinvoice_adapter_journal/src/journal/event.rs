@@ -0,0 +1,585 @@
+use
+{
+	super::State,
+	crate::util,
+
+	clinvoice_adapter::data::{Error as DataError, Sequence},
+	clinvoice_data::
+	{
+		chrono::{DateTime, Utc},
+		BillingModel, CreditNote, Employee, Expense, Id, Invoice, InvoiceDate, Job, Location, Organization, Payment, Person, Timesheet,
+	},
+
+	serde::{Deserialize, Serialize},
+};
+
+/// # Summary
+///
+/// Something which happened to a [`Store`](clinvoice_adapter::Store).
+///
+/// # Remarks
+///
+/// Where possible, changes to a [`Job`] are recorded as the domain events they represent (e.g.
+/// [`Event::TimesheetStarted`]). Changes which cannot be described that way (e.g. editing the
+/// [`Job::notes`]) are recorded as an [`Event::JobUpdated`] instead.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub enum Event
+{
+	/// # Summary
+	///
+	/// A [`CreditNote`] was issued against the [`Invoice`] with some `invoice_id`.
+	CreditNoteIssued
+	{
+		credit_note: CreditNote,
+		invoice_id: Id,
+		job_id: Id,
+	},
+
+	/// # Summary
+	///
+	/// An [`Employee`] was created.
+	EmployeeCreated(Employee),
+
+	/// # Summary
+	///
+	/// The [`Employee`] with some [`Id`] was deleted.
+	EmployeeDeleted(Id),
+
+	/// # Summary
+	///
+	/// An [`Employee`] was edited, and now looks like this.
+	EmployeeUpdated(Employee),
+
+	/// # Summary
+	///
+	/// An [`Expense`] was added to the [`Timesheet`] of some `employee_id` which began at
+	/// `time_begin`.
+	ExpenseAdded
+	{
+		employee_id: Id,
+		expense: Expense,
+		job_id: Id,
+		time_begin: DateTime<Utc>,
+	},
+
+	/// # Summary
+	///
+	/// An [`Invoice`] was issued for all of the unbilled work on a [`Job`] which was done
+	/// `through` some date.
+	InvoiceIssued
+	{
+		invoice: Invoice,
+		job_id: Id,
+		through: DateTime<Utc>,
+	},
+
+	/// # Summary
+	///
+	/// The [`Invoice`] with some `invoice_id` was paid on some `date`.
+	InvoicePaid
+	{
+		date: DateTime<Utc>,
+		invoice_id: Id,
+		job_id: Id,
+	},
+
+	/// # Summary
+	///
+	/// A [`Job`] was closed on some `date`.
+	JobClosed
+	{
+		date: DateTime<Utc>,
+		id: Id,
+	},
+
+	/// # Summary
+	///
+	/// A [`Job`] was created.
+	JobCreated(Job),
+
+	/// # Summary
+	///
+	/// The [`Job`] with some [`Id`] was deleted.
+	JobDeleted(Id),
+
+	/// # Summary
+	///
+	/// A closed [`Job`] was reopened.
+	JobReopened
+	{
+		id: Id,
+	},
+
+	/// # Summary
+	///
+	/// A [`Job`] was edited, and now looks like this.
+	JobUpdated(Job),
+
+	/// # Summary
+	///
+	/// A [`Location`] was created.
+	LocationCreated(Location),
+
+	/// # Summary
+	///
+	/// The [`Location`] with some [`Id`] was deleted.
+	LocationDeleted(Id),
+
+	/// # Summary
+	///
+	/// A [`Location`] was edited, and now looks like this.
+	LocationUpdated(Location),
+
+	/// # Summary
+	///
+	/// An [`Organization`] was created.
+	OrganizationCreated(Organization),
+
+	/// # Summary
+	///
+	/// The [`Organization`] with some [`Id`] was deleted.
+	OrganizationDeleted(Id),
+
+	/// # Summary
+	///
+	/// An [`Organization`] was edited, and now looks like this.
+	OrganizationUpdated(Organization),
+
+	/// # Summary
+	///
+	/// A [`Payment`] was received towards the [`Invoice`] with some `invoice_id`.
+	PaymentReceived
+	{
+		invoice_id: Id,
+		job_id: Id,
+		payment: Payment,
+	},
+
+	/// # Summary
+	///
+	/// A [`Person`] was created.
+	PersonCreated(Person),
+
+	/// # Summary
+	///
+	/// The [`Person`] with some [`Id`] was deleted.
+	PersonDeleted(Id),
+
+	/// # Summary
+	///
+	/// A [`Person`] was edited, and now looks like this.
+	PersonUpdated(Person),
+
+	/// # Summary
+	///
+	/// Some `position` in a [`Sequence`] was given out.
+	SequenceTaken
+	{
+		position: u64,
+		sequence: Sequence,
+	},
+
+	/// # Summary
+	///
+	/// Work began on a [`Job`].
+	TimesheetStarted
+	{
+		job_id: Id,
+		timesheet: Timesheet,
+	},
+
+	/// # Summary
+	///
+	/// Work stopped on the [`Timesheet`] of some `employee_id` which began at `time_begin`.
+	///
+	/// # Remarks
+	///
+	/// `adjusted_begin` is the `time_begin` of the [`Timesheet`] after it was stopped, since it may
+	/// have been rounded to the interval in the user's configuration.
+	TimesheetStopped
+	{
+		adjusted_begin: DateTime<Utc>,
+		employee_id: Id,
+		job_id: Id,
+		time_begin: DateTime<Utc>,
+		time_end: DateTime<Utc>,
+		work_notes: String,
+	},
+}
+
+impl Event
+{
+	/// # Summary
+	///
+	/// Apply this [`Event`] to some `state`.
+	///
+	/// # Returns
+	///
+	/// * Nothing, if the [`Event`] was applied.
+	/// * An [`Error::DataIntegrity`](DataError::DataIntegrity), if the [`Event`] refers to something
+	///   which does not exist in the `state`.
+	pub fn apply(&self, state: &mut State) -> Result<(), DataError>
+	{
+		match self
+		{
+			Self::CreditNoteIssued {credit_note, invoice_id, job_id} => match Self::job_mut(state, job_id)?.invoices.iter_mut()
+				.find(|i| i.id == *invoice_id)
+			{
+				Some(invoice) => invoice.credit_notes.push(credit_note.clone()),
+				_ => return Err(DataError::DataIntegrity(*invoice_id)),
+			},
+
+			Self::EmployeeCreated(employee) | Self::EmployeeUpdated(employee) =>
+			{
+				state.employees.insert(employee.id, employee.clone());
+			},
+			Self::EmployeeDeleted(id) =>
+			{
+				state.employees.remove(id);
+			},
+
+			Self::ExpenseAdded {employee_id, expense, job_id, time_begin} =>
+				Self::timesheet_mut(state, job_id, employee_id, time_begin)?.expenses.push(expense.clone()),
+
+			Self::InvoiceIssued {invoice, job_id, through} => Self::job_mut(state, job_id)?.bill(invoice.clone(), *through),
+			Self::InvoicePaid {date, invoice_id, job_id} => match Self::job_mut(state, job_id)?.invoices.iter_mut()
+				.find(|i| i.id == *invoice_id)
+				.and_then(|i| i.date.as_mut())
+			{
+				Some(invoice_date) => invoice_date.paid = Some(*date),
+				_ => return Err(DataError::DataIntegrity(*invoice_id)),
+			},
+
+			Self::JobClosed {date, id} => Self::job_mut(state, id)?.date_close = Some(*date),
+			Self::JobCreated(job) | Self::JobUpdated(job) =>
+			{
+				state.jobs.insert(job.id, job.clone());
+			},
+			Self::JobDeleted(id) =>
+			{
+				state.jobs.remove(id);
+			},
+			Self::JobReopened {id} => Self::job_mut(state, id)?.date_close = None,
+
+			Self::LocationCreated(location) | Self::LocationUpdated(location) =>
+			{
+				state.locations.insert(location.id, location.clone());
+			},
+			Self::LocationDeleted(id) =>
+			{
+				state.locations.remove(id);
+			},
+
+			Self::OrganizationCreated(organization) | Self::OrganizationUpdated(organization) =>
+			{
+				state.organizations.insert(organization.id, organization.clone());
+			},
+			Self::OrganizationDeleted(id) =>
+			{
+				state.organizations.remove(id);
+			},
+
+			Self::PaymentReceived {invoice_id, job_id, payment} => match Self::job_mut(state, job_id)?.invoices.iter_mut()
+				.find(|i| i.id == *invoice_id)
+			{
+				Some(invoice) => invoice.payments.push(payment.clone()),
+				_ => return Err(DataError::DataIntegrity(*invoice_id)),
+			},
+
+			Self::PersonCreated(person) | Self::PersonUpdated(person) =>
+			{
+				state.people.insert(person.id, person.clone());
+			},
+			Self::PersonDeleted(id) =>
+			{
+				state.people.remove(id);
+			},
+
+			Self::SequenceTaken {position, sequence} => match state.sequences.iter_mut().find(|(s, _)| s == sequence)
+			{
+				Some((_, taken)) => *taken = (*taken).max(*position),
+				_ => state.sequences.push((*sequence, *position)),
+			},
+
+			Self::TimesheetStarted {job_id, timesheet} => Self::job_mut(state, job_id)?.timesheets.push(timesheet.clone()),
+			Self::TimesheetStopped {adjusted_begin, employee_id, job_id, time_begin, time_end, work_notes} =>
+			{
+				let timesheet = Self::timesheet_mut(state, job_id, employee_id, time_begin)?;
+				timesheet.time_begin = *adjusted_begin;
+				timesheet.time_end = Some(*time_end);
+				timesheet.work_notes = work_notes.clone();
+
+				// Stopped timesheets come before active ones, as they do when stopped from the CLI.
+				Self::job_mut(state, job_id)?.timesheets.sort_by_key(|t| (t.time_begin, t.time_end.is_none(), t.time_end));
+			},
+		};
+
+		Ok(())
+	}
+
+	/// # Summary
+	///
+	/// Describe the difference between an `old` version of a [`Job`] and a `new` one as
+	/// [`Event`]s.
+	///
+	/// # Returns
+	///
+	/// [`Event`]s which, when [applied](Event::apply) to `old`, produce `new`. If `old` and `new`
+	/// are identical, nothing is returned.
+	pub fn from_job_update(old: &Job, new: &Job) -> Vec<Self>
+	{
+		let mut events = Vec::new();
+
+		match (old.date_close, new.date_close)
+		{
+			(None, Some(date)) => events.push(Self::JobClosed {date, id: new.id}),
+			(Some(_), None) => events.push(Self::JobReopened {id: new.id}),
+			_ => (),
+		};
+
+		let same_timesheet = |t1: &Timesheet, t2: &Timesheet| t1.employee_id == t2.employee_id && t1.time_begin == t2.time_begin;
+
+		// Active timesheets which are missing from the `new` job may have been stopped (and rounded).
+		let mut stoppable: Vec<_> = old.timesheets.iter().filter(|t|
+			t.time_end.is_none() && !new.timesheets.iter().any(|n| same_timesheet(t, n))
+		).collect();
+
+		new.timesheets.iter().for_each(|timesheet|
+		{
+			let previous = old.timesheets.iter().find(|t| same_timesheet(t, timesheet)).or_else(||
+				timesheet.time_end.and_then(|_|
+					stoppable.iter().position(|t| t.employee_id == timesheet.employee_id).map(|i| stoppable.remove(i))
+				)
+			);
+
+			match previous
+			{
+				Some(p) => Self::from_timesheet_update(new.id, p, timesheet, &mut events),
+				_ =>
+				{
+					let started = Timesheet
+					{
+						expenses: Vec::new(),
+						time_end: None,
+						..timesheet.clone()
+					};
+
+					events.push(Self::TimesheetStarted {job_id: new.id, timesheet: started.clone()});
+					Self::from_timesheet_update(new.id, &started, timesheet, &mut events);
+				},
+			};
+		});
+
+		// Invoices are issued after the work they bill has been stopped.
+		new.invoices.iter().for_each(|invoice| match old.invoices.iter().find(|i| i.id == invoice.id)
+		{
+			Some(previous) => Self::from_invoice_update(new.id, previous, invoice, &mut events),
+			_ =>
+			{
+				let issued = Invoice
+				{
+					credit_notes: Vec::new(),
+					date: invoice.date.as_ref().map(|d| InvoiceDate {paid: None, ..d.clone()}),
+					payments: Vec::new(),
+					..invoice.clone()
+				};
+
+				events.push(Self::InvoiceIssued {invoice: issued.clone(), job_id: new.id, through: Self::billed_through(new, invoice)});
+				Self::from_invoice_update(new.id, &issued, invoice, &mut events);
+			},
+		});
+
+		// Anything which could not be described by another event is recorded as a snapshot. If the
+		// events cannot be applied at all, they must not be recorded, or the journal could not be
+		// replayed past them.
+		let mut state = State::default();
+		state.jobs.insert(old.id, old.clone());
+		if events.iter().try_for_each(|e| e.apply(&mut state)).is_err()
+		{
+			return vec![Self::JobUpdated(new.clone())];
+		}
+
+		if !state.jobs.get(&new.id).map(|j| util::same(j, new)).unwrap_or(false)
+		{
+			events.push(Self::JobUpdated(new.clone()));
+		}
+
+		events
+	}
+
+	/// # Summary
+	///
+	/// The latest date through which the work on a `job` that was billed on some `invoice` was done.
+	///
+	/// # Remarks
+	///
+	/// * A period of a [`Retainer`](clinvoice_data::Retainer) is billed through its end.
+	/// * If no work was billed on the `invoice`, it is the date it was issued (if at all).
+	fn billed_through(job: &Job, invoice: &Invoice) -> DateTime<Utc>
+	{
+		if let BillingModel::Retainer(retainer) = &job.billing_model
+		{
+			if let Some(period) = retainer.periods_billed.iter().find(|p| p.invoice_id == invoice.id)
+			{
+				return period.end;
+			}
+		}
+
+		let milestones = match &job.billing_model
+		{
+			BillingModel::Milestones {milestones, ..} => milestones.as_slice(),
+			_ => &[],
+		};
+
+		job.timesheets.iter().filter(|t| t.invoice_id == Some(invoice.id)).filter_map(|t| t.time_end)
+			.chain(milestones.iter().filter(|m| m.invoice_id == Some(invoice.id)).filter_map(|m| m.date_complete))
+			.max()
+			.or_else(|| invoice.date.as_ref().map(|d| d.issued))
+			.unwrap_or(job.date_open)
+	}
+
+	/// # Summary
+	///
+	/// Push [`Event`]s which describe the difference between a `previous` and `current` version of
+	/// an [`Invoice`] onto some `events`.
+	fn from_invoice_update(job_id: Id, previous: &Invoice, current: &Invoice, events: &mut Vec<Self>)
+	{
+		if current.payments.starts_with(&previous.payments)
+		{
+			current.payments.iter().skip(previous.payments.len()).for_each(|payment| events.push(Self::PaymentReceived
+			{
+				invoice_id: current.id,
+				job_id,
+				payment: payment.clone(),
+			}));
+		}
+
+		if current.credit_notes.starts_with(&previous.credit_notes)
+		{
+			current.credit_notes.iter().skip(previous.credit_notes.len()).for_each(|credit_note| events.push(Self::CreditNoteIssued
+			{
+				credit_note: credit_note.clone(),
+				invoice_id: current.id,
+				job_id,
+			}));
+		}
+
+		if let (Some(previous_date), Some(current_date)) = (&previous.date, &current.date)
+		{
+			if let (None, Some(paid)) = (previous_date.paid, current_date.paid)
+			{
+				events.push(Self::InvoicePaid {date: paid, invoice_id: current.id, job_id});
+			}
+		}
+	}
+
+	/// # Summary
+	///
+	/// Push [`Event`]s which describe the difference between a `previous` and `current` version of
+	/// a [`Timesheet`] onto some `events`.
+	fn from_timesheet_update(job_id: Id, previous: &Timesheet, current: &Timesheet, events: &mut Vec<Self>)
+	{
+		if current.expenses.starts_with(&previous.expenses)
+		{
+			current.expenses.iter().skip(previous.expenses.len()).for_each(|expense| events.push(Self::ExpenseAdded
+			{
+				employee_id: previous.employee_id,
+				expense: expense.clone(),
+				job_id,
+				time_begin: previous.time_begin,
+			}));
+		}
+
+		if let (None, Some(time_end)) = (previous.time_end, current.time_end)
+		{
+			events.push(Self::TimesheetStopped
+			{
+				adjusted_begin: current.time_begin,
+				employee_id: previous.employee_id,
+				job_id,
+				time_begin: previous.time_begin,
+				time_end,
+				work_notes: current.work_notes.clone(),
+			});
+		}
+	}
+
+	/// # Summary
+	///
+	/// Get the [`Job`] with some `id` from the `state`.
+	fn job_mut<'state>(state: &'state mut State, id: &Id) -> Result<&'state mut Job, DataError>
+	{
+		state.jobs.get_mut(id).ok_or(DataError::DataIntegrity(*id))
+	}
+
+	/// # Summary
+	///
+	/// Get the [`Timesheet`] of some `employee_id` which began at `time_begin` from the [`Job`]
+	/// with some `job_id` in the `state`.
+	fn timesheet_mut<'state>(state: &'state mut State, job_id: &Id, employee_id: &Id, time_begin: &DateTime<Utc>)
+		-> Result<&'state mut Timesheet, DataError>
+	{
+		Self::job_mut(state, job_id)?.timesheets.iter_mut()
+			.find(|t| t.employee_id == *employee_id && t.time_begin == *time_begin)
+			.ok_or(DataError::DataIntegrity(*employee_id))
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use
+	{
+		std::time::Instant,
+
+		super::{BillingModel, Event, Id, Job, State, Utc},
+		crate::util,
+
+		clinvoice_data::finance::{Currency, Money},
+	};
+
+	#[test]
+	fn from_job_update()
+	{
+		let old = Job
+		{
+			adjustments: Vec::new(),
+			bill_to: Default::default(),
+			billing_model: BillingModel::Hourly(Money::new(20_00, 2, Currency::USD)),
+			client_id: Id::new_v4(),
+			date_close: None,
+			date_open: Utc::now(),
+			employee_default_rates: Default::default(),
+			employee_rates: Default::default(),
+			expense_markup: Default::default(),
+			id: Id::new_v4(),
+			invoices: Vec::new(),
+			objectives: "Test the job update".into(),
+			notes: "".into(),
+			overtime: None,
+			rate_changes: Vec::new(),
+			timesheets: Vec::new(),
+		};
+
+		let mut closed = old.clone();
+		closed.date_close = Some(Utc::now());
+
+		// Closing a job with a different `Id` describes it as `JobClosed`, which cannot be applied to `old`.
+		let mut moved = closed.clone();
+		moved.id = Id::new_v4();
+
+		let start = Instant::now();
+		let closed_events = Event::from_job_update(&old, &closed);
+		let moved_events = Event::from_job_update(&old, &moved);
+		println!("\n>>>>> Event::from_job_update {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / 2);
+
+		assert!(matches!(closed_events.as_slice(), [Event::JobClosed {..}]));
+		assert!(matches!(moved_events.as_slice(), [Event::JobUpdated(j)] if util::same(j, &moved)));
+
+		// Every event which was returned can be replayed.
+		let mut state = State::default();
+		state.jobs.insert(old.id, old.clone());
+		moved_events.iter().for_each(|e| e.apply(&mut state).unwrap());
+		assert!(util::same(&state.jobs[&moved.id], &moved));
+	}
+}
@@ -0,0 +1,25 @@
+use
+{
+	super::Event,
+
+	clinvoice_data::chrono::{DateTime, Utc},
+
+	serde::{Deserialize, Serialize},
+};
+
+/// # Summary
+///
+/// An [`Event`] as it is written to the journal.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Record
+{
+	/// # Summary
+	///
+	/// The [`Event`] which occurred.
+	pub event: Event,
+
+	/// # Summary
+	///
+	/// The time at which the [`Event`] was appended to the journal.
+	pub timestamp: DateTime<Utc>,
+}
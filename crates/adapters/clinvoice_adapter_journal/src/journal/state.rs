@@ -0,0 +1,51 @@
+use
+{
+	std::collections::BTreeMap,
+
+	clinvoice_adapter::data::Sequence,
+	clinvoice_data::{Employee, Id, Job, Location, Organization, Person},
+
+	serde::{Deserialize, Serialize},
+};
+
+/// # Summary
+///
+/// The state of a [`Store`](clinvoice_adapter::Store) after some [`Event`](super::Event)s have
+/// been replayed.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct State
+{
+	/// # Summary
+	///
+	/// Every [`Employee`] which exists, by [`Id`].
+	pub employees: BTreeMap<Id, Employee>,
+
+	/// # Summary
+	///
+	/// Every [`Job`] which exists, by [`Id`].
+	pub jobs: BTreeMap<Id, Job>,
+
+	/// # Summary
+	///
+	/// Every [`Location`] which exists, by [`Id`].
+	pub locations: BTreeMap<Id, Location>,
+
+	/// # Summary
+	///
+	/// Every [`Organization`] which exists, by [`Id`].
+	pub organizations: BTreeMap<Id, Organization>,
+
+	/// # Summary
+	///
+	/// Every [`Person`] which exists, by [`Id`].
+	pub people: BTreeMap<Id, Person>,
+
+	/// # Summary
+	///
+	/// The last position which was taken in each [`Sequence`].
+	///
+	/// # Remarks
+	///
+	/// This is not a map because JSON objects may only be keyed by strings.
+	pub sequences: Vec<(Sequence, u64)>,
+}
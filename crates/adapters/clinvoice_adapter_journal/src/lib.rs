@@ -0,0 +1,19 @@
+//! # Summary
+//!
+//! This crate provides an implementation of [`clinvoice_adapter`] whose source of truth is an
+//! append-only [journal] of events.
+//!
+//! # Remarks
+//!
+//! Nothing is ever overwritten or removed from the [journal]. Instead, each operation (e.g.
+//! starting a [`Timesheet`](clinvoice_data::Timesheet)) is recorded as an
+//! [`Event`](journal::Event), and the current state of a [`Store`](clinvoice_adapter::Store) is
+//! materialized by replaying every [`Event`](journal::Event) in order. This makes it possible to
+//! audit how a [`Store`](clinvoice_adapter::Store) came to be, and to see what it looked like at
+//! any point in time.
+
+#![allow(clippy::from_over_into)]
+
+pub mod data;
+pub mod journal;
+mod util;
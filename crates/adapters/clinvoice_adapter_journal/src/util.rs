@@ -0,0 +1,168 @@
+use
+{
+	std::
+	{
+		collections::BTreeMap,
+		fs, io,
+		path::{Path, PathBuf},
+	},
+
+	crate::data::Result as DataResult,
+
+	clinvoice_adapter::Store,
+	clinvoice_data::{Attachment, Id, UUID_NAMESPACE},
+
+	serde::Serialize,
+};
+
+#[cfg(test)]
+use
+{
+	clinvoice_adapter::Adapters,
+	std::env,
+};
+
+/// # Summary
+///
+/// Copy the file at some `path` into the attachments of a `store`, unless a file with the same
+/// contents was already attached.
+///
+/// # Errors
+///
+/// * When [`fs::read`] or [`fs::write`] does.
+/// * When the attachments of the `store` can't be created.
+pub fn attach(path: &Path, store: &Store) -> io::Result<Attachment>
+{
+	let contents = fs::read(path)?;
+	let name = path.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default();
+	let attachment = Attachment::new(name, &contents);
+
+	let attachments_path = attachments_path(store);
+	create_store_dir(&attachments_path)?;
+
+	let filepath = attachments_path.join(&attachment.hash);
+	if !filepath.is_file()
+	{
+		fs::write(filepath, contents)?;
+	}
+
+	Ok(attachment)
+}
+
+/// # Summary
+///
+/// Get the [`PathBuf`] pointing to where the attachments of a `store` are kept.
+pub fn attachments_path(store: &Store) -> PathBuf
+{
+	expand_store_path(store).join("Attachments")
+}
+
+/// # Summary
+///
+/// Create some `dir` within `store`.
+///
+/// # Parameters
+///
+/// * `store_dir`, the directory in the [`Store`] to create.
+///
+/// # Returns
+///
+/// * `true`, if the directory was created.
+/// * `false`, if the directory already existed.
+/// * An `Error`, if `store_dir` couldn't be created.
+pub fn create_store_dir(store_dir: &Path) -> io::Result<bool>
+{
+	if !store_dir.is_dir()
+	{
+		fs::create_dir_all(store_dir)?;
+		return Ok(true);
+	}
+
+	Ok(false)
+}
+
+/// # Summary
+///
+/// Expand the `store`'s specified path.
+pub fn expand_store_path(store: &Store) -> PathBuf
+{
+	shellexpand::full(&store.path).map(|p| p.as_ref().into()).unwrap_or_else(|_| store.path.as_str().into())
+}
+
+/// # Summary
+///
+/// Retrieves all [`T`]s from some materialized `entities` where `query` is `true`.
+pub fn retrieve<T>(entities: BTreeMap<Id, T>, query: impl Fn(&T) -> DataResult<bool>) -> DataResult<Vec<T>>
+{
+	entities.into_values().filter_map(|t| match query(&t)
+	{
+		Ok(b) if b => Some(Ok(t)),
+		Err(e) => Some(Err(e)),
+		_ => None,
+	}).collect()
+}
+
+/// # Summary
+///
+/// Whether or not `left` and `right` would be recorded identically in a journal.
+///
+/// # Remarks
+///
+/// This is necessary because some [`clinvoice_data`] types implement [`PartialEq`] by comparing
+/// only their [`Id`]s.
+pub fn same<T>(left: &T, right: &T) -> bool where
+	T : Serialize,
+{
+	match (bincode::serialize(left), bincode::serialize(right))
+	{
+		(Ok(l), Ok(r)) => l == r,
+		_ => false,
+	}
+}
+
+/// # Summary
+///
+/// Test some `assertion` using a new directory within the OS's [temp dir][fn_temp_dir].
+///
+/// # Remarks
+///
+/// Since a journal is a single file, every call receives its own directory so that tests running
+/// in parallel do not see each other's events. The directory is removed once the `assertion`
+/// passes, and kept for inspection otherwise.
+///
+/// [fn_temp_dir]: std::env::temp_dir
+#[cfg(test)]
+pub fn temp_store(assertion: impl FnOnce(&Store))
+{
+	let temp_path = env::temp_dir().join("clinvoice_adapter_journal_data").join(Id::new_v4().to_string());
+
+	assertion(&Store
+	{
+		adapter: Adapters::Journal,
+		password: None,
+		path: temp_path.to_str().expect("`env::temp_path` did not resolve to a valid path").into(),
+		read_only: false,
+		username: None,
+	});
+
+	if temp_path.is_dir()
+	{
+		fs::remove_dir_all(temp_path).unwrap();
+	}
+}
+
+/// # Summary
+///
+/// Get the next [`Id`] for an entity which does not already exist in `entities`.
+pub fn unique_id<T>(entities: &BTreeMap<Id, T>) -> Id
+{
+	loop
+	{
+		let id = Id::new_v5(&UUID_NAMESPACE, Id::new_v4().as_bytes());
+
+		if !entities.contains_key(&id)
+		{
+			return id;
+		}
+	}
+}
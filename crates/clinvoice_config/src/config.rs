@@ -8,10 +8,11 @@ use
 	core::time::Duration,
 	std::{collections::BTreeMap, path::PathBuf},
 
-	crate::{Employees, Invoices, StoreValue, Timesheets},
+	crate::{Contacts, Employees, Expenses, Invoices, Organizations, StoreValue, Timesheets},
 	clinvoice_adapter::{Adapters, data::Updatable, Store},
 	clinvoice_data::{Id, finance::Currency},
 
+	glob::Pattern,
 	serde::{Deserialize, Serialize},
 };
 
@@ -21,16 +22,34 @@ use
 #[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub struct Config<'alias, 'name>
 {
+	/// # Summary
+	///
+	/// Configurations for [`Contact`](clinvoice_data::Contact) information.
+	#[serde(default)]
+	pub contacts: Contacts,
+
 	/// # Summary
 	///
 	/// Configurations for [`Employee`](clinvoice_data::employee::Employee)s.
 	pub employees: Employees,
 
+	/// # Summary
+	///
+	/// Configurations for [`Expense`](clinvoice_data::Expense)s.
+	#[serde(default)]
+	pub expenses: Expenses,
+
 	/// # Summary
 	///
 	/// Configurations for [`Invoice`](clinvoice_data::invoice::Invoice)s.
 	pub invoices: Invoices,
 
+	/// # Summary
+	///
+	/// Configurations for [`Organization`](clinvoice_data::Organization)s.
+	#[serde(default)]
+	pub organizations: Organizations,
+
 	/// # Summary
 	///
 	/// Configurations for data storages.
@@ -54,8 +73,19 @@ impl Config<'_, '_>
 		{
 			let config = Self
 			{
+				contacts: Default::default(),
 				employees: Employees {default_id: Id::default()},
-				invoices: Invoices {default_currency: Currency::USD},
+				expenses: Default::default(),
+				invoices: Invoices
+				{
+					credit_note_number_format: Invoices::default_credit_note_number_format(),
+					default_currency: Currency::USD,
+					number_format: Invoices::default_number_format(),
+					number_per_client: false,
+					payment_terms: Default::default(),
+					taxes: Default::default(),
+				},
+				organizations: Default::default(),
 				stores: vec![
 					("default", StoreValue::Alias("foo")),
 					("foo", StoreValue::Storage(Store
@@ -64,6 +94,7 @@ impl Config<'_, '_>
 						password: Some("Optional password. May or may not be accompanied by a username".into()),
 						username: Some("Optional username. May or may not be accompanied by a password".into()),
 						path: "Place where data can be found. Depends on the adapter— may be a path to a folder on a filesystem, or a schema on a database".into(),
+						read_only: false,
 					})),
 				].into_iter().collect(),
 				timesheets: Timesheets {interval: Duration::from_secs(300)},
@@ -95,6 +126,56 @@ impl Config<'_, '_>
 		})
 	}
 
+	/// # Summary
+	///
+	/// Get every [`Store`] which is named by, or matches a glob in, `patterns`.
+	///
+	/// # Remarks
+	///
+	/// * An exact name may be an alias, which is resolved as in [`Config::get_store`]. Aliases are
+	///   not matched by globs, since the [`Store`] they point to is already.
+	/// * Each [`Store`] is only returned once, under the first name which selected it.
+	///
+	/// # Returns
+	///
+	/// * The name of each selected [`Store`], and the [`Store`] itself, in the order selected.
+	/// * An [`Error::NoStore`] if some pattern did not match anything.
+	/// * An [`Error::Pattern`] if some pattern is not a valid glob.
+	pub fn get_stores<S>(&self, patterns: &[S]) -> Result<Vec<(&str, &Store)>> where
+		S : AsRef<str>,
+	{
+		let mut selected: Vec<(&str, &Store)> = Vec::with_capacity(patterns.len());
+
+		patterns.iter().try_for_each(|pattern|
+		{
+			let pattern = pattern.as_ref();
+			let mut matches: Vec<(&str, &Store)> = match self.stores.get_key_value(pattern)
+			{
+				Some((name, _)) => self.get_store(name).map(|store| vec![(*name, store)]).unwrap_or_default(),
+				_ =>
+				{
+					let glob = Pattern::new(pattern)?;
+					self.stores.iter().filter_map(|(name, value)| match value
+					{
+						StoreValue::Storage(store) if glob.matches(name) => Some((*name, store)),
+						_ => None,
+					}).collect()
+				},
+			};
+
+			if matches.is_empty()
+			{
+				return Err(Error::NoStore(pattern.into()));
+			}
+
+			matches.retain(|(_, store)| !selected.iter().any(|(_, s)| s == store));
+			selected.append(&mut matches);
+			Ok(())
+		})?;
+
+		Ok(selected)
+	}
+
 	pub fn path() -> PathBuf
 	{
 		dirs::config_dir().expect("Operating System is not supported").join("clinvoice").join("config.toml")
@@ -125,20 +206,33 @@ mod tests
 			adapter: Adapters::Bincode,
 			password: None,
 			path: "c/path".into(),
+			read_only: false,
 			username: None,
 		}));
 		stores.insert("d", StoreValue::Storage(Store {
 			adapter: Adapters::Bincode,
 			password: Some("asldkj".into()),
 			path: "d/path".into(),
+			read_only: true,
 			username: None,
 		}));
 		stores.insert("e", StoreValue::Alias("d"));
 
 		let conf = Config
 		{
+			contacts: Default::default(),
 			employees: Employees {default_id: Id::new_v4()},
-			invoices: Invoices {default_currency: Currency::USD},
+			expenses: Default::default(),
+			invoices: Invoices
+			{
+				credit_note_number_format: Invoices::default_credit_note_number_format(),
+				default_currency: Currency::USD,
+				number_format: Invoices::default_number_format(),
+				number_per_client: false,
+				payment_terms: Default::default(),
+				taxes: Default::default(),
+			},
+			organizations: Default::default(),
 			stores,
 			timesheets: Timesheets {interval: Duration::new(100, 0)},
 		};
@@ -156,4 +250,76 @@ mod tests
 
 		println!("\n>>>>> Config::get_store {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / 12);
 	}
+
+	#[test]
+	fn get_stores()
+	{
+		let mut stores = BTreeMap::new();
+
+		stores.insert("client_a", StoreValue::Storage(Store {
+			adapter: Adapters::Bincode,
+			password: None,
+			path: "a/path".into(),
+			read_only: false,
+			username: None,
+		}));
+		stores.insert("client_b", StoreValue::Storage(Store {
+			adapter: Adapters::Bincode,
+			password: None,
+			path: "b/path".into(),
+			read_only: false,
+			username: None,
+		}));
+		stores.insert("client_c", StoreValue::Alias("client_a"));
+		stores.insert("default", StoreValue::Alias("client_b"));
+		stores.insert("personal", StoreValue::Storage(Store {
+			adapter: Adapters::Bincode,
+			password: None,
+			path: "personal/path".into(),
+			read_only: false,
+			username: None,
+		}));
+
+		let conf = Config
+		{
+			contacts: Default::default(),
+			employees: Employees {default_id: Id::new_v4()},
+			expenses: Default::default(),
+			invoices: Invoices
+			{
+				credit_note_number_format: Invoices::default_credit_note_number_format(),
+				default_currency: Currency::USD,
+				number_format: Invoices::default_number_format(),
+				number_per_client: false,
+				payment_terms: Default::default(),
+				taxes: Default::default(),
+			},
+			organizations: Default::default(),
+			stores,
+			timesheets: Timesheets {interval: Duration::new(100, 0)},
+		};
+
+		let names = |patterns: &[&str]| -> Vec<String>
+		{
+			conf.get_stores(patterns).unwrap().into_iter().map(|(name, _)| name.into()).collect()
+		};
+
+		let start = Instant::now();
+
+		// Exact names, including aliases
+		assert_eq!(names(&["default"]), vec!["default"]);
+		assert_eq!(names(&["personal", "client_a"]), vec!["personal", "client_a"]);
+
+		// Globs skip aliases, and each store is only selected once
+		assert_eq!(names(&["client_*"]), vec!["client_a", "client_b"]);
+		assert_eq!(names(&["default", "client_*"]), vec!["default", "client_a"]);
+		assert_eq!(names(&["*"]), vec!["client_a", "client_b", "personal"]);
+
+		// Patterns which match nothing are errors
+		assert!(conf.get_stores(&["foo"]).is_err());
+		assert!(conf.get_stores(&["client_a", "foo*"]).is_err());
+		assert!(conf.get_stores(&["[client"]).is_err());
+
+		println!("\n>>>>> Config::get_stores {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / 8);
+	}
 }
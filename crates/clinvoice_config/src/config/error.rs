@@ -11,6 +11,21 @@ pub enum Error
 	#[error("{0}")]
 	Io(#[from] io::Error),
 
+	/// # Summary
+	///
+	/// Some store name or glob did not match any store in the configuration.
+	#[error("No store in the configuration matches `{0}`")]
+	NoStore(String),
+
+	#[error("{0}")]
+	Pattern(#[from] glob::PatternError),
+
+	/// # Summary
+	///
+	/// An operation which works on one store was given more than one.
+	#[error("This operation can only be performed on one store, but several were selected: {0}")]
+	TooManyStores(String),
+
 	#[error("{0}")]
 	TomlDe(#[from] toml::de::Error),
 
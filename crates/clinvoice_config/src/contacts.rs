@@ -0,0 +1,15 @@
+use serde::{Deserialize, Serialize};
+
+/// # Summary
+///
+/// Configurations for [`Contact`](clinvoice_data::Contact) information.
+#[derive(Clone, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct Contacts
+{
+	/// # Summary
+	///
+	/// The ISO 3166-1 alpha-2 code of the country (e.g. "US") which phone and fax numbers are
+	/// assumed to be in when they are entered without a country calling code.
+	#[serde(default)]
+	pub default_region: Option<String>,
+}
@@ -0,0 +1,109 @@
+use
+{
+	std::collections::BTreeMap,
+
+	clinvoice_data::{finance::Money, DistanceUnit, ExpenseCategory},
+
+	serde::{Deserialize, Serialize},
+};
+
+/// # Summary
+///
+/// Configurations for [`Expense`](clinvoice_data::Expense)s.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct Expenses
+{
+	/// # Summary
+	///
+	/// The [`ExpenseCategory`]s which an [`Expense`](clinvoice_data::Expense) may be recorded under.
+	#[serde(default="Expenses::default_categories")]
+	pub categories: Vec<ExpenseCategory>,
+
+	/// # Summary
+	///
+	/// The unit which distances travelled are measured in, and which the `mileage_rates` are per.
+	#[serde(default)]
+	pub distance_unit: DistanceUnit,
+
+	/// # Summary
+	///
+	/// The amounts reimbursed for each `distance_unit` travelled, by name (e.g. "Car").
+	#[serde(default)]
+	pub mileage_rates: BTreeMap<String, Money>,
+
+	/// # Summary
+	///
+	/// The amounts reimbursed for each day spent travelling, by name (e.g. "Domestic").
+	#[serde(default)]
+	pub per_diem_rates: BTreeMap<String, Money>,
+}
+
+impl Expenses
+{
+	/// # Summary
+	///
+	/// The [`ExpenseCategory`]s which are available when none have been configured. These are the
+	/// categories which every [`Expense`](clinvoice_data::Expense) was recorded under before they
+	/// could be configured.
+	pub fn default_categories() -> Vec<ExpenseCategory>
+	{
+		["Food", "Item", "Other", "Service", "Software", "Travel"].iter().map(|name| ExpenseCategory::new(name)).collect()
+	}
+}
+
+impl Default for Expenses
+{
+	fn default() -> Self
+	{
+		Self
+		{
+			categories: Self::default_categories(),
+			distance_unit: Default::default(),
+			mileage_rates: Default::default(),
+			per_diem_rates: Default::default(),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use
+	{
+		std::time::Instant,
+
+		super::{DistanceUnit, ExpenseCategory, Expenses, Money},
+		clinvoice_data::{finance::{Currency, Decimal}, Tax},
+	};
+
+	#[test]
+	fn deserialize()
+	{
+		let start = Instant::now();
+		let expenses: Expenses = toml::from_str(r#"
+			categories = [
+				"Food",
+				{code = "6100", name = "Lodging", markup = "10", taxes = [{Rate = {name = "VAT", percent = "7"}}]},
+			]
+			distance_unit = "Mile"
+			mileage_rates = {Car = {amount = "0.70", currency = "USD"}}
+		"#).unwrap();
+		println!("\n>>>>> Expenses::deserialize {}us <<<<<\n", Instant::now().duration_since(start).as_micros());
+
+		assert_eq!(expenses.categories, vec![
+			ExpenseCategory::new("Food"),
+			ExpenseCategory
+			{
+				code: "6100".into(),
+				markup: Some(Decimal::new(10, 0)),
+				name: "Lodging".into(),
+				taxes: Some(vec![Tax::Rate {name: "VAT".into(), percent: Decimal::new(7, 0)}]),
+			},
+		]);
+		assert_eq!(expenses.distance_unit, DistanceUnit::Mile);
+		assert_eq!(expenses.mileage_rates, vec![("Car".into(), Money::new(70, 2, Currency::USD))].into_iter().collect());
+		assert!(expenses.per_diem_rates.is_empty());
+
+		assert_eq!(toml::from_str::<Expenses>("").unwrap(), Expenses::default());
+	}
+}
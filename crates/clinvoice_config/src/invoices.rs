@@ -1,6 +1,13 @@
 use
 {
-	clinvoice_data::finance::Currency,
+	core::fmt::Write,
+
+	clinvoice_data::
+	{
+		chrono::{DateTime, Datelike, Local, Utc},
+		finance::Currency,
+		PaymentTerms, Taxes,
+	},
 
 	serde::{Deserialize, Serialize},
 };
@@ -8,8 +15,218 @@ use
 /// # Summary
 ///
 /// Configurations for [`Invoice`](clinvoice_data::invoice::Invoice)s.
-#[derive(Copy, Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
 pub struct Invoices
 {
+	/// # Summary
+	///
+	/// The format of the number which is given to a [`CreditNote`](clinvoice_data::CreditNote)
+	/// when it is issued.
+	///
+	/// # Remarks
+	///
+	/// Supports the same placeholders as [`Invoices::number_format`], except that `{seq}` is the
+	/// position of the credit note in the sequence of issued credit notes.
+	#[serde(default="Invoices::default_credit_note_number_format")]
+	pub credit_note_number_format: String,
+
 	pub default_currency: Currency,
+
+	/// # Summary
+	///
+	/// The format of the number which is given to an [`Invoice`](clinvoice_data::invoice::Invoice)
+	/// when it is issued.
+	///
+	/// # Remarks
+	///
+	/// The following placeholders are replaced:
+	///
+	/// * `{year}`, `{month}`, and `{day}` with the date of issue.
+	/// * `{seq}` with the position of the invoice in the sequence of issued invoices.
+	///
+	/// Any placeholder may be padded with zeroes to some width, e.g. `{seq:04}`.
+	#[serde(default="Invoices::default_number_format")]
+	pub number_format: String,
+
+	/// # Summary
+	///
+	/// Whether each client [`Organization`](clinvoice_data::Organization) has its own sequence of
+	/// invoice numbers, rather than sharing one sequence per store.
+	#[serde(default)]
+	pub number_per_client: bool,
+
+	/// # Summary
+	///
+	/// The [`PaymentTerms`] which are given on an [`Invoice`](clinvoice_data::invoice::Invoice) when
+	/// its client [`Organization`](clinvoice_data::Organization) does not specify any.
+	#[serde(default)]
+	pub payment_terms: PaymentTerms,
+
+	/// # Summary
+	///
+	/// The [`Taxes`] which apply to an [`Invoice`](clinvoice_data::invoice::Invoice) when neither
+	/// its client [`Organization`](clinvoice_data::Organization) nor that client's
+	/// [`Location`](clinvoice_data::Location) specify any.
+	#[serde(default)]
+	pub taxes: Taxes,
+}
+
+impl Invoices
+{
+	/// # Summary
+	///
+	/// Create the number of a credit note which was `issued` at some point, and is `seq`th in the
+	/// sequence of issued credit notes, according to the [`Invoices::credit_note_number_format`].
+	pub fn credit_note_number(&self, issued: DateTime<Utc>, seq: u64) -> String
+	{
+		Self::format_number(&self.credit_note_number_format, issued, seq)
+	}
+
+	/// # Summary
+	///
+	/// The [`Invoices::credit_note_number_format`] which is used if none is configured.
+	pub fn default_credit_note_number_format() -> String
+	{
+		"CN-{year}-{seq:04}".into()
+	}
+
+	/// # Summary
+	///
+	/// The [`Invoices::number_format`] which is used if none is configured.
+	pub fn default_number_format() -> String
+	{
+		"INV-{year}-{seq:04}".into()
+	}
+
+	/// # Summary
+	///
+	/// Create the number of an invoice which was `issued` at some point, and is `seq`th in the
+	/// sequence of issued invoices, according to the [`Invoices::number_format`].
+	pub fn number(&self, issued: DateTime<Utc>, seq: u64) -> String
+	{
+		Self::format_number(&self.number_format, issued, seq)
+	}
+
+	/// # Summary
+	///
+	/// Replace the placeholders in some number `format` with the date a document was `issued`, and
+	/// its position (`seq`) in its sequence.
+	fn format_number(format: &str, issued: DateTime<Utc>, seq: u64) -> String
+	{
+		let issued = DateTime::<Local>::from(issued);
+		let mut number = String::with_capacity(format.len());
+		let mut remaining = format;
+
+		while let Some(start) = remaining.find('{')
+		{
+			number.push_str(&remaining[..start]);
+			remaining = &remaining[start..];
+
+			let end = match remaining.find('}')
+			{
+				Some(end) => end,
+				_ => break,
+			};
+
+			let (name, width) = match remaining[1..end].split_once(':')
+			{
+				Some((name, width)) => (name, width.parse::<usize>().ok()),
+				_ => (&remaining[1..end], None),
+			};
+
+			let value = match name
+			{
+				"day" => u64::from(issued.day()),
+				"month" => u64::from(issued.month()),
+				"seq" => seq,
+				"year" => issued.year() as u64,
+
+				// Leave unknown placeholders as they were.
+				_ =>
+				{
+					number.push_str(&remaining[..=end]);
+					remaining = &remaining[end + 1..];
+					continue;
+				},
+			};
+
+			write!(number, "{:0width$}", value, width=width.unwrap_or(0)).unwrap();
+			remaining = &remaining[end + 1..];
+		}
+
+		number.push_str(remaining);
+		number
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use
+	{
+		std::time::Instant,
+
+		super::{Currency, Invoices, PaymentTerms},
+
+		clinvoice_data::
+		{
+			chrono::{DateTime, Local, TimeZone, Utc},
+			finance::Decimal,
+			ExpenseCategory, Tax,
+		},
+	};
+
+	#[test]
+	fn number()
+	{
+		let issued = DateTime::<Utc>::from(Local.ymd(2021, 3, 7).and_hms(12, 0, 0));
+		let mut invoices = Invoices
+		{
+			credit_note_number_format: Invoices::default_credit_note_number_format(),
+			default_currency: Currency::USD,
+			number_format: Invoices::default_number_format(),
+			number_per_client: false,
+			payment_terms: Default::default(),
+			taxes: Default::default(),
+		};
+
+		let start = Instant::now();
+		assert_eq!(invoices.number(issued, 12), "INV-2021-0012");
+		assert_eq!(invoices.number(issued, 12345), "INV-2021-12345");
+
+		invoices.number_format = "{year}{month:02}{day:02}/{seq}".into();
+		assert_eq!(invoices.number(issued, 3), "20210307/3");
+
+		invoices.number_format = "{client}-{seq:03}-{".into();
+		assert_eq!(invoices.number(issued, 3), "{client}-003-{");
+
+		assert_eq!(invoices.credit_note_number(issued, 7), "CN-2021-0007");
+		println!("\n>>>>> Invoices::number {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / 5);
+	}
+
+	#[test]
+	fn taxes()
+	{
+		let start = Instant::now();
+		let invoices: Invoices = toml::from_str(r#"
+			default_currency = "EUR"
+
+			[taxes]
+			rates = [{Rate = {name = "VAT", percent = "19"}}]
+			expenses = {Food = [{Rate = {name = "VAT", percent = "7"}}], Travel = [{Exempt = {reason = "§4 UStG"}}]}
+		"#).unwrap();
+		println!("\n>>>>> Invoices::deserialize {}us <<<<<\n", Instant::now().duration_since(start).as_micros());
+
+		assert_eq!(invoices.credit_note_number_format, Invoices::default_credit_note_number_format());
+		assert_eq!(invoices.number_format, Invoices::default_number_format());
+		assert_eq!(invoices.payment_terms, PaymentTerms::Net(30));
+		assert_eq!(invoices.taxes.rates, vec![Tax::Rate {name: "VAT".into(), percent: Decimal::new(19, 0)}]);
+		assert_eq!(invoices.taxes.for_expense(&ExpenseCategory::new("Food")), &[Tax::Rate {name: "VAT".into(), percent: Decimal::new(7, 0)}]);
+		assert_eq!(invoices.taxes.for_expense(&ExpenseCategory::new("Travel")), &[Tax::Exempt {reason: "§4 UStG".into()}]);
+		assert_eq!(invoices.taxes.for_expense(&ExpenseCategory::new("Item")), invoices.taxes.rates.as_slice());
+
+		// A category's own taxes apply unless the invoice says otherwise.
+		let lodging = ExpenseCategory {taxes: Some(vec![Tax::ReverseCharge]), ..ExpenseCategory::new("Lodging")};
+		assert_eq!(invoices.taxes.for_expense(&lodging), &[Tax::ReverseCharge]);
+	}
 }
@@ -3,16 +3,22 @@
 //! This crate provides definitions of what a user's `clinvoice` configuration should look like.
 
 mod config;
+mod contacts;
 mod employees;
+mod expenses;
 mod invoices;
+mod organizations;
 mod store_value;
 mod timesheets;
 
 pub use
 {
 	config::{Config, Error, Result},
+	contacts::Contacts,
 	employees::Employees,
+	expenses::Expenses,
 	invoices::Invoices,
+	organizations::Organizations,
 	store_value::StoreValue,
 	timesheets::Timesheets,
 };
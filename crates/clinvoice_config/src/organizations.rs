@@ -0,0 +1,20 @@
+use
+{
+	clinvoice_data::Id,
+
+	serde::{Deserialize, Serialize},
+};
+
+/// # Summary
+///
+/// Configurations for [`Organization`](clinvoice_data::Organization)s.
+#[derive(Copy, Clone, Debug, Default, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd, Serialize)]
+pub struct Organizations
+{
+	/// # Summary
+	///
+	/// The [`Id`] of the organization which issues invoices, and whose details (e.g. tax
+	/// identifiers and payment methods) should be shown on exports.
+	#[serde(default)]
+	pub issuer_id: Option<Id>,
+}
@@ -0,0 +1,40 @@
+use crate::{AdjustmentAmount, Id};
+
+#[cfg(feature="serde_support")]
+use serde::{Deserialize, Serialize};
+
+/// # Summary
+///
+/// An `Adjustment` is a change to the amount owed on an [`Invoice`](crate::Invoice) which is not
+/// tied to any work, such as a discount or a rush fee.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature="serde_support", derive(Deserialize, Serialize))]
+pub struct Adjustment
+{
+	/// # Summary
+	///
+	/// How much is added to the amount owed. Discounts are negative, and surcharges are positive.
+	pub amount: AdjustmentAmount,
+
+	/// # Summary
+	///
+	/// Whether the [`Adjustment`] is applied before tax (and so changes the amount which is taxed)
+	/// or after it.
+	pub before_tax: bool,
+
+	/// # Summary
+	///
+	/// What the [`Adjustment`] is for, e.g. "Loyalty discount".
+	pub description: String,
+
+	/// # Summary
+	///
+	/// The [`Id`] of the [`Invoice`](crate::Invoice) which this [`Adjustment`] was billed on.
+	///
+	/// # Remarks
+	///
+	/// If this is left blank, the [`Adjustment`] is billed on the next
+	/// [`Invoice`](crate::Invoice).
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub invoice_id: Option<Id>,
+}
@@ -0,0 +1,30 @@
+use clinvoice_finance::Decimal;
+
+#[cfg(feature="serde_support")]
+use serde::{Deserialize, Serialize};
+
+/// # Summary
+///
+/// How much an [`Adjustment`](crate::Adjustment) changes the amount owed on an
+/// [`Invoice`](crate::Invoice).
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature="serde_support", derive(Deserialize, Serialize))]
+pub enum AdjustmentAmount
+{
+	/// # Summary
+	///
+	/// A fixed amount, in the currency of the [`Job::billing_model`](crate::Job::billing_model).
+	Fixed(Decimal),
+
+	/// # Summary
+	///
+	/// A percentage of the amount owed, e.g. `-10` for a 10% discount.
+	///
+	/// # Remarks
+	///
+	/// If the [`Adjustment`](crate::Adjustment) is applied before tax, this is a percentage of
+	/// the net amount owed for the work, [`Expense`](crate::Expense)s, and fixed
+	/// [`Adjustment`](crate::Adjustment)s before tax. Otherwise, it is a percentage of the amount
+	/// owed after tax.
+	Percent(Decimal),
+}
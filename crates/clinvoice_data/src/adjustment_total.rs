@@ -0,0 +1,23 @@
+use
+{
+	crate::Adjustment,
+
+	clinvoice_finance::Money,
+};
+
+/// # Summary
+///
+/// The amount which some [`Adjustment`] adds to an [`Invoice`](crate::Invoice).
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct AdjustmentTotal
+{
+	/// # Summary
+	///
+	/// The [`Adjustment`] which was applied.
+	pub adjustment: Adjustment,
+
+	/// # Summary
+	///
+	/// The amount which was added. It is negative for discounts.
+	pub amount: Money,
+}
@@ -0,0 +1,69 @@
+mod display;
+
+use sha2::{Digest, Sha256};
+
+#[cfg(feature="serde_support")]
+use serde::{Deserialize, Serialize};
+
+/// # Summary
+///
+/// A file, such as a receipt, which is kept alongside an [`Expense`](crate::Expense) as proof of
+/// its [cost](crate::Expense::cost).
+///
+/// # Remarks
+///
+/// The contents of an [`Attachment`] are kept in the store of the [`Job`](crate::Job), which
+/// identifies them by their `hash`. Because of this, attaching the same file more than once only
+/// keeps one copy of it.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature="serde_support", derive(Deserialize, Serialize))]
+pub struct Attachment
+{
+	/// # Summary
+	///
+	/// The SHA-256 hash of the contents of this [`Attachment`], in hexadecimal.
+	pub hash: String,
+
+	/// # Summary
+	///
+	/// The name of the file which was attached (e.g. `receipt.pdf`).
+	pub name: String,
+}
+
+impl Attachment
+{
+	/// # Summary
+	///
+	/// Create a new [`Attachment`] for a file with some `name` and `contents`.
+	pub fn new(name: String, contents: &[u8]) -> Self
+	{
+		let hash = Sha256::digest(contents).iter().map(|byte| format!("{:02x}", byte)).collect();
+		Self {hash, name}
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use
+	{
+		std::time::Instant,
+
+		super::Attachment,
+	};
+
+	#[test]
+	fn new()
+	{
+		let start = Instant::now();
+		let receipt = Attachment::new("receipt.txt".into(), b"Paid in full");
+		let copy = Attachment::new("copy.txt".into(), b"Paid in full");
+		let other = Attachment::new("receipt.txt".into(), b"Paid in part");
+		println!("\n>>>>> Attachment::new {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / 3);
+
+		assert_eq!(receipt.hash.len(), 64);
+		assert_eq!(receipt.hash, copy.hash);
+		assert_ne!(receipt.hash, other.hash);
+		assert_eq!(Attachment::new("empty".into(), &[]).hash, "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
+	}
+}
@@ -0,0 +1,14 @@
+use
+{
+	core::fmt::{Display, Formatter, Result},
+
+	super::Attachment,
+};
+
+impl Display for Attachment
+{
+	fn fmt(&self, formatter: &mut Formatter) -> Result
+	{
+		write!(formatter, "{}", self.name)
+	}
+}
@@ -0,0 +1,80 @@
+use crate::{BillingContact, Id};
+
+#[cfg(feature="serde_support")]
+use serde::{Deserialize, Serialize};
+
+/// # Summary
+///
+/// Who an [`Invoice`](crate::Invoice) is addressed to at a client, and where it is sent, when that
+/// is not the client [`Organization`](crate::Organization) itself.
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature="serde_support", derive(Deserialize, Serialize))]
+pub struct BillTo
+{
+	/// # Summary
+	///
+	/// The person at the client which [`Invoice`](crate::Invoice)s are addressed to (e.g. their
+	/// accounts payable department).
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub contact: Option<BillingContact>,
+
+	/// # Summary
+	///
+	/// The reference number of the [`Location`](crate::Location) which [`Invoice`](crate::Invoice)s
+	/// are sent to, instead of that of the client.
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub location_id: Option<Id>,
+}
+
+impl BillTo
+{
+	/// # Summary
+	///
+	/// Whether or not neither a `contact` nor a `location_id` have been set.
+	pub fn is_empty(&self) -> bool
+	{
+		self.contact.is_none() && self.location_id.is_none()
+	}
+
+	/// # Summary
+	///
+	/// Fill in the parts of this [`BillTo`] which have not been set with those of some `fallback`
+	/// (e.g. a [`Job`](crate::Job) falling back to its client).
+	pub fn or(&self, fallback: &Self) -> Self
+	{
+		Self
+		{
+			contact: self.contact.clone().or_else(|| fallback.contact.clone()),
+			location_id: self.location_id.or(fallback.location_id),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use
+	{
+		std::time::Instant,
+
+		super::{BillingContact, BillTo, Id},
+	};
+
+	#[test]
+	fn or()
+	{
+		let client = BillTo
+		{
+			contact: Some(BillingContact::Other {email: Some("ap@big.old".into()), name: "Accounts Payable".into()}),
+			location_id: Some(Id::new_v4()),
+		};
+
+		let job = BillTo {location_id: Some(Id::new_v4()), ..Default::default()};
+
+		let start = Instant::now();
+		assert_eq!(BillTo::default().or(&client), client);
+		assert_eq!(job.or(&client), BillTo {contact: client.contact.clone(), location_id: job.location_id});
+		assert_eq!(client.or(&job), client);
+		println!("\n>>>>> BillTo::or {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / 3);
+	}
+}
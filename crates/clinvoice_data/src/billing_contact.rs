@@ -0,0 +1,35 @@
+use crate::Id;
+
+#[cfg(feature="serde_support")]
+use serde::{Deserialize, Serialize};
+
+/// # Summary
+///
+/// The person at a client which [`Invoice`](crate::Invoice)s are addressed to.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature="serde_support", derive(Deserialize, Serialize))]
+pub enum BillingContact
+{
+	/// # Summary
+	///
+	/// The reference number of an [`Employee`](crate::Employee) of the client, who should be a
+	/// [`Representative`](crate::EmployeeStatus::Representative).
+	Employee(Id),
+
+	/// # Summary
+	///
+	/// Someone who is not recorded as an [`Employee`](crate::Employee) of the client.
+	Other
+	{
+		/// # Summary
+		///
+		/// The email address which [`Invoice`](crate::Invoice)s are sent to.
+		#[cfg_attr(feature="serde_support", serde(default))]
+		email: Option<String>,
+
+		/// # Summary
+		///
+		/// The name of the person or department (e.g. "Accounts Payable").
+		name: String,
+	},
+}
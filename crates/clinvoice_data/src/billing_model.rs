@@ -0,0 +1,72 @@
+mod display;
+
+use
+{
+	crate::{Milestone, Retainer},
+
+	clinvoice_finance::{Currency, Money},
+};
+
+#[cfg(feature="serde_support")]
+use serde::{Deserialize, Serialize};
+
+/// # Summary
+///
+/// How the client is charged for the work on a [`Job`](crate::Job).
+///
+/// # Remarks
+///
+/// No matter the [`BillingModel`], the time spent on each [`Timesheet`](crate::Timesheet) is still
+/// recorded.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature="serde_support", derive(Deserialize, Serialize))]
+pub enum BillingModel
+{
+	/// # Summary
+	///
+	/// The client is charged some price for the whole [`Job`](crate::Job), no matter how long it
+	/// takes.
+	FixedPrice(Money),
+
+	/// # Summary
+	///
+	/// The client is charged some rate for every hour of work.
+	Hourly(Money),
+
+	/// # Summary
+	///
+	/// The client is charged for each of the `milestones` once it is complete.
+	Milestones
+	{
+		/// # Summary
+		///
+		/// The [`Currency`] which the [`Milestone::amount`]s are in.
+		currency: Currency,
+
+		/// # Summary
+		///
+		/// The parts of the [`Job`](crate::Job) which are charged for.
+		milestones: Vec<Milestone>,
+	},
+
+	/// # Summary
+	///
+	/// The client is charged a [`Retainer`] every period, which covers some hours of work.
+	Retainer(Retainer),
+}
+
+impl BillingModel
+{
+	/// # Summary
+	///
+	/// Get the [`Currency`] which the client is charged in.
+	pub fn currency(&self) -> Currency
+	{
+		match self
+		{
+			Self::FixedPrice(money) | Self::Hourly(money) => money.currency,
+			Self::Milestones {currency, ..} => *currency,
+			Self::Retainer(retainer) => retainer.amount.currency,
+		}
+	}
+}
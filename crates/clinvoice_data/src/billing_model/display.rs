@@ -0,0 +1,90 @@
+use
+{
+	core::fmt::{Display, Formatter, Result},
+
+	super::BillingModel,
+
+	chrono::{DateTime, Local},
+	clinvoice_finance::Money,
+};
+
+impl Display for BillingModel
+{
+	fn fmt(&self, formatter: &mut Formatter) -> Result
+	{
+		match self
+		{
+			Self::FixedPrice(price) => write!(formatter, "Fixed Price: {}", price),
+			Self::Hourly(rate) => write!(formatter, "Hourly Rate: {}", rate),
+			Self::Milestones {currency, milestones} =>
+			{
+				write!(formatter, "Milestones:")?;
+				milestones.iter().try_for_each(|m|
+				{
+					write!(formatter, "\n\t{}: {}, ", m.name, Money {amount: m.amount, currency: *currency})?;
+					match m.date_complete
+					{
+						Some(date) => write!(formatter, "completed on {}", DateTime::<Local>::from(date).naive_local()),
+						_ => write!(formatter, "not completed"),
+					}
+				})
+			},
+			Self::Retainer(retainer) => retainer.fmt(formatter),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use
+	{
+		std::time::Instant,
+
+		super::BillingModel,
+		crate::Milestone,
+
+		chrono::{DateTime, Local, Utc},
+		clinvoice_finance::{Currency, Decimal, Money},
+	};
+
+	#[test]
+	fn display()
+	{
+		let date_complete = Utc::now();
+		let milestones = BillingModel::Milestones
+		{
+			currency: Currency::USD,
+			milestones: vec![
+				Milestone
+				{
+					amount: Decimal::new(50_00, 2),
+					date_complete: Some(date_complete),
+					invoice_id: None,
+					name: "Design".into(),
+				},
+				Milestone
+				{
+					amount: Decimal::new(75_00, 2),
+					date_complete: None,
+					invoice_id: None,
+					name: "Build".into(),
+				},
+			],
+		};
+
+		let start = Instant::now();
+		assert_eq!(format!("{}", BillingModel::FixedPrice(Money::new(90_00, 2, Currency::USD))), "Fixed Price: 90.00 USD");
+		assert_eq!(format!("{}", BillingModel::Hourly(Money::new(20_00, 2, Currency::USD))), "Hourly Rate: 20.00 USD");
+		assert_eq!(
+			format!("{}", milestones),
+			format!(
+"Milestones:
+	Design: 50.00 USD, completed on {}
+	Build: 75.00 USD, not completed",
+				DateTime::<Local>::from(date_complete).naive_local(),
+			),
+		);
+		println!("\n>>>>> BillingModel::fmt {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / 3);
+	}
+}
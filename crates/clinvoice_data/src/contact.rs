@@ -26,6 +26,24 @@ pub enum Contact
 	/// * 'foo@bar.io'
 	Email {email: String, export: bool},
 
+	/// # Summary
+	///
+	/// A fax number.
+	///
+	/// # Example
+	///
+	/// * '+16035551234'
+	Fax {fax: String, export: bool},
+
+	/// # Summary
+	///
+	/// A handle on some messaging service.
+	///
+	/// # Example
+	///
+	/// * '@foo' on 'Telegram'
+	Messaging {handle: String, service: String, export: bool},
+
 	/// # Summary
 	///
 	/// A phone number.
@@ -35,4 +53,13 @@ pub enum Contact
 	/// * '1-603-555-1234'
 	/// * '603-555-1234'
 	Phone {phone: String, export: bool},
+
+	/// # Summary
+	///
+	/// A website.
+	///
+	/// # Example
+	///
+	/// * 'https://foo.io'
+	Website {url: String, export: bool},
 }
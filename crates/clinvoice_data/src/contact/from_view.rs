@@ -12,7 +12,10 @@ impl From<View> for Contact
 		{
 			View::Address {location, export} => Self::Address{location_id: location.id, export},
 			View::Email {email, export} => Self::Email {email, export},
+			View::Fax {fax, export} => Self::Fax {fax, export},
+			View::Messaging {handle, service, export} => Self::Messaging {handle, service, export},
 			View::Phone {phone, export} => Self::Phone {phone, export},
+			View::Website {url, export} => Self::Website {url, export},
 		}
 	}
 }
@@ -25,7 +28,10 @@ impl From<&View> for Contact
 		{
 			View::Address {location, export} => Self::Address {location_id: location.id, export: *export},
 			View::Email {email, export} => Self::Email {email: email.clone(), export: *export},
+			View::Fax {fax, export} => Self::Fax {fax: fax.clone(), export: *export},
+			View::Messaging {handle, service, export} => Self::Messaging {handle: handle.clone(), service: service.clone(), export: *export},
 			View::Phone {phone, export} => Self::Phone {phone: phone.clone(), export: *export},
+			View::Website {url, export} => Self::Website {url: url.clone(), export: *export},
 		}
 	}
 }
@@ -0,0 +1,143 @@
+mod display;
+
+use
+{
+	crate::{CreditNoteLine, Id, Taxes, TaxTotal, Totals},
+	clinvoice_finance::{Currency, Money},
+
+	chrono::{DateTime, Utc},
+};
+
+#[cfg(feature="serde_support")]
+use serde::{Deserialize, Serialize};
+
+/// # Summary
+///
+/// A `CreditNote` is a document which corrects an [`Invoice`](crate::Invoice) after it was
+/// issued, by crediting the client for some of what they were billed.
+///
+/// # Remarks
+///
+/// The [`Invoice`](crate::Invoice) which a `CreditNote` corrects is the one which it belongs to.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature="serde_support", derive(Deserialize, Serialize))]
+pub struct CreditNote
+{
+	/// # Summary
+	///
+	/// The date upon which the [`CreditNote`] was issued.
+	pub date: DateTime<Utc>,
+
+	/// # Summary
+	///
+	/// The __unique__ identifier of the [`CreditNote`] within its [`Job`](crate::Job).
+	pub id: Id,
+
+	/// # Summary
+	///
+	/// What the client is credited for.
+	pub lines: Vec<CreditNoteLine>,
+
+	/// # Summary
+	///
+	/// The human-friendly number of this [`CreditNote`].
+	///
+	/// # Configuration
+	///
+	/// The format of this number can be configured in the `[invoices]` section of the
+	/// configuration file.
+	pub number: String,
+
+	/// # Summary
+	///
+	/// Why the [`CreditNote`] was issued, e.g. "Billed twice for the same meeting".
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub reason: String,
+}
+
+impl CreditNote
+{
+	/// # Summary
+	///
+	/// Get the amount of [`Money`] which the client is credited, in some `currency`, before and
+	/// after some `taxes` are applied.
+	///
+	/// # Remarks
+	///
+	/// Each [`CreditNoteLine`] is taxed like the work or [`Expense`](crate::Expense)s it credits
+	/// would be on an [`Invoice`](crate::Invoice): at the [`Taxes::rates`], or else at the
+	/// [`Tax`](crate::Tax)es of its [`CreditNoteLine::category`] (see [`Taxes::for_expense`]).
+	pub fn totals(&self, currency: Currency, taxes: &Taxes) -> Totals
+	{
+		let mut net = Money::new(0, 2, currency);
+		let mut tax_totals = Vec::new();
+		self.lines.iter().for_each(|line|
+		{
+			net.amount += line.amount;
+			TaxTotal::charge(
+				&mut tax_totals,
+				Money {amount: line.amount, currency},
+				line.category.as_ref().map(|c| taxes.for_expense(c)).unwrap_or(&taxes.rates),
+			);
+		});
+		net.amount.rescale(2);
+
+		let mut gross = net;
+		tax_totals.iter_mut().for_each(|t|
+		{
+			t.amount.amount = t.tax.on(t.base.amount);
+			t.amount.amount.rescale(2);
+			t.base.amount.rescale(2);
+			gross.amount += t.amount.amount;
+		});
+
+		Totals {adjustments: Vec::new(), gross, net, taxes: tax_totals}
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use
+	{
+		std::time::Instant,
+
+		super::{CreditNote, CreditNoteLine, Currency, Id, Money, Taxes, TaxTotal},
+		crate::Tax,
+		clinvoice_finance::Decimal,
+
+		chrono::Utc,
+	};
+
+	#[test]
+	fn totals()
+	{
+		let credit_note = CreditNote
+		{
+			date: Utc::now(),
+			id: Id::new_v4(),
+			lines: vec![
+				CreditNoteLine {amount: Decimal::new(-20_00, 2), category: None, description: "Billed twice for one hour".into()},
+				CreditNoteLine {amount: Decimal::new(-5_00, 2), category: None, description: "Parking".into()},
+			],
+			number: "CN-2021-0001".into(),
+			reason: "".into(),
+		};
+
+		let vat = Tax::Rate {name: "VAT".into(), percent: Decimal::new(19, 0)};
+		let taxes = Taxes {rates: vec![vat.clone()], ..Default::default()};
+
+		let start = Instant::now();
+		let totals = credit_note.totals(Currency::EUR, &taxes);
+		println!("\n>>>>> CreditNote::totals {}us <<<<<\n", Instant::now().duration_since(start).as_micros());
+
+		assert_eq!(totals.net, Money::new(-25_00, 2, Currency::EUR));
+		assert_eq!(totals.taxes, vec![TaxTotal
+		{
+			amount: Money::new(-4_75, 2, Currency::EUR),
+			base: Money::new(-25_00, 2, Currency::EUR),
+			tax: vat,
+		}]);
+		assert_eq!(totals.gross, Money::new(-29_75, 2, Currency::EUR));
+	}
+}
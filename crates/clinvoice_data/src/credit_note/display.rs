@@ -0,0 +1,60 @@
+use
+{
+	core::fmt::{Display, Formatter, Result},
+
+	super::CreditNote,
+
+	chrono::{DateTime, Local},
+};
+
+impl Display for CreditNote
+{
+	fn fmt(&self, formatter: &mut Formatter) -> Result
+	{
+		write!(formatter, "{} on {}", self.number, DateTime::<Local>::from(self.date).naive_local())?;
+
+		if !self.reason.is_empty()
+		{
+			write!(formatter, ": {}", self.reason)?;
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use
+	{
+		std::time::Instant,
+
+		super::{CreditNote, DateTime, Local},
+		crate::Id,
+
+		chrono::Utc,
+	};
+
+	#[test]
+	fn display()
+	{
+		let mut credit_note = CreditNote
+		{
+			date: Utc::now(),
+			id: Id::new_v4(),
+			lines: Vec::new(),
+			number: "CN-2021-0001".into(),
+			reason: "".into(),
+		};
+
+		let start = Instant::now();
+		assert_eq!(format!("{}", credit_note), format!("CN-2021-0001 on {}", DateTime::<Local>::from(credit_note.date).naive_local()));
+
+		credit_note.reason = "Billed twice for the same meeting".into();
+		assert_eq!(
+			format!("{}", credit_note),
+			format!("CN-2021-0001 on {}: Billed twice for the same meeting", DateTime::<Local>::from(credit_note.date).naive_local()),
+		);
+		println!("\n>>>>> CreditNote::fmt {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / 2);
+	}
+}
@@ -0,0 +1,39 @@
+use
+{
+	crate::ExpenseCategory,
+	clinvoice_finance::Decimal,
+};
+
+#[cfg(feature="serde_support")]
+use serde::{Deserialize, Serialize};
+
+/// # Summary
+///
+/// Something which a client is credited for on a [`CreditNote`](crate::CreditNote).
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature="serde_support", derive(Deserialize, Serialize))]
+pub struct CreditNoteLine
+{
+	/// # Summary
+	///
+	/// The amount which is credited before tax, in the currency of the
+	/// [`Job::billing_model`](crate::Job::billing_model). This is negative.
+	pub amount: Decimal,
+
+	/// # Summary
+	///
+	/// The [`ExpenseCategory`] of the [`Expense`](crate::Expense)s which are being credited, or
+	/// [`None`] if it is work which is being credited.
+	///
+	/// # Remarks
+	///
+	/// This determines which [`Tax`](crate::Tax)es are credited (see
+	/// [`Taxes::for_expense`](crate::Taxes::for_expense)).
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub category: Option<ExpenseCategory>,
+
+	/// # Summary
+	///
+	/// What is being credited, e.g. "Billed twice for one hour".
+	pub description: String,
+}
@@ -0,0 +1,24 @@
+mod display;
+
+#[cfg(feature="serde_support")]
+use serde::{Deserialize, Serialize};
+
+/// # Summary
+///
+/// A unit which the distance of a [mileage](crate::ExpenseKind::Mileage)
+/// [`Expense`](crate::Expense) is measured in.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature="serde_support", derive(Deserialize, Serialize))]
+pub enum DistanceUnit
+{
+	/// # Summary
+	///
+	/// Kilometers.
+	#[default]
+	Kilometer,
+
+	/// # Summary
+	///
+	/// Miles.
+	Mile,
+}
@@ -0,0 +1,18 @@
+use
+{
+	core::fmt::{Display, Formatter, Result},
+
+	super::DistanceUnit,
+};
+
+impl Display for DistanceUnit
+{
+	fn fmt(&self, formatter: &mut Formatter) -> Result
+	{
+		match self
+		{
+			Self::Kilometer => write!(formatter, "km"),
+			Self::Mile => write!(formatter, "mi"),
+		}
+	}
+}
@@ -6,7 +6,9 @@ use
 {
 	std::collections::HashMap,
 
-	crate::{Contact, EmployeeStatus, Id},
+	crate::{Contact, EmployeeStatus, EmploymentChange, Id},
+	chrono::{DateTime, Utc},
+	clinvoice_finance::Money,
 };
 
 #[cfg(feature="serde_support")]
@@ -29,6 +31,29 @@ pub struct Employee
 	/// Keys in the [map](HashMap) are labels of the contact is (e.g. "Primary Phone").
 	pub contact_info: HashMap<String, Contact>,
 
+	/// # Summary
+	///
+	/// Changes to the [`Employee`]'s status and title over time, such as promotions or leaving the
+	/// company.
+	///
+	/// # Remarks
+	///
+	/// Before the earliest of these changes, the [`Employee`] has its `status` and `title`.
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub employment_changes: Vec<EmploymentChange>,
+
+	/// # Summary
+	///
+	/// The rate which the [`Employee`] bills per hour by default.
+	///
+	/// # Remarks
+	///
+	/// When the [`Employee`] starts working on a [`Job`](crate::Job), this rate is added to its
+	/// [`Job::employee_default_rates`](crate::Job::employee_default_rates). If this is left blank,
+	/// the rate of the [`BillingModel::Hourly`](crate::BillingModel::Hourly) is billed instead.
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub hourly_rate: Option<Money>,
+
 	/// # Summary
 	///
 	/// The reference number of this [`Employee`], which can be used instead of the compound key
@@ -49,16 +74,18 @@ pub struct Employee
 
 	/// # Summary
 	///
-	/// The status of the employee.
+	/// The status of the employee before any of its `employment_changes`.
 	///
 	/// # Remarks
 	///
-	/// Flagging this field as [`NotEmployed`](EmployeeStatus::NOT_EMPLOYED) is a viable alternative to deletion.
+	/// Recording an [`EmploymentChange`] to [`NotEmployed`](EmployeeStatus::NotEmployed) is a viable
+	/// alternative to deletion. See [`status_on`](Self::status_on).
 	pub status: EmployeeStatus,
 
 	/// # Summary
 	///
-	/// The [`Employee`]'s title  in the company.
+	/// The [`Employee`]'s title in the company before any of its `employment_changes`. See
+	/// [`title_on`](Self::title_on).
 	///
 	/// # Example
 	///
@@ -66,3 +93,22 @@ pub struct Employee
 	/// * Media Manager
 	pub title: String,
 }
+
+impl Employee
+{
+	/// # Summary
+	///
+	/// Get the status of this employee on some `date`.
+	pub fn status_on(&self, date: DateTime<Utc>) -> EmployeeStatus
+	{
+		EmploymentChange::effective_on(&self.employment_changes, date).map_or(self.status, |c| c.status)
+	}
+
+	/// # Summary
+	///
+	/// Get the title of this employee on some `date`.
+	pub fn title_on(&self, date: DateTime<Utc>) -> &str
+	{
+		EmploymentChange::effective_on(&self.employment_changes, date).map_or(&self.title, |c| &c.title)
+	}
+}
@@ -11,6 +11,8 @@ impl From<View> for Employee
 		Self
 		{
 			contact_info: view.contact_info.into_iter().map(|(label, contact)| (label, contact.into())).collect(),
+			employment_changes: view.employment_changes,
+			hourly_rate: view.hourly_rate,
 			id: view.id,
 			organization_id: view.organization.id,
 			person_id: view.person.id,
@@ -27,6 +29,8 @@ impl From<&View> for Employee
 		Self
 		{
 			contact_info: view.contact_info.clone().into_iter().map(|(label, contact)| (label, contact.into())).collect(),
+			employment_changes: view.employment_changes.clone(),
+			hourly_rate: view.hourly_rate,
 			id: view.id,
 			organization_id: view.organization.id,
 			person_id: view.person.id,
@@ -0,0 +1,84 @@
+mod display;
+
+use
+{
+	crate::EmployeeStatus,
+
+	chrono::{DateTime, Utc},
+};
+
+#[cfg(feature="serde_support")]
+use serde::{Deserialize, Serialize};
+
+/// # Summary
+///
+/// An `EmploymentChange` is a new status and title which an [`Employee`](crate::Employee) has
+/// from some date onwards (e.g. a promotion, or leaving the [`Organization`](crate::Organization)).
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature="serde_support", derive(Deserialize, Serialize))]
+pub struct EmploymentChange
+{
+	/// # Summary
+	///
+	/// The date from which the [`EmploymentChange::status`] and [`EmploymentChange::title`] apply.
+	pub effective_from: DateTime<Utc>,
+
+	/// # Summary
+	///
+	/// The status of the [`Employee`](crate::Employee).
+	pub status: EmployeeStatus,
+
+	/// # Summary
+	///
+	/// The title of the [`Employee`](crate::Employee) in the company.
+	pub title: String,
+}
+
+impl EmploymentChange
+{
+	/// # Summary
+	///
+	/// Get the latest of some `changes` which is in effect on some `date`, if any.
+	pub fn effective_on(changes: &[Self], date: DateTime<Utc>) -> Option<&Self>
+	{
+		changes.iter().filter(|c| c.effective_from <= date).max_by_key(|c| c.effective_from)
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use
+	{
+		std::time::Instant,
+
+		super::{EmployeeStatus, EmploymentChange},
+
+		chrono::{TimeZone, Utc},
+	};
+
+	#[test]
+	fn effective_on()
+	{
+		let changes = vec![
+			EmploymentChange
+			{
+				effective_from: Utc.ymd(2021, 6, 1).and_hms(0, 0, 0),
+				status: EmployeeStatus::NotEmployed,
+				title: "Janitor".into(),
+			},
+			EmploymentChange
+			{
+				effective_from: Utc.ymd(2021, 1, 1).and_hms(0, 0, 0),
+				status: EmployeeStatus::Employed,
+				title: "Janitor".into(),
+			},
+		];
+
+		let start = Instant::now();
+		assert_eq!(EmploymentChange::effective_on(&changes, Utc.ymd(2020, 12, 31).and_hms(0, 0, 0)), None);
+		assert_eq!(EmploymentChange::effective_on(&changes, Utc.ymd(2021, 3, 1).and_hms(0, 0, 0)), Some(&changes[1]));
+		assert_eq!(EmploymentChange::effective_on(&changes, Utc.ymd(2021, 6, 1).and_hms(0, 0, 0)), Some(&changes[0]));
+		println!("\n>>>>> EmploymentChange::effective_on {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / 3);
+	}
+}
@@ -0,0 +1,48 @@
+use
+{
+	core::fmt::{Display, Formatter, Result},
+
+	super::EmploymentChange,
+
+	chrono::{DateTime, Local},
+};
+
+impl Display for EmploymentChange
+{
+	fn fmt(&self, formatter: &mut Formatter) -> Result
+	{
+		write!(formatter, "{} as {} from {}", self.status, self.title, DateTime::<Local>::from(self.effective_from).naive_local())
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use
+	{
+		std::time::Instant,
+
+		super::{DateTime, EmploymentChange, Local},
+		crate::EmployeeStatus,
+
+		chrono::Utc,
+	};
+
+	#[test]
+	fn display()
+	{
+		let change = EmploymentChange
+		{
+			effective_from: Utc::now(),
+			status: EmployeeStatus::NotEmployed,
+			title: "Janitor".into(),
+		};
+
+		let start = Instant::now();
+		assert_eq!(
+			format!("{}", change),
+			format!("Not employed as Janitor from {}", DateTime::<Local>::from(change.effective_from).naive_local()),
+		);
+		println!("\n>>>>> EmploymentChange::fmt {}us <<<<<\n", Instant::now().duration_since(start).as_micros());
+	}
+}
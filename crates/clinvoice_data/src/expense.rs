@@ -2,7 +2,7 @@ mod display;
 
 use
 {
-	crate::ExpenseCategory,
+	crate::{Attachment, ExpenseCategory, ExpenseKind},
 
 	clinvoice_finance::Money,
 };
@@ -18,6 +18,23 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature="serde_support", derive(Deserialize, Serialize))]
 pub struct Expense
 {
+	/// # Summary
+	///
+	/// Files which prove this [`Expense`] was incurred, such as receipts.
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub attachments: Vec<Attachment>,
+
+	/// # Summary
+	///
+	/// Whether the client is charged for this [`Expense`].
+	///
+	/// # Remarks
+	///
+	/// An [`Expense`] which is not billable (e.g. one which was reimbursed at cost) is still
+	/// recorded, but never counts towards the amount owed.
+	#[cfg_attr(feature="serde_support", serde(default="crate::default_true"))]
+	pub billable: bool,
+
 	/// # Summary
 	///
 	/// What kind of [`Expense`] this is.
@@ -26,10 +43,21 @@ pub struct Expense
 	/// # Summary
 	///
 	/// The amount of [`Money`] that this [`Expense`] cost.
+	///
+	/// # Remarks
+	///
+	/// When this [`Expense`] is not of [`ExpenseKind::Cost`], this should be the
+	/// [`ExpenseKind::cost`].
 	pub cost: Money,
 
 	/// # Summary
 	///
 	/// A description of what this [`Expense`] is.
 	pub description: String,
+
+	/// # Summary
+	///
+	/// How the [`Expense::cost`] was arrived at.
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub kind: ExpenseKind,
 }
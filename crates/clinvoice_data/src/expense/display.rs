@@ -3,14 +3,34 @@ use
 	core::fmt::{Display, Formatter, Result},
 
 	super::Expense,
+	crate::ExpenseKind,
 };
 
 impl Display for Expense
 {
 	fn fmt(&self, formatter: &mut Formatter) -> Result
 	{
-		writeln!(formatter, "{} – {}", self.category, self.cost)?;
-		write!(formatter, "\t{}", self.description.replace('\n', "\n\t"))
+		write!(formatter, "{} – {}", self.category, self.cost)?;
+		if self.kind != ExpenseKind::Cost
+		{
+			write!(formatter, " ({})", self.kind)?;
+		}
+
+		if !self.billable
+		{
+			write!(formatter, " (Non-billable)")?;
+		}
+
+		writeln!(formatter)?;
+		write!(formatter, "\t{}", self.description.replace('\n', "\n\t"))?;
+
+		if !self.attachments.is_empty()
+		{
+			let names: Vec<_> = self.attachments.iter().map(|a| a.name.as_str()).collect();
+			write!(formatter, "\n\tAttachments: {}", names.join(", "))?;
+		}
+
+		Ok(())
 	}
 }
 
@@ -22,19 +42,22 @@ mod tests
 		std::time::Instant,
 
 		super::Expense,
-		crate::ExpenseCategory,
+		crate::{Attachment, DistanceUnit, ExpenseCategory, ExpenseKind},
 
-		clinvoice_finance::{Currency, Money},
+		clinvoice_finance::{Currency, Decimal, Money},
 	};
 
 	#[test]
 	fn display()
 	{
-		let expense = Expense
+		let mut expense = Expense
 		{
-			category: ExpenseCategory::Food,
+			attachments: Vec::new(),
+			billable: true,
+			category: ExpenseCategory::new("Food"),
 			cost: Money::new(20_00, 2, Currency::USD),
 			description: "Take-out for 2".into(),
+			kind: Default::default(),
 		};
 
 		let start = Instant::now();
@@ -43,6 +66,24 @@ mod tests
 "Food – 20.00 USD
 	Take-out for 2",
 		);
-		println!("\n>>>>> Expense::fmt {}us <<<<<\n", Instant::now().duration_since(start).as_micros());
+
+		expense.category = ExpenseCategory::new("Travel");
+		expense.description = "Drive to the client's office".into();
+		expense.kind = ExpenseKind::Mileage {distance: Decimal::new(120, 0), rate: Money::new(70, 2, Currency::EUR), unit: DistanceUnit::Kilometer};
+		expense.cost = expense.kind.cost().unwrap();
+		assert_eq!(
+			format!("{}", expense),
+"Travel – 84.00 EUR (120 km at 0.70 EUR per km)
+	Drive to the client's office",
+		);
+
+		expense.attachments = vec![Attachment::new("log.pdf".into(), b"120 km"), Attachment::new("map.png".into(), b"")];
+		assert_eq!(
+			format!("{}", expense),
+"Travel – 84.00 EUR (120 km at 0.70 EUR per km)
+	Drive to the client's office
+	Attachments: log.pdf, map.png",
+		);
+		println!("\n>>>>> Expense::fmt {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / 3);
 	}
 }
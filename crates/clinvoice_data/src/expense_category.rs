@@ -1,42 +1,76 @@
 mod display;
+#[cfg(feature="serde_support")]
+mod deserialize;
+#[cfg(feature="serde_support")]
+mod serialize;
+
+use
+{
+	crate::Tax,
+	clinvoice_finance::Decimal,
+};
 
 #[cfg(feature="serde_support")]
 use serde::{Deserialize, Serialize};
 
 /// # Summary
 ///
-/// Common categories of [`Expense`](crate::Expense)s.
-#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
-#[cfg_attr(feature="serde_support", derive(Deserialize, Serialize))]
-pub enum ExpenseCategory
+/// A category of [`Expense`](crate::Expense)s, such as "Lodging" or "Equipment Rental".
+///
+/// # Remarks
+///
+/// * Categories are defined by the user, and each [`Expense`](crate::Expense) keeps a copy of the
+///   category it was recorded under.
+/// * When deserializing, a plain name (e.g. `Food`) is accepted as a category whose `code` is the
+///   same as its `name`. This is how categories were stored before they could be defined.
+/// * Formats which are not self-describing (e.g. `bincode`) cannot be read this way, so stores
+///   which use them must be upgraded by their adapter instead.
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature="serde_support", derive(Deserialize, Serialize), serde(remote="Self"))]
+pub struct ExpenseCategory
 {
 	/// # Summary
 	///
-	/// A food item, such as a hamburger or rice. Also covers drinks.
-	Food,
+	/// The code of this category, e.g. the account it is booked to. This is what
+	/// [`Taxes::expenses`](crate::Taxes::expenses) and
+	/// [`ExpenseMarkup::categories`](crate::ExpenseMarkup::categories) refer to it by.
+	pub code: String,
 
 	/// # Summary
 	///
-	/// A physical good, such as a pen or a bundle of paper.
-	Item,
+	/// The percentage which [`Expense`](crate::Expense)s in this category are marked up by, when
+	/// the [`ExpenseMarkup`](crate::ExpenseMarkup) of a [`Job`](crate::Job) does not specify one for
+	/// the [`ExpenseCategory::code`].
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub markup: Option<Decimal>,
 
 	/// # Summary
 	///
-	/// Anything not covered by another category.
-	Other,
+	/// The name of this category which is shown to people, e.g. "Lodging".
+	pub name: String,
 
 	/// # Summary
 	///
-	/// A payment for a service, such as internet hosting or cleaning.
-	Service,
-
-	/// # Summary
-	///
-	/// A digital good, such as Microsoft Office.
-	Software,
+	/// The [`Tax`]es on [`Expense`](crate::Expense)s in this category, when the
+	/// [`Taxes`](crate::Taxes) of an [`Invoice`](crate::Invoice) do not specify any for the
+	/// [`ExpenseCategory::code`].
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub taxes: Option<Vec<Tax>>,
+}
 
+impl ExpenseCategory
+{
 	/// # Summary
 	///
-	/// Cost of travel, such as gas or car maintenance.
-	Travel,
+	/// Create an [`ExpenseCategory`] which has the same `code` and `name`, and no defaults.
+	pub fn new(name: &str) -> Self
+	{
+		Self
+		{
+			code: name.into(),
+			markup: None,
+			name: name.into(),
+			taxes: None,
+		}
+	}
 }
@@ -0,0 +1,68 @@
+use
+{
+	super::ExpenseCategory,
+
+	serde::{Deserialize, Deserializer},
+};
+
+impl<'de> Deserialize<'de> for ExpenseCategory
+{
+	fn deserialize<D>(deserializer: D) -> Result<Self, D::Error> where
+		D : Deserializer<'de>,
+	{
+		/// # Summary
+		///
+		/// The ways an [`ExpenseCategory`] may be written down by a self-describing format.
+		#[derive(Deserialize)]
+		#[serde(untagged)]
+		enum Repr
+		{
+			Category(#[serde(with="ExpenseCategory")] ExpenseCategory),
+			Name(String),
+		}
+
+		if !deserializer.is_human_readable()
+		{
+			return ExpenseCategory::deserialize(deserializer);
+		}
+
+		Ok(match Repr::deserialize(deserializer)?
+		{
+			Repr::Category(category) => category,
+			Repr::Name(name) => ExpenseCategory::new(&name),
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use
+	{
+		std::time::Instant,
+
+		super::ExpenseCategory,
+
+		clinvoice_finance::Decimal,
+	};
+
+	#[test]
+	fn deserialize()
+	{
+		let lodging = ExpenseCategory
+		{
+			code: "6100".into(),
+			markup: Some(Decimal::new(10, 0)),
+			name: "Lodging".into(),
+			taxes: None,
+		};
+
+		let serialized = bincode::serialize(&lodging).unwrap();
+
+		let start = Instant::now();
+		let deserialized = bincode::deserialize::<ExpenseCategory>(&serialized).unwrap();
+		println!("\n>>>>> ExpenseCategory::deserialize {}us <<<<<\n", Instant::now().duration_since(start).as_micros());
+
+		assert_eq!(deserialized, lodging);
+	}
+}
@@ -1,4 +1,3 @@
-
 use
 {
 	core::fmt::{Display, Formatter, Result},
@@ -10,15 +9,13 @@ impl Display for ExpenseCategory
 {
 	fn fmt(&self, formatter: &mut Formatter) -> Result
 	{
-		write!(formatter, "{}", match self
+		write!(formatter, "{}", self.name)?;
+		if self.code != self.name
 		{
-			ExpenseCategory::Food => "Food",
-			ExpenseCategory::Item => "Item",
-			ExpenseCategory::Other => "Other",
-			ExpenseCategory::Service => "Service",
-			ExpenseCategory::Software => "Software",
-			ExpenseCategory::Travel => "Travel",
-		})
+			write!(formatter, " ({})", self.code)?;
+		}
+
+		Ok(())
 	}
 }
 
@@ -35,13 +32,17 @@ mod tests
 	#[test]
 	fn display()
 	{
+		let lodging = ExpenseCategory
+		{
+			code: "6100".into(),
+			markup: None,
+			name: "Lodging".into(),
+			taxes: None,
+		};
+
 		let start = Instant::now();
-		assert_eq!(format!("{}", ExpenseCategory::Food), "Food");
-		assert_eq!(format!("{}", ExpenseCategory::Item), "Item");
-		assert_eq!(format!("{}", ExpenseCategory::Other), "Other");
-		assert_eq!(format!("{}", ExpenseCategory::Service), "Service");
-		assert_eq!(format!("{}", ExpenseCategory::Software), "Software");
-		assert_eq!(format!("{}", ExpenseCategory::Travel), "Travel");
-		println!("\n>>>>> ExpenseCategory::fmt {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / 6);
+		assert_eq!(format!("{}", ExpenseCategory::new("Food")), "Food");
+		assert_eq!(format!("{}", lodging), "Lodging (6100)");
+		println!("\n>>>>> ExpenseCategory::fmt {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / 2);
 	}
 }
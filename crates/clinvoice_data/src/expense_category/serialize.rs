@@ -0,0 +1,15 @@
+use
+{
+	super::ExpenseCategory,
+
+	serde::{Serialize, Serializer},
+};
+
+impl Serialize for ExpenseCategory
+{
+	fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error> where
+		S : Serializer,
+	{
+		ExpenseCategory::serialize(self, serializer)
+	}
+}
@@ -0,0 +1,49 @@
+use
+{
+	core::fmt::{Display, Formatter, Result},
+
+	super::ExpenseKind,
+};
+
+impl Display for ExpenseKind
+{
+	fn fmt(&self, formatter: &mut Formatter) -> Result
+	{
+		match self
+		{
+			Self::Cost => write!(formatter, "Cost"),
+			Self::Mileage {distance, rate, unit} => write!(formatter, "{} {} at {} per {}", distance, unit, rate, unit),
+			Self::PerDiem {days, rate} => write!(formatter, "{} days at {} per day", days, rate),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use
+	{
+		std::time::Instant,
+
+		super::ExpenseKind,
+		crate::DistanceUnit,
+
+		clinvoice_finance::{Currency, Decimal, Money},
+	};
+
+	#[test]
+	fn display()
+	{
+		let start = Instant::now();
+		assert_eq!(format!("{}", ExpenseKind::Cost), "Cost");
+		assert_eq!(
+			format!("{}", ExpenseKind::Mileage {distance: Decimal::new(120, 0), rate: Money::new(70, 2, Currency::EUR), unit: DistanceUnit::Kilometer}),
+			"120 km at 0.70 EUR per km",
+		);
+		assert_eq!(
+			format!("{}", ExpenseKind::PerDiem {days: Decimal::new(3, 0), rate: Money::new(59_00, 2, Currency::USD)}),
+			"3 days at 59.00 USD per day",
+		);
+		println!("\n>>>>> ExpenseKind::fmt {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / 3);
+	}
+}
@@ -0,0 +1,111 @@
+mod display;
+
+use
+{
+	std::collections::BTreeMap,
+
+	crate::{Expense, ExpenseCategory},
+	clinvoice_finance::{Decimal, Money},
+};
+
+#[cfg(feature="serde_support")]
+use serde::{Deserialize, Serialize};
+
+/// # Summary
+///
+/// The percentages which [`Expense`]s are marked up by when they are billed, e.g. to cover the
+/// cost of handling materials.
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature="serde_support", derive(Deserialize, Serialize))]
+pub struct ExpenseMarkup
+{
+	/// # Summary
+	///
+	/// The percentage which [`Expense`]s of some [`ExpenseCategory`] (by its
+	/// [code](ExpenseCategory::code)) are marked up by, instead of the [`ExpenseMarkup::percent`].
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub categories: BTreeMap<String, Decimal>,
+
+	/// # Summary
+	///
+	/// The percentage which every [`Expense`] not in the [`ExpenseMarkup::categories`], and whose
+	/// [`ExpenseCategory`] has no markup of its own, is marked up by.
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub percent: Decimal,
+}
+
+impl ExpenseMarkup
+{
+	/// # Summary
+	///
+	/// Get the cost of some `expense` once it has been marked up.
+	pub fn apply(&self, expense: &Expense) -> Money
+	{
+		let percent = self.for_category(&expense.category);
+		let mut amount = expense.cost.amount + expense.cost.amount * percent / Decimal::new(100, 0);
+		amount.rescale(2);
+		Money {amount, currency: expense.cost.currency}
+	}
+
+	/// # Summary
+	///
+	/// Get the percentage which [`Expense`]s of some `category` are marked up by.
+	///
+	/// # Returns
+	///
+	/// * The [`ExpenseMarkup::categories`] of the `category`, if there is one.
+	/// * Otherwise, the [`ExpenseCategory::markup`], if there is one.
+	/// * Otherwise, the [`ExpenseMarkup::percent`].
+	pub fn for_category(&self, category: &ExpenseCategory) -> Decimal
+	{
+		self.categories.get(&category.code).copied().or(category.markup).unwrap_or(self.percent)
+	}
+
+	/// # Summary
+	///
+	/// Whether no [`Expense`] is marked up at all.
+	pub fn is_empty(&self) -> bool
+	{
+		self.percent.is_zero() && self.categories.values().all(Decimal::is_zero)
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use
+	{
+		std::time::Instant,
+
+		super::{Decimal, Expense, ExpenseCategory, ExpenseMarkup},
+		clinvoice_finance::{Currency, Money},
+	};
+
+	#[test]
+	fn apply()
+	{
+		let markup = ExpenseMarkup
+		{
+			categories: vec![("Travel".into(), Decimal::default())].into_iter().collect(),
+			percent: Decimal::new(10, 0),
+		};
+
+		let mut expense = Expense
+		{
+			attachments: Vec::new(),
+			billable: true,
+			category: ExpenseCategory::new("Item"),
+			cost: Money::new(20_05, 2, Currency::USD),
+			description: "Paper".into(),
+			kind: Default::default(),
+		};
+
+		let start = Instant::now();
+		assert_eq!(markup.apply(&expense), Money::new(22_06, 2, Currency::USD));
+		expense.category = ExpenseCategory::new("Travel");
+		assert_eq!(markup.apply(&expense), expense.cost);
+		expense.category = ExpenseCategory {markup: Some(Decimal::new(15, 0)), ..ExpenseCategory::new("Lodging")};
+		assert_eq!(markup.apply(&expense), Money::new(23_06, 2, Currency::USD));
+		println!("\n>>>>> ExpenseMarkup::apply {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / 3);
+	}
+}
@@ -0,0 +1,44 @@
+use
+{
+	core::fmt::{Display, Formatter, Result},
+
+	super::ExpenseMarkup,
+};
+
+impl Display for ExpenseMarkup
+{
+	fn fmt(&self, formatter: &mut Formatter) -> Result
+	{
+		write!(formatter, "{}%", self.percent)?;
+		self.categories.iter().try_for_each(|(category, percent)| write!(formatter, ", {}% on {}", percent, category))
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use
+	{
+		std::time::Instant,
+
+		super::ExpenseMarkup,
+		clinvoice_finance::Decimal,
+	};
+
+	#[test]
+	fn display()
+	{
+		let markup = ExpenseMarkup
+		{
+			categories: vec![
+				("Food".into(), Decimal::new(5, 0)),
+				("Travel".into(), Decimal::default()),
+			].into_iter().collect(),
+			percent: Decimal::new(10, 0),
+		};
+
+		let start = Instant::now();
+		assert_eq!(format!("{}", markup), "10%, 5% on Food, 0% on Travel");
+		println!("\n>>>>> ExpenseMarkup::fmt {}us <<<<<\n", Instant::now().duration_since(start).as_micros());
+	}
+}
@@ -2,9 +2,9 @@ mod display;
 
 use
 {
-	crate::InvoiceDate,
+	crate::{CreditNote, Id, InvoiceDate, Payment, PaymentTerms, Taxes},
 
-	clinvoice_finance::Money,
+	chrono::{DateTime, Utc},
 };
 
 #[cfg(feature="serde_support")]
@@ -13,10 +13,22 @@ use serde::{Deserialize, Serialize};
 /// # Summary
 ///
 /// An `Invoice` represents the accounts receivable for the user or their employer.
+///
+/// # Remarks
+///
+/// A [`Job`](crate::Job) may be billed over several `Invoice`s (e.g. once per month). The work
+/// which is billed on an `Invoice` refers to it by its [`Invoice::id`].
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
 #[cfg_attr(feature="serde_support", derive(Deserialize, Serialize))]
 pub struct Invoice
 {
+	/// # Summary
+	///
+	/// The [`CreditNote`]s which have corrected this [`Invoice`] since it was issued, in the order
+	/// they were issued.
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub credit_notes: Vec<CreditNote>,
+
 	/// # Summary
 	///
 	/// The date upon which the [`Invoice`] was sent to and paid by the client.
@@ -24,20 +36,62 @@ pub struct Invoice
 
 	/// # Summary
 	///
-	/// The amount of money to be charged for one hour of work.
+	/// The __unique__ identifier of the [`Invoice`] within its [`Job`](crate::Job).
+	pub id: Id,
+
+	/// # Summary
+	///
+	/// The human-friendly number of this [`Invoice`], which is assigned when it is issued.
 	///
 	/// # Configuration
 	///
-	/// The currency used for this rate can be configured by running:
+	/// The format of this number can be configured in the `[invoices]` section of the
+	/// configuration file.
+	pub number: Option<String>,
+
+	/// # Summary
+	///
+	/// The [`PaymentTerms`] which were given to the client when the [`Invoice`] was issued.
+	///
+	/// # Remarks
+	///
+	/// If this is left blank, the [`Invoice`] is due on receipt.
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub payment_terms: Option<PaymentTerms>,
+
+	/// # Summary
+	///
+	/// The [`Payment`]s which the client has made towards this [`Invoice`].
+	///
+	/// # Remarks
+	///
+	/// The balance which is still due can be found with
+	/// [`Job::invoice_balance`](crate::Job::invoice_balance).
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub payments: Vec<Payment>,
+
+	/// # Summary
+	///
+	/// The [`Taxes`] which were applied to the [`Invoice`] when it was issued.
+	///
+	/// # Remarks
+	///
+	/// If this is left blank, the [`Taxes`] of the client are used.
+	pub taxes: Option<Taxes>,
+}
+
+impl Invoice
+{
+	/// # Summary
 	///
-	/// ```sh
-	/// `clinvoice config -c '<char>'`.
-	/// ```
+	/// Get the date upon which the [`Invoice`] is due, according to its [`Invoice::payment_terms`].
 	///
-	/// ## Example
+	/// # Returns
 	///
-	/// ```sh
-	/// clinvoice config -c '\$'
-	/// ```
-	pub hourly_rate: Money,
+	/// * The date which the [`Invoice`] is due, if it was issued.
+	/// * [`None`], otherwise.
+	pub fn date_due(&self) -> Option<DateTime<Utc>>
+	{
+		self.date.as_ref().map(|date| self.payment_terms.unwrap_or(PaymentTerms::DueOnReceipt).due(date.issued))
+	}
 }
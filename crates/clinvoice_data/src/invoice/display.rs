@@ -3,15 +3,44 @@ use
 	core::fmt::{Display, Formatter, Result},
 
 	super::Invoice,
+
+	chrono::{DateTime, Local},
 };
 
 impl Display for Invoice
 {
 	fn fmt(&self, formatter: &mut Formatter) -> Result
 	{
-		writeln!(formatter, "Hourly Rate: {}", self.hourly_rate)?;
+		if let Some(number) = &self.number
+		{
+			writeln!(formatter, "Number: {}", number)?;
+		}
+
+		write!(formatter, "Status: {}", self.date.as_ref().map(|date| date.to_string()).unwrap_or_else(|| "Not issued".into()))?;
+
+		if let Some(date) = self.date_due()
+		{
+			write!(formatter, "\nDue: {}", DateTime::<Local>::from(date))?;
+
+			if let Some(terms) = self.payment_terms
+			{
+				write!(formatter, " ({})", terms)?;
+			}
+		}
+
+		if !self.payments.is_empty()
+		{
+			write!(formatter, "\nPayments:")?;
+			self.payments.iter().try_for_each(|p| write!(formatter, "\n\t{}", p))?;
+		}
 
-		write!(formatter, "Status: {}", self.date.as_ref().map(|date| date.to_string()).unwrap_or_else(|| "Not issued".into()))
+		if !self.credit_notes.is_empty()
+		{
+			write!(formatter, "\nCredit Notes:")?;
+			self.credit_notes.iter().try_for_each(|c| write!(formatter, "\n\t{}", c))?;
+		}
+
+		Ok(())
 	}
 }
 
@@ -22,35 +51,80 @@ mod tests
 	{
 		std::time::Instant,
 
-		super::Invoice,
-		crate::InvoiceDate,
+		super::{DateTime, Invoice, Local},
+		crate::{CreditNote, Id, InvoiceDate, Payment, PaymentTerms},
+
 		clinvoice_finance::{Currency, Money},
 
-		chrono::{DateTime, Local, Utc},
+		chrono::Utc,
 	};
 
 	#[test]
 	fn display()
 	{
-		let invoice = Invoice
+		let mut invoice = Invoice
 		{
+			credit_notes: Vec::new(),
 			date: Some(InvoiceDate
 			{
 				issued: Utc::now(),
 				paid: None,
 			}),
-			hourly_rate: Money::new(10_00, 2, Currency::USD),
+			id: Id::new_v4(),
+			number: Some("INV-2021-0001".into()),
+			payment_terms: Some(PaymentTerms::Net(30)),
+			payments: Vec::new(),
+			taxes: None,
 		};
 
 		let start = Instant::now();
 		assert_eq!(
 			format!("{}", invoice),
 			format!(
-"Hourly Rate: 10.00 USD
-Status: Issued on {}; Outstanding",
-				DateTime::<Local>::from(invoice.date.unwrap().issued),
+"Number: INV-2021-0001
+Status: Issued on {}; Outstanding
+Due: {} (Net 30)",
+				DateTime::<Local>::from(invoice.date.as_ref().unwrap().issued),
+				DateTime::<Local>::from(invoice.date_due().unwrap()),
 			),
 		);
-		println!("\n>>>>> Invoice::fmt {}us <<<<<\n", Instant::now().duration_since(start).as_micros());
+		let middle = Instant::now().duration_since(start);
+
+		invoice.payments.push(Payment
+		{
+			amount: Money::new(20_00, 2, Currency::USD),
+			date: Utc::now(),
+			method: "Cash".into(),
+			reference: "".into(),
+		});
+
+		let second_start = Instant::now();
+		assert_eq!(
+			format!("{}", invoice),
+			format!(
+"Number: INV-2021-0001
+Status: Issued on {}; Outstanding
+Due: {} (Net 30)
+Payments:
+	{}",
+				DateTime::<Local>::from(invoice.date.as_ref().unwrap().issued),
+				DateTime::<Local>::from(invoice.date_due().unwrap()),
+				invoice.payments[0],
+			),
+		);
+		let middle = middle + Instant::now().duration_since(second_start);
+
+		invoice.credit_notes.push(CreditNote
+		{
+			date: Utc::now(),
+			id: Id::new_v4(),
+			lines: Vec::new(),
+			number: "CN-2021-0001".into(),
+			reason: "Overbilled".into(),
+		});
+
+		let third_start = Instant::now();
+		assert!(format!("{}", invoice).ends_with(&format!("\nCredit Notes:\n\t{}", invoice.credit_notes[0])));
+		println!("\n>>>>> Invoice::fmt {}us <<<<<\n", (Instant::now().duration_since(third_start) + middle).as_micros() / 3);
 	}
 }
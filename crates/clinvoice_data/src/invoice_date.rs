@@ -27,13 +27,14 @@ pub struct InvoiceDate
 
 	/// # Summary
 	///
-	/// The date upon which the client paid the [`Invoice`].
+	/// The date upon which the client finished paying the [`Invoice`].
 	///
 	/// # Remarks
 	///
 	/// Upon running `clinvoice new`, this field is left blank. This is to signify that the
 	/// underlying [`Invoice`] has not paid by the client.
 	///
-	/// This field will be updated when running `clinvoice rec`/`receive`
+	/// This field is set when running `clinvoice receive` records the
+	/// [`Payment`](crate::Payment) which settles the [`Invoice`].
 	pub paid: Option<DateTime<Utc>>,
 }
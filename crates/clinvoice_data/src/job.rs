@@ -4,10 +4,12 @@ mod partial_eq;
 
 use
 {
-	crate::{Expense, Id, Invoice, Timesheet},
-	clinvoice_finance::{Decimal, ExchangeRates, Money, Result},
+	std::{collections::{BTreeMap, BTreeSet}, ptr},
 
-	chrono::{DateTime, Utc},
+	crate::{Adjustment, AdjustmentAmount, AdjustmentTotal, BillingModel, BillTo, CreditNoteLine, Expense, ExpenseCategory, ExpenseMarkup, Id, Invoice, Overtime, OvertimeRule, PricedHours, RateChange, RetainerPeriod, Tax, Taxes, TaxTotal, Timesheet, Totals},
+	clinvoice_finance::{Currency, Decimal, ExchangeRates, Money, Result},
+
+	chrono::{Datelike, DateTime, Local, NaiveDate, TimeZone, Utc},
 };
 
 #[cfg(feature="serde_support")]
@@ -15,6 +17,21 @@ use serde::{Deserialize, Serialize};
 
 const SECONDS_PER_HOUR: i16 = 3600;
 
+/// # Summary
+///
+/// Get the first moment of some `date` in a `timezone`.
+///
+/// # Remarks
+///
+/// Where a change to daylight saving time skips midnight (e.g. America/Sao_Paulo before 2019), the
+/// `date` begins at the first hour which exists instead.
+fn start_of_day<Tz>(date: NaiveDate, timezone: &Tz) -> DateTime<Utc> where
+	Tz : TimeZone,
+{
+	(0..24).find_map(|hour| date.and_hms_opt(hour, 0, 0).and_then(|time| timezone.from_local_datetime(&time).earliest()))
+		.map_or_else(|| DateTime::from_utc(date.and_hms(0, 0, 0), Utc), |start| start.with_timezone(&Utc))
+}
+
 /// # Summary
 ///
 /// A [`Job`] contains all of the information which pertains to the specific
@@ -34,6 +51,43 @@ const SECONDS_PER_HOUR: i16 = 3600;
 #[cfg_attr(feature="serde_support", derive(Deserialize, Serialize))]
 pub struct Job
 {
+	/// # Summary
+	///
+	/// Changes to the amount owed for this [`Job`] which are not tied to any work, such as discounts
+	/// or rush fees.
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub adjustments: Vec<Adjustment>,
+
+	/// # Summary
+	///
+	/// Who [`Invoice`]s for this [`Job`] are addressed to, and where they are sent, instead of
+	/// what the [client](Organization) specifies.
+	///
+	/// # Remarks
+	///
+	/// Anything which is not set here falls back to that of the client.
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub bill_to: BillTo,
+
+	/// # Summary
+	///
+	/// How the client is charged for the work.
+	///
+	/// # Configuration
+	///
+	/// The currency used for this model can be configured by running:
+	///
+	/// ```sh
+	/// `clinvoice config -c '<char>'`.
+	/// ```
+	///
+	/// ## Example
+	///
+	/// ```sh
+	/// clinvoice config -c '\$'
+	/// ```
+	pub billing_model: BillingModel,
+
 	/// # Summary
 	///
 	/// The [`Organization`](crate::Organization) who the work is being performed for.
@@ -49,6 +103,40 @@ pub struct Job
 	/// The [date](DateTime) upon which the client requested the work.
 	pub date_open: DateTime<Utc>,
 
+	/// # Summary
+	///
+	/// The [default rate](crate::Employee::hourly_rate) of each [`Employee`](crate::Employee) (by
+	/// [`Id`]) who has worked on this [`Job`], as it was when they started.
+	///
+	/// # Remarks
+	///
+	/// These are billed instead of the rate of the [`BillingModel::Hourly`], but any rate which was
+	/// set on this [`Job`] takes precedence over them.
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub employee_default_rates: BTreeMap<Id, Money>,
+
+	/// # Summary
+	///
+	/// The rate which each [`Employee`](crate::Employee) (by [`Id`]) bills per hour on this
+	/// [`Job`], instead of the rate of the [`BillingModel::Hourly`].
+	///
+	/// # Remarks
+	///
+	/// A rate on a [`Timesheet`], or any of the `rate_changes` for only that
+	/// [`Employee`](crate::Employee) which has taken effect, takes precedence over these.
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub employee_rates: BTreeMap<Id, Money>,
+
+	/// # Summary
+	///
+	/// How much the [`Expense`]s on this [`Job`] are marked up by when they are billed.
+	///
+	/// # Remarks
+	///
+	/// Copied from the [client](Organization) when the [`Job`] is created.
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub expense_markup: ExpenseMarkup,
+
 	/// # Summary
 	///
 	/// The __unique__ number of the [`Job`].
@@ -60,8 +148,9 @@ pub struct Job
 
 	/// # Summary
 	///
-	/// The [`Invoice`] which will be sent to the [client](Organization) after the [`Job`] is done.
-	pub invoice: Invoice,
+	/// The [`Invoice`]s which have been sent to the [client](Organization) for the work on this
+	/// [`Job`], in the order they were issued.
+	pub invoices: Vec<Invoice>,
 
 	/// # Summary
 	///
@@ -93,6 +182,29 @@ pub struct Job
 	/// ```
 	pub objectives: String,
 
+	/// # Summary
+	///
+	/// The [`Overtime`] which applies to the work on this [`Job`] when it is billed by the hour.
+	///
+	/// # Remarks
+	///
+	/// Copied from the [client](Organization) when the [`Job`] is created.
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub overtime: Option<Overtime>,
+
+	/// # Summary
+	///
+	/// Changes to the rate of the [`BillingModel::Hourly`] over time, such as a yearly increase.
+	///
+	/// # Remarks
+	///
+	/// The rate of the [`BillingModel::Hourly`] is billed until the first [`RateChange`] takes
+	/// effect. Once one for only some [`Employee`](crate::Employee) has taken effect, it is billed
+	/// for their work instead of their rate in the `employee_rates`. One for everyone is only
+	/// billed instead of the `employee_default_rates`.
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub rate_changes: Vec<RateChange>,
+
 	/// # Summary
 	///
 	/// The periods of time during which work was performed for this [`Job`].
@@ -113,8 +225,11 @@ impl Job
 		self.timesheets.push(
 			Timesheet
 			{
+				billable: true,
 				employee_id: employee,
 				expenses,
+				hourly_rate: None,
+				invoice_id: None,
 				time_begin,
 				time_end,
 				work_notes: work_notes.into()
@@ -122,6 +237,89 @@ impl Job
 		);
 	}
 
+	/// # Summary
+	///
+	/// Bill some `invoice` for all of the work which has not been billed yet, and was done
+	/// `through` some date. Then, add it to the [`invoices`](Self::invoices).
+	///
+	/// # Remarks
+	///
+	/// * Only [`Timesheet`]s which were stopped, and [`Milestone`](crate::Milestone)s which were
+	///   completed, by then are billed.
+	/// * A [`BillingModel::FixedPrice`] is billed on the first [`Invoice`].
+	/// * A [`BillingModel::Retainer`] is billed one period at a time, once it has ended (see
+	///   [`Job::next_retainer_period`]). Only the [`Timesheet`]s which were stopped during that
+	///   period are billed.
+	/// * Every [`Adjustment`] which has not been billed yet is billed.
+	pub fn bill(&mut self, invoice: Invoice, through: DateTime<Utc>)
+	{
+		let through = match self.next_retainer_period()
+		{
+			Some((begin, end)) if end <= through =>
+			{
+				if let BillingModel::Retainer(retainer) = &mut self.billing_model
+				{
+					retainer.periods_billed.push(RetainerPeriod {begin, end, invoice_id: invoice.id});
+				}
+
+				end
+			},
+
+			// Any work done before the current period began has already been billed.
+			Some((begin, _)) => begin,
+			_ => through,
+		};
+
+		self.adjustments.iter_mut()
+			.filter(|a| a.invoice_id.is_none())
+			.for_each(|a| a.invoice_id = Some(invoice.id));
+
+		self.timesheets.iter_mut()
+			.filter(|t| Self::billable(t.time_end, t.invoice_id, through))
+			.for_each(|t| t.invoice_id = Some(invoice.id));
+
+		if let BillingModel::Milestones {milestones, ..} = &mut self.billing_model
+		{
+			milestones.iter_mut()
+				.filter(|m| Self::billable(m.date_complete, m.invoice_id, through))
+				.for_each(|m| m.invoice_id = Some(invoice.id));
+		}
+
+		self.invoices.push(invoice);
+	}
+
+	/// # Summary
+	///
+	/// Whether some work which was finished on some `date` (if at all), and billed on the
+	/// [`Invoice`] with some `invoice_id` (if at all), may be billed `through` some date.
+	fn billable(date: Option<DateTime<Utc>>, invoice_id: Option<Id>, through: DateTime<Utc>) -> bool
+	{
+		invoice_id.is_none() && date.map(|d| d <= through).unwrap_or(false)
+	}
+
+	/// # Summary
+	///
+	/// Whether there is any work which has not been billed yet, and was done `through` some date.
+	///
+	/// # Remarks
+	///
+	/// * An [`Adjustment`] which has not been billed yet counts as unbilled work.
+	/// * A [`Timesheet`] which is not [billable](Timesheet::billable), and has no billable
+	///   [`Expense`]s, does not.
+	pub fn has_unbilled_work(&self, through: DateTime<Utc>) -> bool
+	{
+		self.adjustments.iter().any(|a| a.invoice_id.is_none()) || match &self.billing_model
+		{
+			BillingModel::FixedPrice(_) if self.invoices.is_empty() => true,
+			BillingModel::Milestones {milestones, ..}
+				if milestones.iter().any(|m| Self::billable(m.date_complete, m.invoice_id, through)) => true,
+			BillingModel::Retainer(_) => self.next_retainer_period().map(|(_, end)| end <= through).unwrap_or(false),
+			_ => self.timesheets.iter().any(|t|
+				Self::billable(t.time_end, t.invoice_id, through) && (t.billable || t.expenses.iter().any(|e| e.billable))
+			),
+		}
+	}
+
 	/// # Summary
 	///
 	/// Create a new [`Timesheet`] with the starting time set to the current time.
@@ -142,53 +340,539 @@ impl Job
 
 	/// # Summary
 	///
-	/// Get the amount of [`Money`] which is owed by the client on the [`Inovice`].
+	/// Get the number of hours which have been worked on the [`Job`], according to its stopped
+	/// [`Timesheet`]s.
+	///
+	/// # Remarks
 	///
-	/// # Panics
+	/// This is tracked regardless of the [`BillingModel`] of the [`Job`], and whether the
+	/// [`Timesheet`]s are [billable](Timesheet::billable).
+	pub fn hours(&self) -> Decimal
+	{
+		self.hours_where(|_| true)
+	}
+
+	/// # Summary
 	///
-	/// * When not all [`Money`] amounts are in the same currency.
+	/// Get the number of hours which have been worked on the [`Job`], according to its stopped
+	/// [`Timesheet`]s which are [billable](Timesheet::billable).
 	///
-	/// # TODO
+	/// # Remarks
 	///
-	/// * Add tests.
-	pub fn total(&self) -> Result<Money>
+	/// Together with [`Job::hours`], this is the billable utilization of the [`Job`].
+	pub fn billable_hours(&self) -> Decimal
+	{
+		self.hours_where(|timesheet| timesheet.billable)
+	}
+
+	/// # Summary
+	///
+	/// Get the number of hours which were worked on the stopped [`Timesheet`]s that match some
+	/// `filter`.
+	fn hours_where(&self, filter: impl Fn(&Timesheet) -> bool) -> Decimal
 	{
 		let seconds_per_hour: Decimal = SECONDS_PER_HOUR.into();
+		self.timesheets.iter().filter(|timesheet| filter(timesheet)).filter_map(|timesheet| timesheet.time_end.map(|time_end|
+			Decimal::from(time_end.signed_duration_since(timesheet.time_begin).num_seconds()) / seconds_per_hour
+		)).sum()
+	}
 
-		let mut exchange_rates = None;
+	/// # Summary
+	///
+	/// Get the rate which is billed per hour for the work on some `timesheet` at some `time`.
+	///
+	/// # Returns
+	///
+	/// * [`None`], if the [`Job`] is not billed by the hour.
+	/// * The [`Timesheet::hourly_rate`], if there is one.
+	/// * Otherwise, the rate of the latest of the [`Job::rate_changes`] for only the
+	///   [`Employee`](crate::Employee) who did the work which took effect by `time`, if there is one.
+	/// * Otherwise, the rate of the [`Employee`](crate::Employee) in the [`Job::employee_rates`], if
+	///   there is one.
+	/// * Otherwise, the rate of the latest of the [`Job::rate_changes`] for everyone which took
+	///   effect by `time`, if there is one.
+	/// * Otherwise, the rate of the [`Employee`](crate::Employee) in the
+	///   [`Job::employee_default_rates`], if there is one.
+	/// * Otherwise, the rate of the [`BillingModel::Hourly`].
+	pub fn hourly_rate(&self, timesheet: &Timesheet, time: DateTime<Utc>) -> Option<Money>
+	{
+		let latest_change = |employee_id: Option<Id>| self.rate_changes.iter()
+			.filter(|change| change.employee_id == employee_id && change.effective_from <= time)
+			.max_by_key(|change| change.effective_from)
+			.map(|change| change.rate);
+
+		match &self.billing_model
+		{
+			BillingModel::Hourly(rate) => Some(timesheet.hourly_rate
+				.or_else(|| latest_change(Some(timesheet.employee_id)))
+				.or_else(|| self.employee_rates.get(&timesheet.employee_id).copied())
+				.or_else(|| latest_change(None))
+				.or_else(|| self.employee_default_rates.get(&timesheet.employee_id).copied())
+				.unwrap_or(*rate)
+			),
+			_ => None,
+		}
+	}
+
+	/// # Summary
+	///
+	/// Get the beginning and end of the next period of a [`BillingModel::Retainer`] which has not
+	/// been billed yet.
+	///
+	/// # Returns
+	///
+	/// * [`None`], if the [`Job`] is not billed by [`Retainer`](crate::Retainer), or it was closed
+	///   before the next period began.
+	/// * Otherwise, the next period, which begins as soon as the previous period ended (or when
+	///   the [`Job`] was opened).
+	pub fn next_retainer_period(&self) -> Option<(DateTime<Utc>, DateTime<Utc>)>
+	{
+		match &self.billing_model
+		{
+			BillingModel::Retainer(retainer) =>
+			{
+				let billed = retainer.periods_billed.len() as u32;
+				let begin = retainer.periods_billed.last().map(|p| p.end).unwrap_or(self.date_open);
+
+				match self.date_close
+				{
+					Some(date_close) if date_close <= begin => None,
+					_ => Some((begin, retainer.period.nth(self.date_open, billed + 1))),
+				}
+			},
+			_ => None,
+		}
+	}
+
+	/// # Summary
+	///
+	/// Get how many hours of a [`BillingModel::Retainer`] were available and used in each of its
+	/// periods.
+	///
+	/// # Returns
+	///
+	/// * An empty [`Vec`], if the [`Job`] is not billed by [`Retainer`](crate::Retainer).
+	/// * Otherwise, the [`Id`] of the [`Invoice`] which each period was billed on, the hours which
+	///   were available (including those which rolled over), and the hours which were worked on
+	///   the [billable](Timesheet::billable) [`Timesheet`]s billed with it. The
+	///   [`Job::next_retainer_period`], if there is one, is last and has no [`Invoice`]; every
+	///   stopped [`Timesheet`] which has not been billed counts towards it.
+	pub fn retainer_usage(&self) -> Vec<(Option<Id>, Decimal, Decimal)>
+	{
+		let retainer = match &self.billing_model
+		{
+			BillingModel::Retainer(retainer) => retainer,
+			_ => return Vec::new(),
+		};
+
+		let seconds_per_hour: Decimal = SECONDS_PER_HOUR.into();
+		let worked = |invoice_id: Option<Id>| self.timesheets.iter()
+			.filter(|t| t.billable && t.invoice_id == invoice_id)
+			.filter_map(|t| t.time_end.map(|time_end| Decimal::from(time_end.signed_duration_since(t.time_begin).num_seconds())))
+			.sum::<Decimal>() / seconds_per_hour;
+
+		let mut carried = Decimal::default();
+		retainer.periods_billed.iter().map(|p| Some(p.invoice_id))
+			.chain(self.next_retainer_period().map(|_| None))
+			.map(|invoice_id|
+			{
+				let available = retainer.included_hours + carried;
+				let used = worked(invoice_id);
+				carried = retainer.rollover.carried(available, used);
+				(invoice_id, available, used)
+			})
+			.collect()
+	}
+
+	/// # Summary
+	///
+	/// Get the [`Job::rate_changes`] which apply to the work of the [`Employee`](crate::Employee)
+	/// with some `employee_id`.
+	fn rate_changes(&self, employee_id: Id) -> impl Iterator<Item=&RateChange>
+	{
+		self.rate_changes.iter().filter(move |change| change.employee_id.map(|id| id == employee_id).unwrap_or(true))
+	}
+
+	/// # Summary
+	///
+	/// Split the work on some `timesheet` into the periods which are billed at each hourly rate,
+	/// according to the [`Job::rate_changes`].
+	///
+	/// # Returns
+	///
+	/// * An empty [`Vec`], if the [`Job`] is not billed by the hour, or the `timesheet` has not
+	///   ended yet.
+	/// * Otherwise, the beginning, end, and [`Job::hourly_rate`] of each period, in order.
+	pub fn rate_periods(&self, timesheet: &Timesheet) -> Vec<(DateTime<Utc>, DateTime<Utc>, Money)>
+	{
+		let time_end = match (&self.billing_model, timesheet.time_end)
+		{
+			(BillingModel::Hourly(_), Some(time_end)) => time_end,
+			_ => return Vec::new(),
+		};
+
+		let mut boundaries: Vec<_> = self.rate_changes(timesheet.employee_id)
+			.map(|change| change.effective_from)
+			.filter(|effective_from| timesheet.time_begin < *effective_from && *effective_from < time_end)
+			.collect();
+		boundaries.sort();
+		boundaries.push(time_end);
+
+		let mut periods: Vec<(DateTime<Utc>, DateTime<Utc>, Money)> = Vec::with_capacity(boundaries.len());
+		boundaries.into_iter().fold(timesheet.time_begin, |begin, end|
+		{
+			let rate = self.hourly_rate(timesheet, begin).unwrap();
+			match periods.last_mut()
+			{
+				Some((_, last_end, last_rate)) if *last_rate == rate => *last_end = end,
+				_ => periods.push((begin, end, rate)),
+			};
+
+			end
+		});
+
+		periods
+	}
+
+	/// # Summary
+	///
+	/// Price the hours worked on each [`Timesheet`] which has ended, by splitting them according to
+	/// the [`Job::rate_periods`] and the [`Job::overtime`].
+	///
+	/// # Remarks
+	///
+	/// * Days and weeks are found in the user's local time. Weeks begin on Monday.
+	/// * Every hour which an [`Employee`](crate::Employee) worked on this [`Job`] counts towards the
+	///   thresholds of [`OvertimeRule::Daily`] and [`OvertimeRule::Weekly`], whether it has been
+	///   billed yet (or is [billable](Timesheet::billable)) or not.
+	///
+	/// # Returns
+	///
+	/// * An empty [`Vec`], if the [`Job`] is not billed by the hour.
+	/// * Otherwise, the [`PricedHours`] of each [`Timesheet`], in the order that they were worked.
+	pub fn priced_hours(&self) -> Vec<PricedHours<'_>>
+	{
+		let rules = self.overtime.as_ref().map(|o| o.rules.as_slice()).unwrap_or_default();
+		let no_holidays = BTreeSet::new();
+		let holidays = self.overtime.as_ref().map(|o| &o.holidays).unwrap_or(&no_holidays);
 
-		let mut total = self.timesheets.iter().filter(|timesheet| timesheet.time_end.is_some()).try_fold(
-			Money::new(0, 2, self.invoice.hourly_rate.currency),
-			|mut total, timesheet| -> Result<Money>
+		let mut timesheets: Vec<_> = self.timesheets.iter().filter(|t| t.time_end.is_some()).collect();
+		timesheets.sort_by_key(|t| t.time_begin);
+
+		let seconds_per_hour: Decimal = SECONDS_PER_HOUR.into();
+		let zero = Decimal::default();
+		let mut worked_daily = BTreeMap::new();
+		let mut worked_weekly = BTreeMap::new();
+		let mut priced: Vec<PricedHours> = Vec::new();
+
+		timesheets.into_iter().for_each(|timesheet| self.rate_periods(timesheet).into_iter().for_each(|(begin, end, rate)|
+		{
+			let mut day_begin = begin;
+			while day_begin < end
 			{
-				let duration_seconds: Decimal = timesheet.time_end.unwrap().signed_duration_since(timesheet.time_begin).num_seconds().into();
-				total.amount += (duration_seconds / seconds_per_hour) * self.invoice.hourly_rate.amount;
+				let date = DateTime::<Local>::from(day_begin).date().naive_local();
+				let day_end = end.min(start_of_day(date.succ(), &Local));
+
+				let worked_today: &mut Decimal = worked_daily.entry((timesheet.employee_id, date)).or_default();
+				let worked_this_week: &mut Decimal = worked_weekly.entry((timesheet.employee_id, date.iso_week())).or_default();
+				let seconds: Decimal = day_end.signed_duration_since(day_begin).num_seconds().into();
+
+				// The number of seconds into this day at which a threshold is crossed.
+				let mut splits: Vec<_> = rules.iter().filter_map(|rule| match rule
+				{
+					OvertimeRule::Daily {hours, ..} => Some(*hours * seconds_per_hour - *worked_today),
+					OvertimeRule::Weekly {hours, ..} => Some(*hours * seconds_per_hour - *worked_this_week),
+					_ => None,
+				}).filter(|split| zero < *split && *split < seconds).collect();
+				splits.push(seconds);
+				splits.sort();
+				splits.dedup();
 
-				timesheet.expenses.iter().try_for_each(|expense| -> Result<()>
+				splits.into_iter().fold(zero, |split_begin, split_end|
 				{
-					if expense.cost.currency == total.currency
+					let overtime = rules.iter().filter(|rule| match rule
 					{
-						total.amount += expense.cost.amount;
-					}
-					else
+						OvertimeRule::Daily {hours, ..} => *worked_today + split_begin >= *hours * seconds_per_hour,
+						OvertimeRule::Holidays {..} => holidays.contains(&date),
+						OvertimeRule::Weekdays {days, ..} => days.contains(&date.weekday()),
+						OvertimeRule::Weekly {hours, ..} => *worked_this_week + split_begin >= *hours * seconds_per_hour,
+					}).max_by_key(|rule| rule.multiplier());
+					let hours = (split_end - split_begin) / seconds_per_hour;
+
+					match priced.last_mut()
 					{
-						if exchange_rates.is_none()
-						{
-							exchange_rates = Some(ExchangeRates::new()?);
-						}
+						Some(last) if ptr::eq(last.timesheet, timesheet) && last.rate == rate && last.overtime == overtime =>
+							last.hours += hours,
+						_ => priced.push(PricedHours {hours, overtime, rate, timesheet}),
+					};
+
+					split_end
+				});
+
+				*worked_today += seconds;
+				*worked_this_week += seconds;
+				day_begin = day_end;
+			}
+		}));
+
+		priced
+	}
+
+	/// # Summary
+	///
+	/// Get the amount of [`Money`] which is owed by the client for all of the work on the [`Job`],
+	/// including its [`Adjustment`]s, when there are no [`Taxes`].
+	pub fn total(&self) -> Result<Money>
+	{
+		self.totals(&Taxes::default()).map(|totals| totals.gross)
+	}
+
+	/// # Summary
+	///
+	/// Get the amount of [`Money`] which is still owed by the client on some `invoice`, after its
+	/// [`Payment`](crate::Payment)s, [`CreditNote`](crate::CreditNote)s, and some `taxes` are
+	/// accounted for.
+	///
+	/// # Remarks
+	///
+	/// * The balance is negative if the client paid more than they were billed.
+	/// * [`Payment`](crate::Payment)s in other currencies are exchanged into the currency of the
+	///   [`Job::billing_model`].
+	pub fn invoice_balance(&self, invoice: &Invoice, taxes: &Taxes) -> Result<Money>
+	{
+		let mut balance = self.invoice_totals(Some(invoice.id), taxes)?.gross;
+		let mut exchange_rates = None;
+
+		invoice.credit_notes.iter().for_each(|c| balance.amount += c.totals(balance.currency, taxes).gross.amount);
+
+		invoice.payments.iter().try_for_each(|payment| -> Result<()>
+		{
+			balance.amount -= Self::exchange(payment.amount, balance.currency, &mut exchange_rates)?;
+			Ok(())
+		})?;
+
+		balance.amount.rescale(2);
+		Ok(balance)
+	}
+
+	/// # Summary
+	///
+	/// Get the [`CreditNoteLine`]s which credit the client for everything that was billed on some
+	/// `invoice`, before some `taxes` are applied.
+	///
+	/// # Remarks
+	///
+	/// * There is one [`CreditNoteLine`] for the billable [`Expense`]s of each [`ExpenseCategory`],
+	///   so that each is taxed as it was on the `invoice`, and one for the rest of what was billed.
+	/// * Percentages before tax are credited alongside what they were taken from.
+	pub fn credit_lines(&self, invoice: &Invoice, taxes: &Taxes) -> Result<Vec<CreditNoteLine>>
+	{
+		let currency = self.billing_model.currency();
+		let net = self.invoice_totals(Some(invoice.id), taxes)?.net.amount;
+		let percent: Decimal = self.adjustments.iter()
+			.filter(|a| a.before_tax && a.invoice_id == Some(invoice.id))
+			.filter_map(|a| match a.amount
+			{
+				AdjustmentAmount::Percent(percent) => Some(percent),
+				_ => None,
+			})
+			.sum();
+
+		let mut exchange_rates = None;
+		let mut expenses = BTreeMap::<&ExpenseCategory, Decimal>::new();
+		self.timesheets.iter().filter(|t| t.time_end.is_some() && t.invoice_id == Some(invoice.id)).try_for_each(|timesheet|
+			timesheet.expenses.iter().filter(|e| e.billable).try_for_each(|expense| -> Result<()>
+			{
+				*expenses.entry(&expense.category).or_default() += Self::exchange(self.expense_markup.apply(expense), currency, &mut exchange_rates)?;
+				Ok(())
+			})
+		)?;
+
+		let mut lines: Vec<_> = expenses.into_iter().map(|(category, mut amount)|
+		{
+			amount += amount * percent / Decimal::new(100, 0);
+			amount.rescale(2);
+			CreditNoteLine {amount: -amount, category: Some(category.clone()), description: format!("{} which was billed in error", category.name)}
+		}).collect();
+
+		let mut work = lines.iter().fold(-net, |work, line| work - line.amount);
+		if lines.is_empty() || !work.is_zero()
+		{
+			work.rescale(2);
+			lines.insert(0, CreditNoteLine {amount: work, category: None, description: "Work which was billed in error".into()});
+		}
+
+		Ok(lines)
+	}
+
+	/// # Summary
+	///
+	/// Get the amount of `money` in some `currency`, downloading the `exchange_rates` if they are
+	/// necessary and have not been already.
+	fn exchange(money: Money, currency: Currency, exchange_rates: &mut Option<ExchangeRates>) -> Result<Decimal>
+	{
+		if money.currency == currency
+		{
+			return Ok(money.amount);
+		}
+
+		if exchange_rates.is_none()
+		{
+			*exchange_rates = Some(ExchangeRates::new()?);
+		}
+
+		Ok(money.exchange(currency, exchange_rates.as_ref().unwrap()).amount)
+	}
+
+	/// # Summary
+	///
+	/// Get the amount of [`Money`] which is owed by the client for the work billed on the
+	/// [`Invoice`] with some `invoice_id` (or the work which has not been billed yet, if [`None`]),
+	/// before and after some `taxes` are applied.
+	///
+	/// # Remarks
+	///
+	/// See [`Job::totals`].
+	pub fn invoice_totals(&self, invoice_id: Option<Id>, taxes: &Taxes) -> Result<Totals>
+	{
+		self.totals_where(taxes, |billed_on| billed_on == invoice_id)
+	}
+
+	/// # Summary
+	///
+	/// Get the amount of [`Money`] which is owed by the client for all of the work on the [`Job`],
+	/// before and after some `taxes` are applied.
+	///
+	/// # Remarks
+	///
+	/// * Every amount is in the currency of the [`Job::billing_model`]. [`Expense`]s and hourly
+	///   rates in other currencies are exchanged.
+	/// * Work is charged according to the [`BillingModel`]: by the hour (see
+	///   [`Job::priced_hours`]), at a fixed price, for each [`Milestone`](crate::Milestone) which
+	///   has been completed, or by [`Retainer`](crate::Retainer) (see [`Job::retainer_usage`]).
+	/// * Only [billable](Timesheet::billable) hours and [billable](Expense::billable) [`Expense`]s
+	///   are charged. [`Expense`]s are marked up by the [`Job::expense_markup`].
+	/// * Each [`Tax`] is applied to the sum of everything it is charged on, rather than to each
+	///   item separately.
+	/// * Fixed [`Adjustment`]s before tax are taxed at the [`Taxes::rates`]. Percentages before
+	///   tax change the amount which each [`Tax`] is charged on by that percentage.
+	pub fn totals(&self, taxes: &Taxes) -> Result<Totals>
+	{
+		self.totals_where(taxes, |_| true)
+	}
+
+	/// # Summary
+	///
+	/// Get the [`Totals`] of the work which was `billed` on some [`Invoice`] (by its [`Id`]).
+	fn totals_where(&self, taxes: &Taxes, billed: impl Fn(Option<Id>) -> bool) -> Result<Totals>
+	{
+		let currency = self.billing_model.currency();
+		let zero = Money::new(0, 2, currency);
+
+		let adjustments: Vec<_> = self.adjustments.iter().filter(|a| billed(a.invoice_id)).collect();
+		let mut exchange_rates = None;
+		let mut net = zero;
+		let mut tax_totals: Vec<TaxTotal> = Vec::new();
 
-						total.amount += expense.cost.exchange(total.currency, exchange_rates.as_ref().unwrap()).amount;
+		let mut charge = |amount: Decimal, applied: &[Tax]|
+		{
+			net.amount += amount;
+			TaxTotal::charge(&mut tax_totals, Money {amount, currency}, applied);
+		};
+
+		match &self.billing_model
+		{
+			BillingModel::FixedPrice(price) => if billed(self.invoices.first().map(|i| i.id))
+			{
+				charge(price.amount, &taxes.rates);
+			},
+			BillingModel::Hourly(_) => (),
+			BillingModel::Milestones {milestones, ..} => milestones.iter()
+				.filter(|milestone| milestone.date_complete.is_some() && billed(milestone.invoice_id))
+				.for_each(|milestone| charge(milestone.amount, &taxes.rates)),
+			BillingModel::Retainer(retainer) => self.retainer_usage().into_iter()
+				.filter(|(invoice_id, ..)| billed(*invoice_id))
+				.try_for_each(|(_, available, used)| -> Result<()>
+				{
+					charge(retainer.amount.amount, &taxes.rates);
+					if used > available
+					{
+						charge((used - available) * Self::exchange(retainer.overage_rate, currency, &mut exchange_rates)?, &taxes.rates);
 					}
 
 					Ok(())
-				})?;
+				})?,
+		};
 
-				Ok(total)
-			},
-		)?;
+		self.priced_hours().into_iter().filter(|priced| priced.timesheet.billable && billed(priced.timesheet.invoice_id)).try_for_each(|priced| -> Result<()>
+		{
+			charge(priced.hours * priced.multiplier() * Self::exchange(priced.rate, currency, &mut exchange_rates)?, &taxes.rates);
+			Ok(())
+		})?;
+
+		self.timesheets.iter().filter(|timesheet| timesheet.time_end.is_some() && billed(timesheet.invoice_id)).try_for_each(|timesheet| -> Result<()>
+		{
+			timesheet.expenses.iter().filter(|expense| expense.billable).try_for_each(|expense| -> Result<()>
+			{
+				charge(Self::exchange(self.expense_markup.apply(expense), currency, &mut exchange_rates)?, taxes.for_expense(&expense.category));
+				Ok(())
+			})
+		})?;
+
+		adjustments.iter().filter(|a| a.before_tax).for_each(|a| if let AdjustmentAmount::Fixed(amount) = a.amount
+		{
+			charge(amount, &taxes.rates);
+		});
+
+		net.amount.rescale(2);
+
+		let percent_of = |base: Decimal, percent: Decimal|
+		{
+			let mut amount = base * percent / Decimal::new(100, 0);
+			amount.rescale(2);
+			amount
+		};
+
+		// Percentages are all taken from the same amounts, rather than compounding on each other.
+		let subtotal = net.amount;
+		let percents: Vec<_> = adjustments.iter().filter(|a| a.before_tax).filter_map(|a| match a.amount
+		{
+			AdjustmentAmount::Percent(percent) => Some(percent),
+			_ => None,
+		}).collect();
+
+		percents.iter().for_each(|percent| net.amount += percent_of(subtotal, *percent));
+
+		let percent: Decimal = percents.into_iter().sum();
+		tax_totals.iter_mut().for_each(|t| t.base.amount += t.base.amount * percent / Decimal::new(100, 0));
+
+		let mut gross = net;
+		tax_totals.iter_mut().for_each(|t|
+		{
+			t.amount.amount = t.tax.on(t.base.amount);
+			t.amount.amount.rescale(2);
+			t.base.amount.rescale(2);
+			gross.amount += t.amount.amount;
+		});
 
-		total.amount.rescale(2);
-		Ok(total)
+		let taxed = gross.amount;
+		let adjustment_totals = adjustments.into_iter().map(|a|
+		{
+			let amount = match a.amount
+			{
+				AdjustmentAmount::Fixed(mut amount) => { amount.rescale(2); amount },
+				AdjustmentAmount::Percent(percent) => percent_of(if a.before_tax { subtotal } else { taxed }, percent),
+			};
+
+			if !a.before_tax
+			{
+				gross.amount += amount;
+			}
+
+			AdjustmentTotal {adjustment: a.clone(), amount: Money {amount, currency}}
+		}).collect();
+
+		Ok(Totals {adjustments: adjustment_totals, gross, net, taxes: tax_totals})
 	}
 }
 
@@ -199,49 +883,139 @@ mod tests
 	{
 		std::time::Instant,
 
-		super::{Expense, Id, Invoice, Job, Money, Timesheet},
-		crate::ExpenseCategory,
+		super::{Adjustment, AdjustmentAmount, BillingModel, Decimal, Expense, ExpenseCategory, ExpenseMarkup, Id, Invoice, Job, Money, Overtime, OvertimeRule, RateChange, Tax, Taxes, TaxTotal, Timesheet},
+		crate::{CreditNote, Milestone, Payment, Recurrence, Retainer, Rollover},
 		clinvoice_finance::Currency,
 
-		chrono::Utc,
+		chrono::{Duration, FixedOffset, Local, LocalResult, NaiveDate, NaiveDateTime, TimeZone, Utc, Weekday},
 	};
 
+	/// # Summary
+	///
+	/// America/Sao_Paulo near the end of 2018, when its clocks moved forward from midnight to 1am
+	/// on November 4th.
+	#[derive(Clone, Copy, Debug)]
+	struct SaoPaulo;
+
+	impl SaoPaulo
+	{
+		/// # Summary
+		///
+		/// The offset from UTC before and after the clocks moved forward.
+		fn offsets() -> (FixedOffset, FixedOffset)
+		{
+			(FixedOffset::west(3 * 3600), FixedOffset::west(2 * 3600))
+		}
+
+		/// # Summary
+		///
+		/// The local time at which the clocks moved forward.
+		fn change() -> NaiveDateTime
+		{
+			NaiveDate::from_ymd(2018, 11, 4).and_hms(0, 0, 0)
+		}
+	}
+
+	impl TimeZone for SaoPaulo
+	{
+		type Offset = FixedOffset;
+
+		fn from_offset(_: &FixedOffset) -> Self
+		{
+			Self
+		}
+
+		fn offset_from_local_date(&self, local: &NaiveDate) -> LocalResult<FixedOffset>
+		{
+			self.offset_from_local_datetime(&local.and_hms(12, 0, 0))
+		}
+
+		fn offset_from_local_datetime(&self, local: &NaiveDateTime) -> LocalResult<FixedOffset>
+		{
+			let (before, after) = Self::offsets();
+			match *local
+			{
+				l if l < Self::change() => LocalResult::Single(before),
+				l if l < Self::change() + Duration::hours(1) => LocalResult::None,
+				_ => LocalResult::Single(after),
+			}
+		}
+
+		fn offset_from_utc_date(&self, utc: &NaiveDate) -> FixedOffset
+		{
+			self.offset_from_utc_datetime(&utc.and_hms(12, 0, 0))
+		}
+
+		fn offset_from_utc_datetime(&self, utc: &NaiveDateTime) -> FixedOffset
+		{
+			let (before, after) = Self::offsets();
+			match *utc < Self::change() + Duration::hours(3)
+			{
+				true => before,
+				_ => after,
+			}
+		}
+	}
+
+	#[test]
+	fn start_of_day()
+	{
+		let start = Instant::now();
+		assert_eq!(super::start_of_day(NaiveDate::from_ymd(2018, 11, 3), &SaoPaulo), Utc.ymd(2018, 11, 3).and_hms(3, 0, 0));
+		assert_eq!(super::start_of_day(NaiveDate::from_ymd(2018, 11, 4), &SaoPaulo), Utc.ymd(2018, 11, 4).and_hms(3, 0, 0));
+		assert_eq!(super::start_of_day(NaiveDate::from_ymd(2018, 11, 5), &SaoPaulo), Utc.ymd(2018, 11, 5).and_hms(2, 0, 0));
+		println!("\n>>>>> job::start_of_day {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / 3);
+	}
+
 	#[test]
 	fn total()
 	{
-		let job = Job
+		let mut job = Job
 		{
+			adjustments: Vec::new(),
+			bill_to: Default::default(),
+			billing_model: BillingModel::Hourly(Money::new(20_00, 2, Currency::USD)),
 			client_id: Id::default(),
 			date_close: None,
 			date_open: Utc::now(),
+			employee_default_rates: Default::default(),
+			employee_rates: Default::default(),
+			expense_markup: Default::default(),
 			id: Id::default(),
-			invoice: Invoice
-			{
-				date: None,
-				hourly_rate: Money::new(20_00, 2, Currency::USD),
-			},
+			invoices: Vec::new(),
 			notes: "".into(),
 			objectives: "".into(),
+			overtime: None,
+			rate_changes: Vec::new(),
 			timesheets: vec![
 				Timesheet
 				{
+					billable: true,
 					employee_id: Id::default(),
 					expenses: Vec::new(),
+					hourly_rate: None,
+					invoice_id: None,
 					time_begin: Utc::today().and_hms(2, 0, 0),
 					time_end: Some(Utc::today().and_hms(2, 30, 0)),
 					work_notes: "- Wrote the test.".into(),
 				},
 				Timesheet
 				{
+					billable: true,
 					employee_id: Id::default(),
 					expenses: vec![
 						Expense
 						{
-							category: ExpenseCategory::Item,
+							attachments: Vec::new(),
+							billable: true,
+							category: ExpenseCategory::new("Item"),
 							cost: Money::new(20_00, 2, Currency::USD),
 							description: "Paid for someone else to clean".into(),
+							kind: Default::default(),
 						},
 					],
+					hourly_rate: None,
+					invoice_id: None,
 					time_begin: Utc::today().and_hms(3, 0, 0),
 					time_end: Some(Utc::today().and_hms(3, 30, 0)),
 					work_notes: "- Clean the deck.".into(),
@@ -252,5 +1026,540 @@ mod tests
 		let start = Instant::now();
 		assert_eq!(job.total().unwrap(), Money::new(4000, 2, Currency::USD));
 		println!("\n>>>>> Job::total {}us <<<<<\n", Instant::now().duration_since(start).as_micros());
+
+		let vat = Tax::Rate {name: "VAT".into(), percent: Decimal::new(19, 0)};
+		let taxes = Taxes
+		{
+			expenses: vec![("Item".into(), vec![Tax::ReverseCharge])].into_iter().collect(),
+			rates: vec![vat.clone()],
+		};
+
+		let start = Instant::now();
+		let totals = job.totals(&taxes).unwrap();
+		println!("\n>>>>> Job::totals {}us <<<<<\n", Instant::now().duration_since(start).as_micros());
+
+		assert_eq!(totals.net, Money::new(40_00, 2, Currency::USD));
+		assert_eq!(totals.taxes, vec![
+			TaxTotal
+			{
+				amount: Money::new(3_80, 2, Currency::USD),
+				base: Money::new(20_00, 2, Currency::USD),
+				tax: vat,
+			},
+			TaxTotal
+			{
+				amount: Money::new(0, 2, Currency::USD),
+				base: Money::new(20_00, 2, Currency::USD),
+				tax: Tax::ReverseCharge,
+			},
+		]);
+		assert_eq!(totals.gross, Money::new(43_80, 2, Currency::USD));
+
+		// The hours worked do not change the price of the other billing models.
+		assert_eq!(job.hours(), Decimal::new(1, 0));
+
+		job.billing_model = BillingModel::FixedPrice(Money::new(50_00, 2, Currency::USD));
+		assert_eq!(job.total().unwrap(), Money::new(70_00, 2, Currency::USD));
+
+		job.billing_model = BillingModel::Milestones
+		{
+			currency: Currency::USD,
+			milestones: vec![
+				Milestone
+				{
+					amount: Decimal::new(30_00, 2),
+					date_complete: Some(Utc::now()),
+					invoice_id: None,
+					name: "Design".into(),
+				},
+				Milestone
+				{
+					amount: Decimal::new(45_00, 2),
+					date_complete: None,
+					invoice_id: None,
+					name: "Build".into(),
+				},
+			],
+		};
+		assert_eq!(job.total().unwrap(), Money::new(50_00, 2, Currency::USD));
+
+		let adjustment = |amount, before_tax, description: &str| Adjustment {amount, before_tax, description: description.into(), invoice_id: None};
+		job.adjustments = vec![
+			adjustment(AdjustmentAmount::Fixed(Decimal::new(-5, 0)), true, "Comped half an hour"),
+			adjustment(AdjustmentAmount::Percent(Decimal::new(-20, 0)), true, "Loyalty discount"),
+			adjustment(AdjustmentAmount::Fixed(Decimal::new(15, 0)), false, "Rush fee"),
+			adjustment(AdjustmentAmount::Percent(Decimal::new(10, 0)), false, "Late fee"),
+		];
+
+		let totals = job.totals(&taxes).unwrap();
+		assert_eq!(
+			totals.adjustments.iter().map(|a| a.amount).collect::<Vec<_>>(),
+			vec![
+				Money::new(-5_00, 2, Currency::USD),
+				Money::new(-9_00, 2, Currency::USD),
+				Money::new(15_00, 2, Currency::USD),
+				Money::new(3_98, 2, Currency::USD),
+			],
+		);
+		assert_eq!(totals.net, Money::new(36_00, 2, Currency::USD));
+		assert_eq!(totals.taxes.iter().map(|t| t.base).collect::<Vec<_>>(), vec![
+			Money::new(20_00, 2, Currency::USD),
+			Money::new(16_00, 2, Currency::USD),
+		]);
+		assert_eq!(totals.gross, Money::new(58_78, 2, Currency::USD));
+		assert_eq!(job.total().unwrap(), Money::new(54_60, 2, Currency::USD));
+	}
+
+	#[test]
+	fn hourly_rate()
+	{
+		let (junior, senior) = (Id::new_v4(), Id::new_v4());
+		let mut job = Job
+		{
+			adjustments: Vec::new(),
+			bill_to: Default::default(),
+			billing_model: BillingModel::Hourly(Money::new(20_00, 2, Currency::USD)),
+			client_id: Id::default(),
+			date_close: None,
+			date_open: Utc::now(),
+			employee_default_rates: Default::default(),
+			employee_rates: vec![(senior, Money::new(60_00, 2, Currency::USD))].into_iter().collect(),
+			expense_markup: Default::default(),
+			id: Id::default(),
+			invoices: Vec::new(),
+			notes: "".into(),
+			objectives: "".into(),
+			overtime: None,
+			rate_changes: Vec::new(),
+			timesheets: Vec::new(),
+		};
+
+		job.attach_timesheet(junior, Vec::new(), Utc::today().and_hms(2, 0, 0), Some(Utc::today().and_hms(3, 0, 0)), "");
+		job.attach_timesheet(senior, Vec::new(), Utc::today().and_hms(2, 0, 0), Some(Utc::today().and_hms(2, 30, 0)), "");
+		job.attach_timesheet(senior, Vec::new(), Utc::today().and_hms(3, 0, 0), Some(Utc::today().and_hms(3, 30, 0)), "");
+		job.timesheets[2].hourly_rate = Some(Money::new(90_00, 2, Currency::USD));
+
+		let start = Instant::now();
+		assert_eq!(
+			job.timesheets.iter().map(|t| job.hourly_rate(t, t.time_begin)).collect::<Vec<_>>(),
+			vec![
+				Some(Money::new(20_00, 2, Currency::USD)),
+				Some(Money::new(60_00, 2, Currency::USD)),
+				Some(Money::new(90_00, 2, Currency::USD)),
+			],
+		);
+		println!("\n>>>>> Job::hourly_rate {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / 3);
+
+		assert_eq!(job.total().unwrap(), Money::new(95_00, 2, Currency::USD));
+
+		// Only hourly jobs have an hourly rate.
+		job.billing_model = BillingModel::FixedPrice(Money::new(50_00, 2, Currency::USD));
+		assert_eq!(job.hourly_rate(&job.timesheets[2], Utc::now()), None);
+	}
+
+	#[test]
+	fn billable()
+	{
+		let mut job = Job
+		{
+			adjustments: Vec::new(),
+			bill_to: Default::default(),
+			billing_model: BillingModel::Hourly(Money::new(20_00, 2, Currency::USD)),
+			client_id: Id::default(),
+			date_close: None,
+			date_open: Utc::now(),
+			employee_default_rates: Default::default(),
+			employee_rates: Default::default(),
+			expense_markup: ExpenseMarkup
+			{
+				categories: vec![("Travel".into(), Decimal::default())].into_iter().collect(),
+				percent: Decimal::new(10, 0),
+			},
+			id: Id::default(),
+			invoices: Vec::new(),
+			notes: "".into(),
+			objectives: "".into(),
+			overtime: None,
+			rate_changes: Vec::new(),
+			timesheets: Vec::new(),
+		};
+
+		let expense = |billable, category, cost| Expense {attachments: Vec::new(), billable, category, cost: Money::new(cost, 2, Currency::USD), description: "".into(), kind: Default::default()};
+		job.attach_timesheet(Id::default(), Vec::new(), Utc::today().and_hms(1, 0, 0), Some(Utc::today().and_hms(2, 0, 0)), "- Internal meeting.");
+		job.attach_timesheet(
+			Id::default(),
+			vec![
+				expense(true, ExpenseCategory::new("Item"), 20_00),
+				expense(true, ExpenseCategory::new("Travel"), 5_00),
+				expense(false, ExpenseCategory::new("Food"), 10_00),
+			],
+			Utc::today().and_hms(3, 0, 0),
+			Some(Utc::today().and_hms(3, 30, 0)),
+			"- Bought supplies.",
+		);
+		job.timesheets[0].billable = false;
+
+		let start = Instant::now();
+		assert_eq!(job.total().unwrap(), Money::new(37_00, 2, Currency::USD));
+		println!("\n>>>>> Job::total {}us <<<<<\n", Instant::now().duration_since(start).as_micros());
+
+		assert_eq!(job.hours(), Decimal::new(15, 1));
+		assert_eq!(job.billable_hours(), Decimal::new(5, 1));
+
+		// Work which is not billable is not waiting to be billed.
+		job.timesheets.remove(1);
+		assert!(!job.has_unbilled_work(Utc::now()));
+		assert_eq!(job.total().unwrap(), Money::new(0, 2, Currency::USD));
+	}
+
+	#[test]
+	fn retainer()
+	{
+		let local = |month, day, hour| Local.ymd(2022, month, day).and_hms(hour, 0, 0).with_timezone(&Utc);
+		let mut job = Job
+		{
+			adjustments: Vec::new(),
+			bill_to: Default::default(),
+			billing_model: BillingModel::Retainer(Retainer
+			{
+				amount: Money::new(80_00, 2, Currency::USD),
+				included_hours: Decimal::new(10, 0),
+				overage_rate: Money::new(12_00, 2, Currency::USD),
+				period: Recurrence::Monthly,
+				periods_billed: Vec::new(),
+				rollover: Rollover::Capped(Decimal::new(2, 0)),
+			}),
+			client_id: Id::default(),
+			date_close: None,
+			date_open: local(1, 1, 0),
+			employee_default_rates: Default::default(),
+			employee_rates: Default::default(),
+			expense_markup: Default::default(),
+			id: Id::default(),
+			invoices: Vec::new(),
+			notes: "".into(),
+			objectives: "".into(),
+			overtime: None,
+			rate_changes: Vec::new(),
+			timesheets: Vec::new(),
+		};
+
+		// 7 hours in January, 13 in February, and 2 so far in March.
+		job.attach_timesheet(Id::default(), Vec::new(), local(1, 5, 9), Some(local(1, 5, 16)), "");
+		job.attach_timesheet(Id::default(), Vec::new(), local(2, 7, 8), Some(local(2, 7, 21)), "");
+		job.attach_timesheet(Id::default(), Vec::new(), local(3, 1, 9), Some(local(3, 1, 11)), "");
+
+		let january = Invoice {credit_notes: Vec::new(), date: None, id: Id::new_v4(), number: None, payment_terms: None, payments: Vec::new(), taxes: None};
+		let february = Invoice {id: Id::new_v4(), ..january.clone()};
+		let march = Invoice {id: Id::new_v4(), ..january.clone()};
+		let through = local(3, 15, 0);
+
+		let start = Instant::now();
+		assert_eq!(job.next_retainer_period(), Some((local(1, 1, 0), local(2, 1, 0))));
+		assert!(job.has_unbilled_work(through));
+		job.bill(january.clone(), through);
+		assert!(job.has_unbilled_work(through));
+		job.bill(february.clone(), through);
+		println!("\n>>>>> Job::bill {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / 2);
+
+		// March has not ended yet.
+		assert_eq!(job.next_retainer_period(), Some((local(3, 1, 0), local(4, 1, 0))));
+		assert!(!job.has_unbilled_work(through));
+		assert_eq!(job.timesheets.iter().map(|t| t.invoice_id).collect::<Vec<_>>(), vec![Some(january.id), Some(february.id), None]);
+
+		// 3 unused hours in January, of which 2 roll over. February is 1 hour over.
+		assert_eq!(job.retainer_usage(), vec![
+			(Some(january.id), Decimal::new(10, 0), Decimal::new(7, 0)),
+			(Some(february.id), Decimal::new(12, 0), Decimal::new(13, 0)),
+			(None, Decimal::new(10, 0), Decimal::new(2, 0)),
+		]);
+		assert_eq!(job.invoice_totals(Some(january.id), &Taxes::default()).unwrap().net, Money::new(80_00, 2, Currency::USD));
+		assert_eq!(job.invoice_totals(Some(february.id), &Taxes::default()).unwrap().net, Money::new(92_00, 2, Currency::USD));
+		assert_eq!(job.invoice_totals(None, &Taxes::default()).unwrap().net, Money::new(80_00, 2, Currency::USD));
+
+		// Nothing is billed before the period ends.
+		job.bill(march.clone(), through);
+		assert_eq!(job.timesheets[2].invoice_id, None);
+
+		// Once a job is closed, there are no more periods to bill.
+		job.date_close = Some(local(3, 1, 0));
+		assert_eq!(job.next_retainer_period(), None);
+	}
+
+	#[test]
+	fn priced_hours()
+	{
+		let employee = Id::new_v4();
+		let mut job = Job
+		{
+			adjustments: Vec::new(),
+			bill_to: Default::default(),
+			billing_model: BillingModel::Hourly(Money::new(5_00, 2, Currency::USD)),
+			client_id: Id::default(),
+			date_close: None,
+			date_open: Utc.ymd(2022, 1, 1).and_hms(0, 0, 0),
+			employee_default_rates: Default::default(),
+			employee_rates: Default::default(),
+			expense_markup: Default::default(),
+			id: Id::default(),
+			invoices: Vec::new(),
+			notes: "".into(),
+			objectives: "".into(),
+			overtime: Some(Overtime
+			{
+				holidays: vec![NaiveDate::from_ymd(2022, 1, 10)].into_iter().collect(),
+				rules: vec![
+					OvertimeRule::Daily {hours: Decimal::new(8, 0), multiplier: Decimal::new(15, 1)},
+					OvertimeRule::Holidays {multiplier: Decimal::new(3, 0)},
+					OvertimeRule::Weekdays {days: vec![Weekday::Sat, Weekday::Sun], multiplier: Decimal::new(2, 0)},
+					OvertimeRule::Weekly {hours: Decimal::new(10, 0), multiplier: Decimal::new(125, 2)},
+				],
+			}),
+			rate_changes: Vec::new(),
+			timesheets: Vec::new(),
+		};
+
+		let local = |day, hour| Local.ymd(2022, 1, day).and_hms(hour, 0, 0).with_timezone(&Utc);
+
+		// Monday, Tuesday, Saturday, and the holiday on the next Monday.
+		job.attach_timesheet(employee, Vec::new(), local(3, 8), Some(local(3, 18)), "");
+		job.attach_timesheet(employee, Vec::new(), local(4, 9), Some(local(4, 11)), "");
+		job.attach_timesheet(employee, Vec::new(), local(8, 10), Some(local(8, 11)), "");
+		job.attach_timesheet(employee, Vec::new(), local(10, 10), Some(local(10, 11)), "");
+
+		let rules = &job.overtime.as_ref().unwrap().rules;
+
+		let start = Instant::now();
+		assert_eq!(
+			job.priced_hours().into_iter().map(|p| (p.hours, p.overtime)).collect::<Vec<_>>(),
+			vec![
+				(Decimal::new(8, 0), None),
+				(Decimal::new(2, 0), Some(&rules[0])),
+				(Decimal::new(2, 0), Some(&rules[3])),
+				(Decimal::new(1, 0), Some(&rules[2])),
+				(Decimal::new(1, 0), Some(&rules[1])),
+			],
+		);
+		println!("\n>>>>> Job::priced_hours {}us <<<<<\n", Instant::now().duration_since(start).as_micros());
+
+		// 8 × 5 + 2 × 7.5 + 2 × 6.25 + 1 × 10 + 1 × 15
+		assert_eq!(job.total().unwrap(), Money::new(92_50, 2, Currency::USD));
+
+		// Work which spans midnight counts towards each day separately.
+		job.timesheets.clear();
+		job.attach_timesheet(employee, Vec::new(), local(4, 17), Some(local(5, 3)), "");
+		assert_eq!(
+			job.priced_hours().into_iter().map(|p| (p.hours, p.overtime)).collect::<Vec<_>>(),
+			vec![(Decimal::new(10, 0), None)],
+		);
+	}
+
+	#[test]
+	fn rate_periods()
+	{
+		let employee = Id::new_v4();
+		let raise = Utc.ymd(2022, 1, 1).and_hms(0, 0, 0);
+		let mut job = Job
+		{
+			adjustments: Vec::new(),
+			bill_to: Default::default(),
+			billing_model: BillingModel::Hourly(Money::new(20_00, 2, Currency::USD)),
+			client_id: Id::default(),
+			date_close: None,
+			date_open: Utc.ymd(2021, 6, 1).and_hms(0, 0, 0),
+			employee_default_rates: Default::default(),
+			employee_rates: Default::default(),
+			expense_markup: Default::default(),
+			id: Id::default(),
+			invoices: Vec::new(),
+			notes: "".into(),
+			objectives: "".into(),
+			overtime: None,
+			rate_changes: vec![RateChange {effective_from: raise, employee_id: None, rate: Money::new(30_00, 2, Currency::USD)}],
+			timesheets: Vec::new(),
+		};
+
+		job.attach_timesheet(employee, Vec::new(), Utc.ymd(2021, 12, 31).and_hms(23, 0, 0), Some(Utc.ymd(2022, 1, 1).and_hms(1, 0, 0)), "");
+		job.attach_timesheet(employee, Vec::new(), Utc.ymd(2022, 1, 2).and_hms(9, 0, 0), Some(Utc.ymd(2022, 1, 2).and_hms(10, 0, 0)), "");
+		job.attach_timesheet(employee, Vec::new(), Utc.ymd(2022, 1, 3).and_hms(9, 0, 0), None, "");
+
+		let start = Instant::now();
+		assert_eq!(job.rate_periods(&job.timesheets[0]), vec![
+			(job.timesheets[0].time_begin, raise, Money::new(20_00, 2, Currency::USD)),
+			(raise, job.timesheets[0].time_end.unwrap(), Money::new(30_00, 2, Currency::USD)),
+		]);
+		assert_eq!(job.rate_periods(&job.timesheets[1]), vec![
+			(job.timesheets[1].time_begin, job.timesheets[1].time_end.unwrap(), Money::new(30_00, 2, Currency::USD)),
+		]);
+		assert_eq!(job.rate_periods(&job.timesheets[2]), Vec::new());
+		println!("\n>>>>> Job::rate_periods {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / 3);
+
+		// 1 hour at 20, then 1 + 1 hours at 30.
+		assert_eq!(job.total().unwrap(), Money::new(80_00, 2, Currency::USD));
+
+		// An employee's default rate is billed until a rate change for everyone takes effect.
+		job.employee_default_rates.insert(employee, Money::new(25_00, 2, Currency::USD));
+		assert_eq!(job.rate_periods(&job.timesheets[0]), vec![
+			(job.timesheets[0].time_begin, raise, Money::new(25_00, 2, Currency::USD)),
+			(raise, job.timesheets[0].time_end.unwrap(), Money::new(30_00, 2, Currency::USD)),
+		]);
+
+		// A rate set for the employee on the job is not affected by a rate change for everyone.
+		job.employee_rates.insert(employee, Money::new(40_00, 2, Currency::USD));
+		assert_eq!(job.rate_periods(&job.timesheets[0]), vec![
+			(job.timesheets[0].time_begin, job.timesheets[0].time_end.unwrap(), Money::new(40_00, 2, Currency::USD)),
+		]);
+
+		// A rate change for only that employee takes precedence over it.
+		job.rate_changes.push(RateChange {effective_from: raise, employee_id: Some(employee), rate: Money::new(35_00, 2, Currency::USD)});
+		job.rate_changes.push(RateChange {effective_from: raise, employee_id: Some(Id::new_v4()), rate: Money::new(50_00, 2, Currency::USD)});
+		assert_eq!(job.rate_periods(&job.timesheets[0]), vec![
+			(job.timesheets[0].time_begin, raise, Money::new(40_00, 2, Currency::USD)),
+			(raise, job.timesheets[0].time_end.unwrap(), Money::new(35_00, 2, Currency::USD)),
+		]);
+	}
+
+	#[test]
+	fn bill()
+	{
+		let mut job = Job
+		{
+			adjustments: Vec::new(),
+			bill_to: Default::default(),
+			billing_model: BillingModel::Hourly(Money::new(20_00, 2, Currency::USD)),
+			client_id: Id::default(),
+			date_close: None,
+			date_open: Utc::now(),
+			employee_default_rates: Default::default(),
+			employee_rates: Default::default(),
+			expense_markup: Default::default(),
+			id: Id::default(),
+			invoices: Vec::new(),
+			notes: "".into(),
+			objectives: "".into(),
+			overtime: None,
+			rate_changes: Vec::new(),
+			timesheets: Vec::new(),
+		};
+
+		job.attach_timesheet(Id::default(), Vec::new(), Utc::today().and_hms(2, 0, 0), Some(Utc::today().and_hms(2, 30, 0)), "");
+		job.attach_timesheet(Id::default(), Vec::new(), Utc::today().and_hms(3, 0, 0), Some(Utc::today().and_hms(3, 30, 0)), "");
+		job.attach_timesheet(Id::default(), Vec::new(), Utc::today().and_hms(4, 0, 0), None, "");
+
+		let first = Invoice {credit_notes: Vec::new(), date: None, id: Id::new_v4(), number: None, payment_terms: None, payments: Vec::new(), taxes: None};
+		let second = Invoice {id: Id::new_v4(), ..first.clone()};
+
+		let start = Instant::now();
+		job.bill(first.clone(), Utc::today().and_hms(2, 45, 0));
+		println!("\n>>>>> Job::bill {}us <<<<<\n", Instant::now().duration_since(start).as_micros());
+
+		assert_eq!(job.timesheets.iter().map(|t| t.invoice_id).collect::<Vec<_>>(), vec![Some(first.id), None, None]);
+		assert!(job.has_unbilled_work(Utc::today().and_hms(3, 30, 0)));
+
+		job.adjustments.push(Adjustment
+		{
+			amount: AdjustmentAmount::Fixed(Decimal::new(2, 0)),
+			before_tax: true,
+			description: "Rush fee".into(),
+			invoice_id: None,
+		});
+
+		// Work which was already billed is not billed again.
+		job.bill(second.clone(), Utc::today().and_hms(3, 30, 0));
+		assert_eq!(job.timesheets.iter().map(|t| t.invoice_id).collect::<Vec<_>>(), vec![Some(first.id), Some(second.id), None]);
+		assert_eq!(job.adjustments[0].invoice_id, Some(second.id));
+		assert!(!job.has_unbilled_work(Utc::today().and_hms(3, 30, 0)));
+		assert_eq!(job.invoices, vec![first.clone(), second.clone()]);
+
+		assert_eq!(job.invoice_totals(Some(first.id), &Taxes::default()).unwrap().net, Money::new(10_00, 2, Currency::USD));
+		assert_eq!(job.invoice_totals(None, &Taxes::default()).unwrap().net, Money::new(0, 2, Currency::USD));
+		assert_eq!(job.invoice_totals(Some(second.id), &Taxes::default()).unwrap().net, Money::new(12_00, 2, Currency::USD));
+		assert_eq!(job.total().unwrap(), Money::new(22_00, 2, Currency::USD));
+
+		let payment = Payment {amount: Money::new(4_00, 2, Currency::USD), date: Utc::now(), method: "".into(), reference: "".into()};
+		job.invoices[0].payments.push(payment.clone());
+
+		let start = Instant::now();
+		assert_eq!(job.invoice_balance(&job.invoices[0], &Taxes::default()).unwrap(), Money::new(6_00, 2, Currency::USD));
+		println!("\n>>>>> Job::invoice_balance {}us <<<<<\n", Instant::now().duration_since(start).as_micros());
+
+		// Paying too much leaves the client in credit.
+		job.invoices[0].payments.push(Payment {amount: Money::new(8_00, 2, Currency::USD), ..payment});
+		assert_eq!(job.invoice_balance(&job.invoices[0], &Taxes::default()).unwrap(), Money::new(-2_00, 2, Currency::USD));
+	}
+	#[test]
+	fn credit_lines()
+	{
+		let mut job = Job
+		{
+			adjustments: Vec::new(),
+			bill_to: Default::default(),
+			billing_model: BillingModel::Hourly(Money::new(20_00, 2, Currency::USD)),
+			client_id: Id::default(),
+			date_close: None,
+			date_open: Utc::now(),
+			employee_default_rates: Default::default(),
+			employee_rates: Default::default(),
+			expense_markup: ExpenseMarkup {percent: Decimal::new(10, 0), ..Default::default()},
+			id: Id::default(),
+			invoices: Vec::new(),
+			notes: "".into(),
+			objectives: "".into(),
+			overtime: None,
+			rate_changes: Vec::new(),
+			timesheets: Vec::new(),
+		};
+
+		let expense = |category: &str, cost: i64| Expense
+		{
+			attachments: Vec::new(),
+			billable: true,
+			category: ExpenseCategory::new(category),
+			cost: Money::new(cost, 2, Currency::USD),
+			description: "".into(),
+			kind: Default::default(),
+		};
+
+		job.attach_timesheet(
+			Id::default(),
+			vec![expense("Food", 12_34), expense("Lodging", 95_00), expense("Lodging", 1_99)],
+			Utc::today().and_hms(2, 0, 0),
+			Some(Utc::today().and_hms(2, 30, 0)),
+			"",
+		);
+
+		let invoice = Invoice {credit_notes: Vec::new(), date: None, id: Id::new_v4(), number: None, payment_terms: None, payments: Vec::new(), taxes: None};
+		job.bill(invoice.clone(), Utc::today().and_hms(3, 0, 0));
+
+		// Lodging is taxed at a lower rate than everything else.
+		let taxes = Taxes
+		{
+			expenses: vec![("Lodging".into(), vec![Tax::Rate {name: "VAT".into(), percent: Decimal::new(7, 0)}])].into_iter().collect(),
+			rates: vec![Tax::Rate {name: "VAT".into(), percent: Decimal::new(19, 0)}],
+		};
+
+		let start = Instant::now();
+		let lines = job.credit_lines(&invoice, &taxes).unwrap();
+		println!("\n>>>>> Job::credit_lines {}us <<<<<\n", Instant::now().duration_since(start).as_micros());
+
+		assert_eq!(
+			lines.iter().map(|l| (l.amount, l.category.as_ref().map(|c| c.code.as_str()))).collect::<Vec<_>>(),
+			vec![
+				(Decimal::new(-10_00, 2), None),
+				(Decimal::new(-13_57, 2), Some("Food")),
+				(Decimal::new(-10669, 2), Some("Lodging")),
+			],
+		);
+
+		// Crediting all of the lines leaves nothing to pay.
+		job.invoices[0].credit_notes.push(CreditNote
+		{
+			date: Utc::now(),
+			id: Id::new_v4(),
+			lines,
+			number: "CN-1".into(),
+			reason: "".into(),
+		});
+		assert_eq!(job.invoice_balance(&job.invoices[0], &taxes).unwrap(), Money::new(0, 2, Currency::USD));
 	}
 }
@@ -10,13 +10,21 @@ impl From<View> for Job
 	{
 		Self
 		{
+			adjustments: view.adjustments,
+			bill_to: view.bill_to,
+			billing_model: view.billing_model,
 			client_id: view.client.id,
 			date_close: view.date_close,
 			date_open: view.date_open,
+			employee_default_rates: view.employee_default_rates,
+			employee_rates: view.employee_rates,
+			expense_markup: view.expense_markup,
 			id: view.id,
-			invoice: view.invoice,
+			invoices: view.invoices,
 			notes: view.notes,
 			objectives: view.objectives,
+			overtime: view.overtime,
+			rate_changes: view.rate_changes,
 			timesheets: view.timesheets.into_iter().map(|t| t.into()).collect(),
 		}
 	}
@@ -28,13 +36,21 @@ impl From<&View> for Job
 	{
 		Self
 		{
+			adjustments: view.adjustments.clone(),
+			bill_to: view.bill_to.clone(),
+			billing_model: view.billing_model.clone(),
 			client_id: view.client.id,
 			date_close: view.date_close,
 			date_open: view.date_open,
+			employee_default_rates: view.employee_default_rates.clone(),
+			employee_rates: view.employee_rates.clone(),
+			expense_markup: view.expense_markup.clone(),
 			id: view.id,
-			invoice: view.invoice.clone(),
+			invoices: view.invoices.clone(),
 			notes: view.notes.clone(),
 			objectives: view.objectives.clone(),
+			overtime: view.overtime.clone(),
+			rate_changes: view.rate_changes.clone(),
 			timesheets: view.timesheets.iter().cloned().map(|t| t.into()).collect(),
 		}
 	}
@@ -18,36 +18,96 @@
 
 #![allow(clippy::suspicious_else_formatting)]
 
+mod adjustment;
+mod adjustment_amount;
+mod adjustment_total;
+mod attachment;
+mod bill_to;
+mod billing_contact;
+mod billing_model;
 mod contact;
+mod credit_note;
+mod credit_note_line;
+mod distance_unit;
 mod employee;
 mod employee_status;
+mod employment_change;
 mod expense;
 mod expense_category;
+mod expense_kind;
+mod expense_markup;
 mod id;
 mod invoice;
 mod invoice_date;
 mod job;
 mod location;
+mod milestone;
 mod organization;
+mod overtime;
+mod overtime_rule;
+mod payment;
+mod payment_method;
+mod payment_terms;
 mod person;
+mod postal_address;
+mod priced_hours;
+mod rate_change;
+mod recurrence;
+mod retainer;
+mod retainer_period;
+mod rollover;
+mod tax;
+mod tax_total;
+mod taxes;
 mod timesheet;
+mod totals;
 pub mod views;
 
 pub use
 {
+	adjustment::Adjustment,
+	adjustment_amount::AdjustmentAmount,
+	adjustment_total::AdjustmentTotal,
+	attachment::Attachment,
+	bill_to::BillTo,
+	billing_contact::BillingContact,
+	billing_model::BillingModel,
 	contact::Contact,
+	credit_note::CreditNote,
+	credit_note_line::CreditNoteLine,
+	distance_unit::DistanceUnit,
 	employee::Employee,
 	employee_status::EmployeeStatus,
+	employment_change::EmploymentChange,
 	expense::Expense,
 	expense_category::ExpenseCategory,
+	expense_kind::ExpenseKind,
+	expense_markup::ExpenseMarkup,
 	id::Id,
 	invoice::Invoice,
 	invoice_date::InvoiceDate,
 	job::Job,
 	location::Location,
+	milestone::Milestone,
 	organization::Organization,
+	overtime::Overtime,
+	overtime_rule::OvertimeRule,
+	payment::Payment,
+	payment_method::PaymentMethod,
+	payment_terms::PaymentTerms,
 	person::Person,
+	postal_address::PostalAddress,
+	priced_hours::PricedHours,
+	rate_change::RateChange,
+	recurrence::Recurrence,
+	retainer::Retainer,
+	retainer_period::RetainerPeriod,
+	rollover::Rollover,
+	tax::Tax,
+	tax_total::TaxTotal,
+	taxes::Taxes,
 	timesheet::Timesheet,
+	totals::Totals,
 };
 
 pub use chrono;
@@ -63,3 +123,12 @@ pub const UUID_NAMESPACE: Id = Id::from_bytes(
 	0x92, 0x08, 0xe5, 0x6b,
 	0x09, 0x9a, 0x6f, 0x4b
 ]);
+
+/// # Summary
+///
+/// The default value of fields which are `true` unless specified otherwise.
+#[cfg(feature="serde_support")]
+const fn default_true() -> bool
+{
+	true
+}
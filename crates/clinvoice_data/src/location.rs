@@ -2,7 +2,7 @@ mod from_view;
 mod hash;
 mod partial_eq;
 
-use crate::Id;
+use crate::{Id, PostalAddress, Taxes};
 
 #[cfg(feature="serde_support")]
 use serde::{Deserialize, Serialize};
@@ -15,6 +15,17 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature="serde_support", derive(Deserialize, Serialize))]
 pub struct Location
 {
+	/// # Summary
+	///
+	/// The [`PostalAddress`] of the [`Location`], if it is somewhere which mail can be sent to.
+	///
+	/// # Remarks
+	///
+	/// When there is a [`PostalAddress`], it is used to refer to the [`Location`] instead of the
+	/// `name`s of it and the [`Location`]s which it is inside of.
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub address: Option<PostalAddress>,
+
 	/// # Summary
 	///
 	/// The reference number of the [`Location`].
@@ -36,4 +47,10 @@ pub struct Location
 	///
 	/// The name of the [`Location`].
 	pub name: String,
+
+	/// # Summary
+	///
+	/// The [`Taxes`] which are charged to clients in this [`Location`], instead of those of the
+	/// [`Location`] it is inside of.
+	pub taxes: Option<Taxes>,
 }
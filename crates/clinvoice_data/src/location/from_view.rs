@@ -10,9 +10,11 @@ impl From<View> for Location
 	{
 		Self
 		{
+			address: view.address,
 			id: view.id,
 			outer_id: view.outer.map(|l| l.id),
 			name: view.name,
+			taxes: view.taxes,
 		}
 	}
 }
@@ -23,9 +25,11 @@ impl From<&View> for Location
 	{
 		Self
 		{
+			address: view.address.clone(),
 			id: view.id,
 			outer_id: view.outer.as_ref().map(|l| l.id),
 			name: view.name.clone(),
+			taxes: view.taxes.clone(),
 		}
 	}
 }
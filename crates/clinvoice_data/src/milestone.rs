@@ -0,0 +1,47 @@
+use
+{
+	crate::Id,
+
+	clinvoice_finance::Decimal,
+
+	chrono::{DateTime, Utc},
+};
+
+#[cfg(feature="serde_support")]
+use serde::{Deserialize, Serialize};
+
+/// # Summary
+///
+/// A `Milestone` is some part of a [`Job`](crate::Job) which the client pays for once it is
+/// complete.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature="serde_support", derive(Deserialize, Serialize))]
+pub struct Milestone
+{
+	/// # Summary
+	///
+	/// The amount which is charged for the [`Milestone`], in the currency of the
+	/// [`BillingModel::Milestones`](crate::BillingModel::Milestones).
+	pub amount: Decimal,
+
+	/// # Summary
+	///
+	/// The date upon which the [`Milestone`] was completed.
+	///
+	/// # Remarks
+	///
+	/// The client is only charged for the [`Milestone`] once this is set.
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub date_complete: Option<DateTime<Utc>>,
+
+	/// # Summary
+	///
+	/// The [`Id`] of the [`Invoice`](crate::Invoice) which this [`Milestone`] was billed on.
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub invoice_id: Option<Id>,
+
+	/// # Summary
+	///
+	/// The name of the [`Milestone`], e.g. "Design".
+	pub name: String,
+}
@@ -1,6 +1,11 @@
 mod from_view;
 
-use crate::Id;
+use
+{
+	std::collections::BTreeMap,
+
+	crate::{BillTo, ExpenseMarkup, Id, Overtime, PaymentMethod, PaymentTerms, Taxes},
+};
 
 #[cfg(feature="serde_support")]
 use serde::{Deserialize, Serialize};
@@ -20,6 +25,23 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature="serde_support", derive(Deserialize, Serialize))]
 pub struct Organization
 {
+	/// # Summary
+	///
+	/// Who [`Invoice`](crate::Invoice)s are addressed to, and where they are sent, when this
+	/// [`Organization`] is a client.
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub bill_to: BillTo,
+
+	/// # Summary
+	///
+	/// How much the [`Expense`](crate::Expense)s on work for this client are marked up by.
+	///
+	/// # Remarks
+	///
+	/// Copied to each [`Job`](crate::Job) for this client when it is created.
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub expense_markup: ExpenseMarkup,
+
 	/// # Summary
 	///
 	/// The unique reference number for this [`Organization`].
@@ -35,4 +57,37 @@ pub struct Organization
 	///
 	/// The name of the [`Organization`].
 	pub name: String,
+
+	/// # Summary
+	///
+	/// The [`Overtime`] which applies to [`Job`](crate::Job)s for this [`Organization`] when it is
+	/// a client.
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub overtime: Option<Overtime>,
+
+	/// # Summary
+	///
+	/// The ways which this [`Organization`] can be paid, which are shown on the invoices it issues.
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub payment_methods: Vec<PaymentMethod>,
+
+	/// # Summary
+	///
+	/// The [`PaymentTerms`] which this [`Organization`] is given when it is a client, instead of
+	/// those in the user's configuration.
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub payment_terms: Option<PaymentTerms>,
+
+	/// # Summary
+	///
+	/// The identifiers which this [`Organization`] is registered for taxes with, by kind (e.g.
+	/// `VAT` → `DE123456789`).
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub tax_ids: BTreeMap<String, String>,
+
+	/// # Summary
+	///
+	/// The [`Taxes`] which are charged to this [`Organization`] when it is a client, instead of
+	/// those of its [`Location`](super::location::Location).
+	pub taxes: Option<Taxes>,
 }
@@ -10,9 +10,16 @@ impl From<View> for Organization
 	{
 		Self
 		{
+			bill_to: view.bill_to,
+			expense_markup: view.expense_markup,
 			id: view.id,
 			location_id: view.location.id,
 			name: view.name,
+			overtime: view.overtime,
+			payment_methods: view.payment_methods,
+			payment_terms: view.payment_terms,
+			tax_ids: view.tax_ids,
+			taxes: view.taxes,
 		}
 	}
 }
@@ -23,9 +30,16 @@ impl From<&View> for Organization
 	{
 		Self
 		{
+			bill_to: view.bill_to.clone(),
+			expense_markup: view.expense_markup.clone(),
 			id: view.id,
 			location_id: view.location.id,
 			name: view.name.clone(),
+			overtime: view.overtime.clone(),
+			payment_methods: view.payment_methods.clone(),
+			payment_terms: view.payment_terms,
+			tax_ids: view.tax_ids.clone(),
+			taxes: view.taxes.clone(),
 		}
 	}
 }
@@ -0,0 +1,95 @@
+use
+{
+	std::{collections::BTreeSet, fs, io, path::Path},
+
+	crate::OvertimeRule,
+
+	chrono::NaiveDate,
+};
+
+#[cfg(feature="serde_support")]
+use serde::{Deserialize, Serialize};
+
+/// # Summary
+///
+/// The [`OvertimeRule`]s which apply to work that is billed by the hour.
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature="serde_support", derive(Deserialize, Serialize))]
+pub struct Overtime
+{
+	/// # Summary
+	///
+	/// The dates of holidays, for [`OvertimeRule::Holidays`].
+	///
+	/// # Remarks
+	///
+	/// These can be read from a calendar file with [`Overtime::read_holidays`].
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub holidays: BTreeSet<NaiveDate>,
+
+	/// # Summary
+	///
+	/// The [`OvertimeRule`]s. When more than one applies to the same work, the one with the
+	/// greatest [multiplier](OvertimeRule::multiplier) is used.
+	pub rules: Vec<OvertimeRule>,
+}
+
+impl Overtime
+{
+	/// # Summary
+	///
+	/// Read the dates of the holidays in the calendar file at some `path`.
+	///
+	/// # Remarks
+	///
+	/// Each line of the file begins with a date (e.g. `2021-12-25 Christmas Day`). Empty lines, and
+	/// lines starting with `#`, are ignored.
+	///
+	/// # Returns
+	///
+	/// * The dates of the holidays.
+	/// * An [`io::Error`], if the calendar could not be read, or contains a line which does not
+	///   begin with a date.
+	pub fn read_holidays(path: &Path) -> io::Result<BTreeSet<NaiveDate>>
+	{
+		fs::read_to_string(path)?.lines()
+			.map(|line| line.trim())
+			.filter(|line| !(line.is_empty() || line.starts_with('#')))
+			.map(|line| NaiveDate::parse_from_str(line.split_whitespace().next().unwrap(), "%Y-%m-%d").map_err(|e|
+				io::Error::new(io::ErrorKind::InvalidData, format!("{} in {}: {}", e, path.display(), line))
+			))
+			.collect()
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use
+	{
+		std::{env, fs, time::Instant},
+
+		super::{NaiveDate, Overtime},
+	};
+
+	#[test]
+	fn read_holidays()
+	{
+		let path = env::temp_dir().join("clinvoice_data__overtime__read_holidays.txt");
+		assert!(Overtime::read_holidays(&path).is_err());
+
+		fs::write(&path, "# Public holidays\n2021-12-25 Christmas Day\n\n2022-01-01 New Year's Day\n").unwrap();
+
+		let start = Instant::now();
+		assert_eq!(
+			Overtime::read_holidays(&path).unwrap().into_iter().collect::<Vec<_>>(),
+			vec![NaiveDate::from_ymd(2021, 12, 25), NaiveDate::from_ymd(2022, 1, 1)],
+		);
+		println!("\n>>>>> Overtime::read_holidays {}us <<<<<\n", Instant::now().duration_since(start).as_micros());
+
+		fs::write(&path, "Christmas Day\n").unwrap();
+		assert!(Overtime::read_holidays(&path).is_err());
+
+		fs::remove_file(path).unwrap();
+	}
+}
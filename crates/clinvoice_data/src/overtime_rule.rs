@@ -0,0 +1,89 @@
+mod display;
+
+use
+{
+	core::cmp::Ordering,
+
+	clinvoice_finance::Decimal,
+
+	chrono::Weekday,
+};
+
+#[cfg(feature="serde_support")]
+use serde::{Deserialize, Serialize};
+
+/// # Summary
+///
+/// A rule for when work which is billed by the hour is billed at a multiple of its usual rate,
+/// e.g. 1.5× after 40 hours a week.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+#[cfg_attr(feature="serde_support", derive(Deserialize, Serialize))]
+pub enum OvertimeRule
+{
+	/// # Summary
+	///
+	/// Work done after some number of `hours` have been worked on the same day.
+	Daily {hours: Decimal, multiplier: Decimal},
+
+	/// # Summary
+	///
+	/// Work done on a date which is one of the [`Overtime::holidays`](crate::Overtime::holidays).
+	Holidays {multiplier: Decimal},
+
+	/// # Summary
+	///
+	/// Work done on some `days` of the week (e.g. weekends).
+	Weekdays {days: Vec<Weekday>, multiplier: Decimal},
+
+	/// # Summary
+	///
+	/// Work done after some number of `hours` have been worked in the same week (from Monday to
+	/// Sunday).
+	Weekly {hours: Decimal, multiplier: Decimal},
+}
+
+impl OvertimeRule
+{
+	/// # Summary
+	///
+	/// The multiple of the usual rate which is billed for work that this rule applies to.
+	pub fn multiplier(&self) -> Decimal
+	{
+		match self
+		{
+			Self::Daily {multiplier, ..} | Self::Holidays {multiplier} |
+			Self::Weekdays {multiplier, ..} | Self::Weekly {multiplier, ..} => *multiplier,
+		}
+	}
+
+	/// # Summary
+	///
+	/// A key for ordering [`OvertimeRule`]s, since [`Weekday`] cannot be ordered.
+	fn sort_key(&self) -> (u8, Decimal, Vec<u32>, Decimal)
+	{
+		match self
+		{
+			Self::Daily {hours, multiplier} => (0, *hours, Vec::new(), *multiplier),
+			Self::Holidays {multiplier} => (1, Decimal::default(), Vec::new(), *multiplier),
+			Self::Weekdays {days, multiplier} =>
+				(2, Decimal::default(), days.iter().map(|d| d.num_days_from_monday()).collect(), *multiplier),
+			Self::Weekly {hours, multiplier} => (3, *hours, Vec::new(), *multiplier),
+		}
+	}
+}
+
+impl Ord for OvertimeRule
+{
+	fn cmp(&self, other: &Self) -> Ordering
+	{
+		self.sort_key().cmp(&other.sort_key())
+	}
+}
+
+impl PartialOrd for OvertimeRule
+{
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering>
+	{
+		Some(self.cmp(other))
+	}
+}
@@ -0,0 +1,58 @@
+use
+{
+	core::fmt::{Display, Formatter, Result},
+
+	super::OvertimeRule,
+};
+
+impl Display for OvertimeRule
+{
+	fn fmt(&self, formatter: &mut Formatter) -> Result
+	{
+		write!(formatter, "{}× ", self.multiplier())?;
+
+		match self
+		{
+			Self::Daily {hours, ..} => write!(formatter, "after {} hours a day", hours),
+			Self::Holidays {..} => write!(formatter, "on holidays"),
+			Self::Weekdays {days, ..} => write!(formatter, "on {}",
+				days.iter().map(|d| d.to_string()).collect::<Vec<_>>().join(", "),
+			),
+			Self::Weekly {hours, ..} => write!(formatter, "after {} hours a week", hours),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use
+	{
+		std::time::Instant,
+
+		super::OvertimeRule,
+		clinvoice_finance::Decimal,
+
+		chrono::Weekday,
+	};
+
+	#[test]
+	fn display()
+	{
+		let start = Instant::now();
+		assert_eq!(
+			format!("{}", OvertimeRule::Daily {hours: Decimal::new(75, 1), multiplier: Decimal::new(125, 2)}),
+			"1.25× after 7.5 hours a day",
+		);
+		assert_eq!(format!("{}", OvertimeRule::Holidays {multiplier: Decimal::new(2, 0)}), "2× on holidays");
+		assert_eq!(
+			format!("{}", OvertimeRule::Weekdays {days: vec![Weekday::Sat, Weekday::Sun], multiplier: Decimal::new(15, 1)}),
+			"1.5× on Sat, Sun",
+		);
+		assert_eq!(
+			format!("{}", OvertimeRule::Weekly {hours: Decimal::new(40, 0), multiplier: Decimal::new(15, 1)}),
+			"1.5× after 40 hours a week",
+		);
+		println!("\n>>>>> OvertimeRule::fmt {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / 4);
+	}
+}
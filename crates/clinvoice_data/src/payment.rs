@@ -0,0 +1,47 @@
+mod display;
+
+use
+{
+	clinvoice_finance::Money,
+
+	chrono::{DateTime, Utc},
+};
+
+#[cfg(feature="serde_support")]
+use serde::{Deserialize, Serialize};
+
+/// # Summary
+///
+/// A `Payment` is some amount of [`Money`] which a client paid towards an
+/// [`Invoice`](crate::Invoice).
+///
+/// # Remarks
+///
+/// An [`Invoice`](crate::Invoice) may be paid over several `Payment`s, or paid more than it was
+/// billed for.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature="serde_support", derive(Deserialize, Serialize))]
+pub struct Payment
+{
+	/// # Summary
+	///
+	/// The amount of [`Money`] which was paid.
+	pub amount: Money,
+
+	/// # Summary
+	///
+	/// The date upon which the [`Payment`] was received.
+	pub date: DateTime<Utc>,
+
+	/// # Summary
+	///
+	/// How the [`Payment`] was made, e.g. "Bank Transfer".
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub method: String,
+
+	/// # Summary
+	///
+	/// Anything which identifies the [`Payment`], e.g. a transaction number.
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub reference: String,
+}
@@ -0,0 +1,66 @@
+use
+{
+	core::fmt::{Display, Formatter, Result},
+
+	super::Payment,
+
+	chrono::{DateTime, Local},
+};
+
+impl Display for Payment
+{
+	fn fmt(&self, formatter: &mut Formatter) -> Result
+	{
+		write!(formatter, "{} on {}", self.amount, DateTime::<Local>::from(self.date).naive_local())?;
+
+		if !self.method.is_empty()
+		{
+			write!(formatter, " by {}", self.method)?;
+		}
+
+		if !self.reference.is_empty()
+		{
+			write!(formatter, " ({})", self.reference)?;
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use
+	{
+		std::time::Instant,
+
+		super::{DateTime, Local, Payment},
+
+		clinvoice_finance::{Currency, Money},
+
+		chrono::Utc,
+	};
+
+	#[test]
+	fn display()
+	{
+		let mut payment = Payment
+		{
+			amount: Money::new(20_00, 2, Currency::USD),
+			date: Utc::now(),
+			method: "".into(),
+			reference: "".into(),
+		};
+
+		let start = Instant::now();
+		assert_eq!(format!("{}", payment), format!("20.00 USD on {}", DateTime::<Local>::from(payment.date).naive_local()));
+
+		payment.method = "Bank Transfer".into();
+		payment.reference = "TX-1234".into();
+		assert_eq!(
+			format!("{}", payment),
+			format!("20.00 USD on {} by Bank Transfer (TX-1234)", DateTime::<Local>::from(payment.date).naive_local()),
+		);
+		println!("\n>>>>> Payment::fmt {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / 2);
+	}
+}
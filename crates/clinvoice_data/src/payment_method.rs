@@ -0,0 +1,57 @@
+mod display;
+
+#[cfg(feature="serde_support")]
+use serde::{Deserialize, Serialize};
+
+/// # Summary
+///
+/// A way which an [`Organization`](crate::Organization) can be paid.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature="serde_support", derive(Deserialize, Serialize))]
+pub enum PaymentMethod
+{
+	/// # Summary
+	///
+	/// A transfer to a domestic bank account.
+	Account
+	{
+		/// # Summary
+		///
+		/// The number of the account.
+		account_number: String,
+
+		/// # Summary
+		///
+		/// The name of the bank which holds the account.
+		#[cfg_attr(feature="serde_support", serde(default))]
+		bank: Option<String>,
+
+		/// # Summary
+		///
+		/// The number which identifies the bank (e.g. an ABA routing number or sort code).
+		#[cfg_attr(feature="serde_support", serde(default))]
+		routing_number: Option<String>,
+	},
+
+	/// # Summary
+	///
+	/// A transfer to an International Bank Account Number.
+	Iban
+	{
+		/// # Summary
+		///
+		/// The Bank Identifier Code (also known as a SWIFT code) of the bank which holds the account.
+		#[cfg_attr(feature="serde_support", serde(default))]
+		bic: Option<String>,
+
+		/// # Summary
+		///
+		/// The International Bank Account Number.
+		iban: String,
+	},
+
+	/// # Summary
+	///
+	/// Any other instructions for payment (e.g. "Checks payable to Big Old Test").
+	Other(String),
+}
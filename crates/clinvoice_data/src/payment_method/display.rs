@@ -0,0 +1,73 @@
+use
+{
+	core::fmt::{Display, Formatter, Result},
+
+	super::PaymentMethod,
+};
+
+impl Display for PaymentMethod
+{
+	fn fmt(&self, formatter: &mut Formatter) -> Result
+	{
+		match self
+		{
+			Self::Account {account_number, bank, routing_number} =>
+			{
+				write!(formatter, "Account {}", account_number)?;
+				if let Some(routing_number) = routing_number
+				{
+					write!(formatter, ", Routing {}", routing_number)?;
+				}
+
+				if let Some(bank) = bank
+				{
+					write!(formatter, " at {}", bank)?;
+				}
+
+				Ok(())
+			},
+
+			Self::Iban {bic, iban} =>
+			{
+				write!(formatter, "IBAN {}", iban)?;
+				if let Some(bic) = bic
+				{
+					write!(formatter, ", BIC {}", bic)?;
+				}
+
+				Ok(())
+			},
+
+			Self::Other(instructions) => write!(formatter, "{}", instructions),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use
+	{
+		std::time::Instant,
+
+		super::PaymentMethod,
+	};
+
+	#[test]
+	fn display()
+	{
+		let account = PaymentMethod::Account
+		{
+			account_number: "000123456789".into(),
+			bank: Some("First Test Bank".into()),
+			routing_number: Some("110000000".into()),
+		};
+		let iban = PaymentMethod::Iban {bic: Some("COBADEFFXXX".into()), iban: "DE89 3704 0044 0532 0130 00".into()};
+
+		let start = Instant::now();
+		assert_eq!(format!("{}", account), "Account 000123456789, Routing 110000000 at First Test Bank");
+		assert_eq!(format!("{}", iban), "IBAN DE89 3704 0044 0532 0130 00, BIC COBADEFFXXX");
+		assert_eq!(format!("{}", PaymentMethod::Other("Cash".into())), "Cash");
+		println!("\n>>>>> PaymentMethod::fmt {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / 3);
+	}
+}
@@ -0,0 +1,93 @@
+mod display;
+
+use chrono::{Datelike, DateTime, Duration, Local, NaiveDate, Utc};
+
+#[cfg(feature="serde_support")]
+use serde::{Deserialize, Serialize};
+
+/// # Summary
+///
+/// How long a client has to pay an [`Invoice`](crate::Invoice) after it is issued.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature="serde_support", derive(Deserialize, Serialize))]
+pub enum PaymentTerms
+{
+	/// # Summary
+	///
+	/// The [`Invoice`](crate::Invoice) is due as soon as it is issued.
+	DueOnReceipt,
+
+	/// # Summary
+	///
+	/// The [`Invoice`](crate::Invoice) is due some number of days after the end of the month in
+	/// which it was issued.
+	EndOfMonth(u32),
+
+	/// # Summary
+	///
+	/// The [`Invoice`](crate::Invoice) is due some number of days after it was issued, e.g. "Net 30".
+	Net(u32),
+}
+
+impl PaymentTerms
+{
+	/// # Summary
+	///
+	/// Get the date upon which an [`Invoice`](crate::Invoice) which was `issued` on some date is due.
+	///
+	/// # Remarks
+	///
+	/// The end of the month is found in the user's local time.
+	pub fn due(&self, issued: DateTime<Utc>) -> DateTime<Utc>
+	{
+		match self
+		{
+			Self::DueOnReceipt => issued,
+			Self::EndOfMonth(days) =>
+			{
+				let local = DateTime::<Local>::from(issued);
+				let (year, month) = if local.month() == 12 { (local.year() + 1, 1) } else { (local.year(), local.month() + 1) };
+				let days_left_in_month = NaiveDate::from_ymd(year, month, 1).signed_duration_since(local.naive_local().date()).num_days() - 1;
+
+				issued + Duration::days(days_left_in_month + i64::from(*days))
+			},
+			Self::Net(days) => issued + Duration::days((*days).into()),
+		}
+	}
+}
+
+impl Default for PaymentTerms
+{
+	fn default() -> Self
+	{
+		Self::Net(30)
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use
+	{
+		std::time::Instant,
+
+		super::PaymentTerms,
+
+		chrono::{DateTime, Local, TimeZone, Utc},
+	};
+
+	#[test]
+	fn due()
+	{
+		let issued = DateTime::<Utc>::from(Local.ymd(2021, 2, 10).and_hms(12, 0, 0));
+		let december = DateTime::<Utc>::from(Local.ymd(2021, 12, 31).and_hms(12, 0, 0));
+
+		let start = Instant::now();
+		assert_eq!(PaymentTerms::DueOnReceipt.due(issued), issued);
+		assert_eq!(PaymentTerms::Net(30).due(issued), DateTime::<Utc>::from(Local.ymd(2021, 3, 12).and_hms(12, 0, 0)));
+		assert_eq!(PaymentTerms::EndOfMonth(0).due(issued), DateTime::<Utc>::from(Local.ymd(2021, 2, 28).and_hms(12, 0, 0)));
+		assert_eq!(PaymentTerms::EndOfMonth(10).due(issued), DateTime::<Utc>::from(Local.ymd(2021, 3, 10).and_hms(12, 0, 0)));
+		assert_eq!(PaymentTerms::EndOfMonth(15).due(december), DateTime::<Utc>::from(Local.ymd(2022, 1, 15).and_hms(12, 0, 0)));
+		println!("\n>>>>> PaymentTerms::due {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / 5);
+	}
+}
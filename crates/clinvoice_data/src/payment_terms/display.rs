@@ -0,0 +1,42 @@
+use
+{
+	core::fmt::{Display, Formatter, Result},
+
+	super::PaymentTerms,
+};
+
+impl Display for PaymentTerms
+{
+	fn fmt(&self, formatter: &mut Formatter) -> Result
+	{
+		match self
+		{
+			Self::DueOnReceipt => write!(formatter, "Due on receipt"),
+			Self::EndOfMonth(0) => write!(formatter, "End of month"),
+			Self::EndOfMonth(days) => write!(formatter, "End of month + {} days", days),
+			Self::Net(days) => write!(formatter, "Net {}", days),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use
+	{
+		std::time::Instant,
+
+		super::PaymentTerms,
+	};
+
+	#[test]
+	fn display()
+	{
+		let start = Instant::now();
+		assert_eq!(format!("{}", PaymentTerms::DueOnReceipt), "Due on receipt");
+		assert_eq!(format!("{}", PaymentTerms::EndOfMonth(0)), "End of month");
+		assert_eq!(format!("{}", PaymentTerms::EndOfMonth(10)), "End of month + 10 days");
+		assert_eq!(format!("{}", PaymentTerms::Net(30)), "Net 30");
+		println!("\n>>>>> PaymentTerms::fmt {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / 4);
+	}
+}
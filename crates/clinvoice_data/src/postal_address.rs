@@ -0,0 +1,136 @@
+mod display;
+
+#[cfg(feature="serde_support")]
+use serde::{Deserialize, Serialize};
+
+/// # Summary
+///
+/// The address which mail for a [`Location`](crate::Location) should be sent to.
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature="serde_support", derive(Deserialize, Serialize))]
+pub struct PostalAddress
+{
+	/// # Summary
+	///
+	/// The city, town, or village of the address.
+	pub city: String,
+
+	/// # Summary
+	///
+	/// The ISO 3166-1 alpha-2 code of the country of the address (e.g. `US`), which decides how the
+	/// address is formatted.
+	pub country: String,
+
+	/// # Summary
+	///
+	/// The postal code of the address (e.g. `85004`), if the country uses them.
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub postal_code: Option<String>,
+
+	/// # Summary
+	///
+	/// The state, province, or other region of the address (e.g. `AZ`), if the country uses them.
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub region: Option<String>,
+
+	/// # Summary
+	///
+	/// The lines of the address which come before the `city`, such as the street, building, and
+	/// unit number.
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub street: Vec<String>,
+}
+
+impl PostalAddress
+{
+	/// # Summary
+	///
+	/// Get the lines of this [`PostalAddress`], in the order which they are written on mail to the
+	/// `country`.
+	///
+	/// # Remarks
+	///
+	/// The last line is always the `country`, so that the address may be used internationally.
+	pub fn lines(&self) -> Vec<String>
+	{
+		/// # Summary
+		///
+		/// Join the `parts` of a line which are present with a `separator`.
+		fn join(parts: &[Option<&str>], separator: &str) -> String
+		{
+			parts.iter().flatten().filter(|p| !p.is_empty()).copied().collect::<Vec<_>>().join(separator)
+		}
+
+		let (postal_code, region) = (self.postal_code.as_deref(), self.region.as_deref());
+		let mut lines = self.street.clone();
+
+		match self.country.to_uppercase().as_str()
+		{
+			// The city, then the region and postal code.
+			"AU" => lines.push(join(&[Some(&self.city), region, postal_code], " ")),
+			"CA" | "US" => lines.push(join(&[Some(&self.city), Some(&join(&[region, postal_code], " "))], ", ")),
+
+			// The city, with the postal code on its own line.
+			"GB" | "IE" => lines.extend([Some(self.city.as_str()), region, postal_code].iter().flatten().map(|l| l.to_string())),
+
+			// The postal code, then the city. The region follows, if there is one.
+			_ =>
+			{
+				lines.push(join(&[postal_code, Some(&self.city)], " "));
+				lines.extend(region.map(String::from));
+			},
+		};
+
+		lines.push(self.country.to_uppercase());
+		lines.retain(|l| !l.is_empty());
+		lines
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use
+	{
+		std::time::Instant,
+
+		super::PostalAddress,
+	};
+
+	#[test]
+	fn lines()
+	{
+		let mut address = PostalAddress
+		{
+			city: "Phoenix".into(),
+			country: "us".into(),
+			postal_code: Some("85004".into()),
+			region: Some("AZ".into()),
+			street: vec!["1337 Some Street".into(), "Suite 200".into()],
+		};
+
+		let start = Instant::now();
+		assert_eq!(address.lines(), ["1337 Some Street", "Suite 200", "Phoenix, AZ 85004", "US"]);
+
+		address.postal_code = None;
+		address.region = None;
+		assert_eq!(address.lines(), ["1337 Some Street", "Suite 200", "Phoenix", "US"]);
+
+		address.city = "Berlin".into();
+		address.country = "DE".into();
+		address.postal_code = Some("10117".into());
+		address.region = None;
+		address.street = vec!["Unter den Linden 1".into()];
+		assert_eq!(address.lines(), ["Unter den Linden 1", "10117 Berlin", "DE"]);
+
+		address.city = "London".into();
+		address.country = "GB".into();
+		address.postal_code = Some("SW1A 1AA".into());
+		address.street = vec!["10 Downing Street".into()];
+		assert_eq!(address.lines(), ["10 Downing Street", "London", "SW1A 1AA", "GB"]);
+
+		address.postal_code = None;
+		assert_eq!(address.lines(), ["10 Downing Street", "London", "GB"]);
+		println!("\n>>>>> PostalAddress::lines {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / 5);
+	}
+}
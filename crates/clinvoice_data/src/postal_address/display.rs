@@ -0,0 +1,14 @@
+use
+{
+	core::fmt::{Display, Formatter, Result},
+
+	super::PostalAddress,
+};
+
+impl Display for PostalAddress
+{
+	fn fmt(&self, formatter: &mut Formatter) -> Result
+	{
+		write!(formatter, "{}", self.lines().join(", "))
+	}
+}
@@ -0,0 +1,45 @@
+use
+{
+	crate::{OvertimeRule, Timesheet},
+	clinvoice_finance::{Decimal, Money},
+};
+
+/// # Summary
+///
+/// Some of the hours of a [`Timesheet`] which are billed at the same rate, as found by
+/// [`Job::priced_hours`](crate::Job::priced_hours).
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct PricedHours<'job>
+{
+	/// # Summary
+	///
+	/// The number of hours.
+	pub hours: Decimal,
+
+	/// # Summary
+	///
+	/// The [`OvertimeRule`] which applies to the [`PricedHours::hours`], if any.
+	pub overtime: Option<&'job OvertimeRule>,
+
+	/// # Summary
+	///
+	/// The rate per hour, before the [`PricedHours::overtime`] is applied.
+	pub rate: Money,
+
+	/// # Summary
+	///
+	/// The [`Timesheet`] that the [`PricedHours::hours`] were worked on.
+	pub timesheet: &'job Timesheet,
+}
+
+impl PricedHours<'_>
+{
+	/// # Summary
+	///
+	/// Get the multiple of the [`PricedHours::rate`] which is billed for the
+	/// [`PricedHours::hours`], according to their [`PricedHours::overtime`].
+	pub fn multiplier(&self) -> Decimal
+	{
+		self.overtime.map(|o| o.multiplier()).unwrap_or_else(|| 1.into())
+	}
+}
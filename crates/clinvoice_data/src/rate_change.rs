@@ -0,0 +1,40 @@
+mod display;
+
+use
+{
+	crate::Id,
+	clinvoice_finance::Money,
+
+	chrono::{DateTime, Utc},
+};
+
+#[cfg(feature="serde_support")]
+use serde::{Deserialize, Serialize};
+
+/// # Summary
+///
+/// A `RateChange` is a new rate which a [`Job`](crate::Job) that is billed by the hour is billed
+/// at from some date onwards, either for all of its work or for that of one
+/// [`Employee`](crate::Employee).
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature="serde_support", derive(Deserialize, Serialize))]
+pub struct RateChange
+{
+	/// # Summary
+	///
+	/// The date from which the [`RateChange::rate`] is billed.
+	pub effective_from: DateTime<Utc>,
+
+	/// # Summary
+	///
+	/// The [`Id`] of the [`Employee`](crate::Employee) whose work the [`RateChange::rate`] is billed
+	/// for. When [`None`], it is billed for the work of everyone who does not have a rate in the
+	/// [`Job::employee_rates`](crate::Job::employee_rates).
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub employee_id: Option<Id>,
+
+	/// # Summary
+	///
+	/// The rate which is billed per hour.
+	pub rate: Money,
+}
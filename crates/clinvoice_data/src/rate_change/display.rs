@@ -0,0 +1,65 @@
+use
+{
+	core::fmt::{Display, Formatter, Result},
+
+	super::RateChange,
+
+	chrono::{DateTime, Local},
+};
+
+impl Display for RateChange
+{
+	fn fmt(&self, formatter: &mut Formatter) -> Result
+	{
+		write!(formatter, "{} from {}", self.rate, DateTime::<Local>::from(self.effective_from).naive_local())?;
+
+		if let Some(id) = self.employee_id
+		{
+			write!(formatter, " for employee {}", id)?;
+		}
+
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use
+	{
+		std::time::Instant,
+
+		super::{DateTime, Local, RateChange},
+		crate::Id,
+		clinvoice_finance::{Currency, Money},
+
+		chrono::Utc,
+	};
+
+	#[test]
+	fn display()
+	{
+		let rate_change = RateChange
+		{
+			effective_from: Utc::now(),
+			employee_id: None,
+			rate: Money::new(25_00, 2, Currency::USD),
+		};
+
+		let employee_rate_change = RateChange {employee_id: Some(Id::new_v4()), ..rate_change.clone()};
+
+		let start = Instant::now();
+		assert_eq!(
+			format!("{}", rate_change),
+			format!("25.00 USD from {}", DateTime::<Local>::from(rate_change.effective_from).naive_local()),
+		);
+		assert_eq!(
+			format!("{}", employee_rate_change),
+			format!("25.00 USD from {} for employee {}",
+				DateTime::<Local>::from(rate_change.effective_from).naive_local(),
+				employee_rate_change.employee_id.unwrap(),
+			),
+		);
+		println!("\n>>>>> RateChange::fmt {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / 2);
+	}
+}
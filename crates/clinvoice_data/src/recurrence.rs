@@ -0,0 +1,96 @@
+mod display;
+
+use chrono::{Datelike, DateTime, Local, NaiveDate, TimeZone, Utc};
+
+#[cfg(feature="serde_support")]
+use serde::{Deserialize, Serialize};
+
+/// # Summary
+///
+/// How often a [`Retainer`](crate::Retainer) is billed.
+#[derive(Copy, Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature="serde_support", derive(Deserialize, Serialize))]
+pub enum Recurrence
+{
+	/// # Summary
+	///
+	/// Every month, on the same day of the month (or the last day, for shorter months).
+	Monthly,
+
+	/// # Summary
+	///
+	/// Every three months.
+	Quarterly,
+
+	/// # Summary
+	///
+	/// Every seven days.
+	Weekly,
+
+	/// # Summary
+	///
+	/// Every year, on the same day of the year.
+	Yearly,
+}
+
+impl Recurrence
+{
+	/// # Summary
+	///
+	/// Get the beginning of the `n`th period after some `start`, where `start` is the beginning of
+	/// the first period.
+	///
+	/// # Remarks
+	///
+	/// Months are found in the user's local time.
+	pub fn nth(&self, start: DateTime<Utc>, n: u32) -> DateTime<Utc>
+	{
+		let months = match self
+		{
+			Self::Monthly => n,
+			Self::Quarterly => n * 3,
+			Self::Weekly => return start + chrono::Duration::weeks(n.into()),
+			Self::Yearly => n * 12,
+		};
+
+		let local = DateTime::<Local>::from(start);
+		let month0 = local.month0() + months;
+		let year = local.year() + (month0 / 12) as i32;
+		let month = month0 % 12 + 1;
+
+		// The same day of the month, unless the month is too short.
+		let date = (1..=local.day()).rev().find_map(|day| NaiveDate::from_ymd_opt(year, month, day)).unwrap();
+		let naive = date.and_time(local.time());
+
+		Local.from_local_datetime(&naive).earliest()
+			.map(|d| d.with_timezone(&Utc))
+			.unwrap_or_else(|| Utc.from_utc_datetime(&naive))
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use
+	{
+		std::time::Instant,
+
+		super::{Local, Recurrence, TimeZone, Utc},
+	};
+
+	#[test]
+	fn nth()
+	{
+		let start = Local.ymd(2021, 1, 31).and_hms(9, 0, 0).with_timezone(&Utc);
+		let local = |year, month, day| Local.ymd(year, month, day).and_hms(9, 0, 0).with_timezone(&Utc);
+
+		let instant = Instant::now();
+		assert_eq!(Recurrence::Monthly.nth(start, 0), start);
+		assert_eq!(Recurrence::Monthly.nth(start, 1), local(2021, 2, 28));
+		assert_eq!(Recurrence::Monthly.nth(start, 2), local(2021, 3, 31));
+		assert_eq!(Recurrence::Quarterly.nth(start, 5), local(2022, 4, 30));
+		assert_eq!(Recurrence::Weekly.nth(start, 2), start + chrono::Duration::weeks(2));
+		assert_eq!(Recurrence::Yearly.nth(start, 3), local(2024, 1, 31));
+		println!("\n>>>>> Recurrence::nth {}us <<<<<\n", Instant::now().duration_since(instant).as_micros() / 6);
+	}
+}
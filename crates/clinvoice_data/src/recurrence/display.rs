@@ -0,0 +1,20 @@
+use
+{
+	core::fmt::{Display, Formatter, Result},
+
+	super::Recurrence,
+};
+
+impl Display for Recurrence
+{
+	fn fmt(&self, formatter: &mut Formatter) -> Result
+	{
+		write!(formatter, "{}", match self
+		{
+			Self::Monthly => "month",
+			Self::Quarterly => "quarter",
+			Self::Weekly => "week",
+			Self::Yearly => "year",
+		})
+	}
+}
@@ -0,0 +1,53 @@
+mod display;
+
+use
+{
+	crate::{Recurrence, RetainerPeriod, Rollover},
+	clinvoice_finance::{Decimal, Money},
+};
+
+#[cfg(feature="serde_support")]
+use serde::{Deserialize, Serialize};
+
+/// # Summary
+///
+/// An amount which a client pays every period (e.g. every month) to have some hours of work done.
+/// Work beyond those hours is billed at an hourly rate.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature="serde_support", derive(Deserialize, Serialize))]
+pub struct Retainer
+{
+	/// # Summary
+	///
+	/// The amount which is billed every [`Retainer::period`].
+	pub amount: Money,
+
+	/// # Summary
+	///
+	/// The number of hours of work which the [`Retainer::amount`] covers every
+	/// [`Retainer::period`].
+	pub included_hours: Decimal,
+
+	/// # Summary
+	///
+	/// The rate which is billed per hour for work beyond the [`Retainer::included_hours`].
+	pub overage_rate: Money,
+
+	/// # Summary
+	///
+	/// How often the [`Retainer`] is billed.
+	pub period: Recurrence,
+
+	/// # Summary
+	///
+	/// The periods which have been billed, in order.
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub periods_billed: Vec<RetainerPeriod>,
+
+	/// # Summary
+	///
+	/// What happens to the [`Retainer::included_hours`] which were not worked by the end of a
+	/// [`Retainer::period`].
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub rollover: Rollover,
+}
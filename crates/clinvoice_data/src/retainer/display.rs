@@ -0,0 +1,54 @@
+use
+{
+	core::fmt::{Display, Formatter, Result},
+
+	super::Retainer,
+};
+
+impl Display for Retainer
+{
+	fn fmt(&self, formatter: &mut Formatter) -> Result
+	{
+		writeln!(formatter, "Retainer: {} per {}", self.amount, self.period)?;
+		writeln!(formatter, "\tIncluded Hours: {}", self.included_hours)?;
+		writeln!(formatter, "\tOverage Rate: {}", self.overage_rate)?;
+		write!(formatter, "\tRollover: {}", self.rollover)
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use
+	{
+		std::time::Instant,
+
+		super::Retainer,
+		crate::{Recurrence, Rollover},
+		clinvoice_finance::{Currency, Decimal, Money},
+	};
+
+	#[test]
+	fn display()
+	{
+		let retainer = Retainer
+		{
+			amount: Money::new(80_00, 2, Currency::USD),
+			included_hours: Decimal::new(10, 0),
+			overage_rate: Money::new(12_00, 2, Currency::USD),
+			period: Recurrence::Monthly,
+			periods_billed: Vec::new(),
+			rollover: Rollover::Capped(Decimal::new(5, 0)),
+		};
+
+		let start = Instant::now();
+		assert_eq!(
+			format!("{}", retainer),
+"Retainer: 80.00 USD per month
+	Included Hours: 10
+	Overage Rate: 12.00 USD
+	Rollover: up to 5 hours",
+		);
+		println!("\n>>>>> Retainer::fmt {}us <<<<<\n", Instant::now().duration_since(start).as_micros());
+	}
+}
@@ -0,0 +1,32 @@
+use
+{
+	crate::Id,
+
+	chrono::{DateTime, Utc},
+};
+
+#[cfg(feature="serde_support")]
+use serde::{Deserialize, Serialize};
+
+/// # Summary
+///
+/// A period of a [`Retainer`](crate::Retainer) which has been billed.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature="serde_support", derive(Deserialize, Serialize))]
+pub struct RetainerPeriod
+{
+	/// # Summary
+	///
+	/// When the period began.
+	pub begin: DateTime<Utc>,
+
+	/// # Summary
+	///
+	/// When the period ended.
+	pub end: DateTime<Utc>,
+
+	/// # Summary
+	///
+	/// The [`Id`] of the [`Invoice`](crate::Invoice) which the period was billed on.
+	pub invoice_id: Id,
+}
@@ -0,0 +1,73 @@
+mod display;
+
+use clinvoice_finance::Decimal;
+
+#[cfg(feature="serde_support")]
+use serde::{Deserialize, Serialize};
+
+/// # Summary
+///
+/// What happens to the [included hours](crate::Retainer::included_hours) of a
+/// [`Retainer`](crate::Retainer) which were not worked by the end of a period.
+#[derive(Copy, Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature="serde_support", derive(Deserialize, Serialize))]
+pub enum Rollover
+{
+	/// # Summary
+	///
+	/// The unused hours are added to the next period, up to some number of hours.
+	Capped(Decimal),
+
+	/// # Summary
+	///
+	/// The unused hours are lost.
+	#[default]
+	Expire,
+
+	/// # Summary
+	///
+	/// All of the unused hours are added to the next period.
+	Unlimited,
+}
+
+impl Rollover
+{
+	/// # Summary
+	///
+	/// Get the number of hours which roll over into the next period, when some number of hours
+	/// were `available` and some were `used`.
+	pub fn carried(&self, available: Decimal, used: Decimal) -> Decimal
+	{
+		let unused = (available - used).max(Decimal::default());
+		match self
+		{
+			Self::Capped(cap) => unused.min(*cap),
+			Self::Expire => Decimal::default(),
+			Self::Unlimited => unused,
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use
+	{
+		std::time::Instant,
+
+		super::{Decimal, Rollover},
+	};
+
+	#[test]
+	fn carried()
+	{
+		let (available, used) = (Decimal::new(10, 0), Decimal::new(4, 0));
+
+		let start = Instant::now();
+		assert_eq!(Rollover::Capped(Decimal::new(5, 0)).carried(available, used), Decimal::new(5, 0));
+		assert_eq!(Rollover::Expire.carried(available, used), Decimal::default());
+		assert_eq!(Rollover::Unlimited.carried(available, used), Decimal::new(6, 0));
+		assert_eq!(Rollover::Unlimited.carried(used, available), Decimal::default());
+		println!("\n>>>>> Rollover::carried {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / 4);
+	}
+}
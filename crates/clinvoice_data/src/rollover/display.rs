@@ -0,0 +1,19 @@
+use
+{
+	core::fmt::{Display, Formatter, Result},
+
+	super::Rollover,
+};
+
+impl Display for Rollover
+{
+	fn fmt(&self, formatter: &mut Formatter) -> Result
+	{
+		match self
+		{
+			Self::Capped(hours) => write!(formatter, "up to {} hours", hours),
+			Self::Expire => write!(formatter, "none"),
+			Self::Unlimited => write!(formatter, "unlimited"),
+		}
+	}
+}
@@ -0,0 +1,62 @@
+mod display;
+
+use clinvoice_finance::Decimal;
+
+#[cfg(feature="serde_support")]
+use serde::{Deserialize, Serialize};
+
+/// # Summary
+///
+/// A `Tax` which applies to some work or [`Expense`](crate::Expense) on an
+/// [`Invoice`](crate::Invoice).
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature="serde_support", derive(Deserialize, Serialize))]
+pub enum Tax
+{
+	/// # Summary
+	///
+	/// No tax is charged, because the work is exempt for some `reason`.
+	Exempt
+	{
+		/// # Summary
+		///
+		/// Why the work is exempt from tax, e.g. the relevant section of law.
+		reason: String,
+	},
+
+	/// # Summary
+	///
+	/// A tax, such as VAT or sales tax, which is charged at some `percent` of the net amount.
+	Rate
+	{
+		/// # Summary
+		///
+		/// The name of the tax, e.g. "VAT".
+		name: String,
+
+		/// # Summary
+		///
+		/// The percentage of the net amount which is charged, e.g. `19` for 19%.
+		percent: Decimal,
+	},
+
+	/// # Summary
+	///
+	/// No tax is charged, because the client must account for it themselves.
+	ReverseCharge,
+}
+
+impl Tax
+{
+	/// # Summary
+	///
+	/// Get the amount of tax which is charged on some `net` amount.
+	pub fn on(&self, net: Decimal) -> Decimal
+	{
+		match self
+		{
+			Self::Exempt {..} | Self::ReverseCharge => Decimal::new(0, 0),
+			Self::Rate {percent, ..} => net * percent / Decimal::new(100, 0),
+		}
+	}
+}
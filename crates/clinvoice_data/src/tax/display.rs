@@ -0,0 +1,42 @@
+use
+{
+	core::fmt::{Display, Formatter, Result},
+
+	super::Tax,
+};
+
+impl Display for Tax
+{
+	fn fmt(&self, formatter: &mut Formatter) -> Result
+	{
+		match self
+		{
+			Self::Exempt {reason} => write!(formatter, "Exempt ({})", reason),
+			Self::Rate {name, percent} => write!(formatter, "{} {}%", name, percent),
+			Self::ReverseCharge => write!(formatter, "Reverse charge"),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use
+	{
+		std::time::Instant,
+
+		super::Tax,
+		clinvoice_finance::Decimal,
+	};
+
+	#[test]
+	fn display()
+	{
+		let start = Instant::now();
+		assert_eq!(format!("{}", Tax::Rate {name: "VAT".into(), percent: Decimal::new(19, 0)}), "VAT 19%");
+		assert_eq!(format!("{}", Tax::Rate {name: "Sales Tax".into(), percent: Decimal::new(725, 2)}), "Sales Tax 7.25%");
+		assert_eq!(format!("{}", Tax::Exempt {reason: "§ 4 Nr. 14 UStG".into()}), "Exempt (§ 4 Nr. 14 UStG)");
+		assert_eq!(format!("{}", Tax::ReverseCharge), "Reverse charge");
+		println!("\n>>>>> Tax::fmt {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / 4);
+	}
+}
@@ -0,0 +1,49 @@
+use
+{
+	crate::Tax,
+
+	clinvoice_finance::Money,
+};
+
+/// # Summary
+///
+/// The amount of some [`Tax`] which is charged on an [`Invoice`](crate::Invoice).
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct TaxTotal
+{
+	/// # Summary
+	///
+	/// The amount of tax which is charged.
+	pub amount: Money,
+
+	/// # Summary
+	///
+	/// The net amount which the [`Tax`] applies to.
+	pub base: Money,
+
+	/// # Summary
+	///
+	/// The [`Tax`] which was applied.
+	pub tax: Tax,
+}
+
+impl TaxTotal
+{
+	/// # Summary
+	///
+	/// Charge the `applied` [`Tax`]es on some `amount`, by adding it to the
+	/// [`base`](TaxTotal::base) of each of the `tax_totals` for them. A [`TaxTotal`] is added for
+	/// any of the `applied` [`Tax`]es which is not in the `tax_totals` yet.
+	///
+	/// # Remarks
+	///
+	/// The [`amount`](TaxTotal::amount) of each [`TaxTotal`] is left as-is.
+	pub(crate) fn charge(tax_totals: &mut Vec<Self>, amount: Money, applied: &[Tax])
+	{
+		applied.iter().for_each(|tax| match tax_totals.iter_mut().find(|t| &t.tax == tax)
+		{
+			Some(total) => total.base.amount += amount.amount,
+			_ => tax_totals.push(TaxTotal {amount: Money {amount: Default::default(), ..amount}, base: amount, tax: tax.clone()}),
+		});
+	}
+}
@@ -0,0 +1,57 @@
+use
+{
+	std::collections::BTreeMap,
+
+	crate::{ExpenseCategory, Tax},
+};
+
+#[cfg(feature="serde_support")]
+use serde::{Deserialize, Serialize};
+
+/// # Summary
+///
+/// The [`Tax`]es which apply to the work and [`Expense`](crate::Expense)s on an
+/// [`Invoice`](crate::Invoice).
+#[derive(Clone, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature="serde_support", derive(Deserialize, Serialize))]
+pub struct Taxes
+{
+	/// # Summary
+	///
+	/// The [`Tax`]es on [`Expense`](crate::Expense)s of some [`ExpenseCategory`] (by its
+	/// [code](ExpenseCategory::code)), instead of the [`Taxes::rates`].
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub expenses: BTreeMap<String, Vec<Tax>>,
+
+	/// # Summary
+	///
+	/// The [`Tax`]es on work, and any [`Expense`](crate::Expense) which is not in
+	/// [`Taxes::expenses`] and whose [`ExpenseCategory`] has no [`Tax`]es of its own.
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub rates: Vec<Tax>,
+}
+
+impl Taxes
+{
+	/// # Summary
+	///
+	/// Get the [`Tax`]es on an [`Expense`](crate::Expense) of some `category`.
+	///
+	/// # Returns
+	///
+	/// * The [`Taxes::expenses`] of the `category`, if there are any.
+	/// * Otherwise, the [`ExpenseCategory::taxes`], if there are any.
+	/// * Otherwise, the [`Taxes::rates`].
+	pub fn for_expense<'taxes>(&'taxes self, category: &'taxes ExpenseCategory) -> &'taxes [Tax]
+	{
+		self.expenses.get(&category.code).or(category.taxes.as_ref()).unwrap_or(&self.rates)
+	}
+
+	/// # Summary
+	///
+	/// Whether there are no [`Tax`]es at all.
+	pub fn is_empty(&self) -> bool
+	{
+		self.rates.is_empty() && self.expenses.values().all(Vec::is_empty)
+	}
+}
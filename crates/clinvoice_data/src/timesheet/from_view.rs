@@ -10,8 +10,11 @@ impl From<View> for Timesheet
 	{
 		Self
 		{
+			billable: view.billable,
 			employee_id: view.employee.id,
 			expenses: view.expenses,
+			hourly_rate: view.hourly_rate,
+			invoice_id: view.invoice_id,
 			time_begin: view.time_begin,
 			time_end: view.time_end,
 			work_notes: view.work_notes,
@@ -26,8 +29,11 @@ impl From<&View> for Timesheet
 	{
 		Self
 		{
+			billable: view.billable,
 			employee_id: view.employee.id,
 			expenses: view.expenses.clone(),
+			hourly_rate: view.hourly_rate,
+			invoice_id: view.invoice_id,
 			time_begin: view.time_begin,
 			time_end: view.time_end,
 			work_notes: view.work_notes.clone(),
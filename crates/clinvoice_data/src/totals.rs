@@ -0,0 +1,39 @@
+mod display;
+
+use
+{
+	crate::{AdjustmentTotal, TaxTotal},
+
+	clinvoice_finance::Money,
+};
+
+/// # Summary
+///
+/// The amounts which are owed on an [`Invoice`](crate::Invoice), before and after tax.
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Totals
+{
+	/// # Summary
+	///
+	/// The amount of each [`Adjustment`](crate::Adjustment) which is applied, in the order which
+	/// they were made.
+	pub adjustments: Vec<AdjustmentTotal>,
+
+	/// # Summary
+	///
+	/// The amount owed after [`Totals::taxes`], and any [`Totals::adjustments`] which are applied
+	/// after tax.
+	pub gross: Money,
+
+	/// # Summary
+	///
+	/// The amount owed before [`Totals::taxes`], including any [`Totals::adjustments`] which are
+	/// applied before tax.
+	pub net: Money,
+
+	/// # Summary
+	///
+	/// The amount of each [`Tax`](crate::Tax) which is charged, in the order which they were
+	/// first applied.
+	pub taxes: Vec<TaxTotal>,
+}
@@ -0,0 +1,105 @@
+use
+{
+	core::fmt::{Display, Formatter, Result},
+
+	super::Totals,
+	crate::{AdjustmentAmount, Tax},
+};
+
+impl Display for Totals
+{
+	fn fmt(&self, formatter: &mut Formatter) -> Result
+	{
+		let write_adjustments = |formatter: &mut Formatter, before_tax: bool| self.adjustments.iter()
+			.filter(|a| a.adjustment.before_tax == before_tax)
+			.try_for_each(|a| match a.adjustment.amount
+			{
+				AdjustmentAmount::Percent(percent) => writeln!(formatter, "{} ({}%): {}", a.adjustment.description, percent, a.amount),
+				_ => writeln!(formatter, "{}: {}", a.adjustment.description, a.amount),
+			});
+
+		write_adjustments(formatter, true)?;
+		writeln!(formatter, "Net: {}", self.net)?;
+
+		self.taxes.iter().try_for_each(|t| match t.tax
+		{
+			Tax::Rate {..} => writeln!(formatter, "{}: {} on {}", t.tax, t.amount, t.base),
+			_ => writeln!(formatter, "{}: {}", t.tax, t.base),
+		})?;
+
+		write_adjustments(formatter, false)?;
+		write!(formatter, "Gross: {}", self.gross)
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use
+	{
+		std::time::Instant,
+
+		super::Totals,
+		crate::{Adjustment, AdjustmentAmount, AdjustmentTotal, Tax, TaxTotal},
+
+		clinvoice_finance::{Currency, Decimal, Money},
+	};
+
+	#[test]
+	fn display()
+	{
+		let totals = Totals
+		{
+			adjustments: vec![
+				AdjustmentTotal
+				{
+					adjustment: Adjustment
+					{
+						amount: AdjustmentAmount::Fixed(Decimal::new(10, 0)),
+						before_tax: false,
+						description: "Rush fee".into(),
+						invoice_id: None,
+					},
+					amount: Money::new(10_00, 2, Currency::EUR),
+				},
+				AdjustmentTotal
+				{
+					adjustment: Adjustment
+					{
+						amount: AdjustmentAmount::Percent(Decimal::new(-10, 0)),
+						before_tax: true,
+						description: "Loyalty discount".into(),
+						invoice_id: None,
+					},
+					amount: Money::new(-5_00, 2, Currency::EUR),
+				},
+			],
+			gross: Money::new(63_55, 2, Currency::EUR),
+			net: Money::new(45_00, 2, Currency::EUR),
+			taxes: vec![
+				TaxTotal
+				{
+					amount: Money::new(8_55, 2, Currency::EUR),
+					base: Money::new(45_00, 2, Currency::EUR),
+					tax: Tax::Rate {name: "VAT".into(), percent: Decimal::new(19, 0)},
+				},
+				TaxTotal
+				{
+					amount: Money::new(0, 2, Currency::EUR),
+					base: Money::new(45_00, 2, Currency::EUR),
+					tax: Tax::ReverseCharge,
+				},
+			],
+		};
+
+		let start = Instant::now();
+		assert_eq!(format!("{}", totals),
+"Loyalty discount (-10%): -5.00 EUR
+Net: 45.00 EUR
+VAT 19%: 8.55 EUR on 45.00 EUR
+Reverse charge: 45.00 EUR
+Rush fee: 10.00 EUR
+Gross: 63.55 EUR");
+		println!("\n>>>>> Totals::fmt {}us <<<<<\n", Instant::now().duration_since(start).as_micros());
+	}
+}
@@ -0,0 +1,26 @@
+use super::{BillingContactView, LocationView};
+
+#[cfg(feature="serde_support")]
+use serde::{Deserialize, Serialize};
+
+/// # Summary
+///
+/// A view of [`BillTo`](crate::BillTo).
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature="serde_support", derive(Deserialize, Serialize))]
+pub struct BillToView
+{
+	/// # Summary
+	///
+	/// The person at the client which [`Invoice`](crate::Invoice)s are addressed to (e.g. their
+	/// accounts payable department).
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub contact: Option<BillingContactView>,
+
+	/// # Summary
+	///
+	/// The [`Location`](crate::Location) which [`Invoice`](crate::Invoice)s are sent to, instead
+	/// of that of the client.
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub location: Option<LocationView>,
+}
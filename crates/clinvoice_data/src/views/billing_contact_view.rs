@@ -0,0 +1,65 @@
+mod display;
+
+use super::{ContactView, EmployeeView};
+
+#[cfg(feature="serde_support")]
+use serde::{Deserialize, Serialize};
+
+/// # Summary
+///
+/// A view of [`BillingContact`](crate::BillingContact).
+#[derive(Clone, Debug, Eq, PartialEq)]
+#[cfg_attr(feature="serde_support", derive(Deserialize, Serialize))]
+pub enum BillingContactView
+{
+	/// # Summary
+	///
+	/// An [`Employee`](crate::Employee) of the client.
+	Employee(Box<EmployeeView>),
+
+	/// # Summary
+	///
+	/// Someone who is not recorded as an [`Employee`](crate::Employee) of the client.
+	Other
+	{
+		/// # Summary
+		///
+		/// The email address which [`Invoice`](crate::Invoice)s are sent to.
+		#[cfg_attr(feature="serde_support", serde(default))]
+		email: Option<String>,
+
+		/// # Summary
+		///
+		/// The name of the person or department (e.g. "Accounts Payable").
+		name: String,
+	},
+}
+
+impl BillingContactView
+{
+	/// # Summary
+	///
+	/// The email address which [`Invoice`](crate::Invoice)s are sent to, if there is one.
+	///
+	/// # Remarks
+	///
+	/// For an [`Employee`](BillingContactView::Employee), this is the first email address in
+	/// their contact information (by label) which may be exported.
+	pub fn email(&self) -> Option<&str>
+	{
+		match self
+		{
+			Self::Employee(employee) =>
+			{
+				let mut labels: Vec<_> = employee.contact_info.keys().collect();
+				labels.sort();
+				labels.into_iter().find_map(|label| match &employee.contact_info[label]
+				{
+					ContactView::Email {email, export: true} => Some(email.as_str()),
+					_ => None,
+				})
+			},
+			Self::Other {email, ..} => email.as_deref(),
+		}
+	}
+}
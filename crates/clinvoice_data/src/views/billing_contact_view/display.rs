@@ -0,0 +1,74 @@
+use
+{
+	core::fmt::{Display, Formatter, Result},
+
+	super::BillingContactView,
+};
+
+impl Display for BillingContactView
+{
+	fn fmt(&self, formatter: &mut Formatter) -> Result
+	{
+		match self
+		{
+			Self::Employee(employee) => write!(formatter, "{}, {}", employee.person.name, employee.title),
+			Self::Other {name, ..} => write!(formatter, "{}", name),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use
+	{
+		std::time::Instant,
+
+		super::BillingContactView,
+		crate::
+		{
+			EmployeeStatus, Id,
+			views::{ContactView, EmployeeView, LocationView, OrganizationView, PersonView},
+		},
+	};
+
+	#[test]
+	fn display()
+	{
+		let employee = BillingContactView::Employee(Box::new(EmployeeView
+		{
+			contact_info: vec![
+				("Office".into(), ContactView::Email {email: "office@big.old".into(), export: false}),
+				("Work".into(), ContactView::Email {email: "jane@big.old".into(), export: true}),
+			].into_iter().collect(),
+			employment_changes: Vec::new(),
+			hourly_rate: None,
+			id: Id::new_v4(),
+			organization: OrganizationView
+			{
+				bill_to: Default::default(),
+				expense_markup: Default::default(),
+				id: Id::new_v4(),
+				location: LocationView {address: None, id: Id::new_v4(), name: "Earth".into(), outer: None, taxes: None},
+				name: "Big Old Test".into(),
+				overtime: None,
+				payment_methods: Vec::new(),
+				payment_terms: None,
+				tax_ids: Default::default(),
+				taxes: None,
+			},
+			person: PersonView {id: Id::new_v4(), name: "Jane Doe".into()},
+			status: EmployeeStatus::Representative,
+			title: "Accounts Payable".into(),
+		}));
+
+		let other = BillingContactView::Other {email: None, name: "Accounts Payable".into()};
+
+		let start = Instant::now();
+		assert_eq!(format!("{}", employee), "Jane Doe, Accounts Payable");
+		assert_eq!(employee.email(), Some("jane@big.old"));
+		assert_eq!(format!("{}", other), "Accounts Payable");
+		assert_eq!(other.email(), None);
+		println!("\n>>>>> BillingContactView::fmt {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / 4);
+	}
+}
@@ -28,6 +28,24 @@ pub enum ContactView
 	/// * 'foo@bar.io'
 	Email {email: String, export: bool},
 
+	/// # Summary
+	///
+	/// A fax number.
+	///
+	/// # Example
+	///
+	/// * '+16035551234'
+	Fax {fax: String, export: bool},
+
+	/// # Summary
+	///
+	/// A handle on some messaging service.
+	///
+	/// # Example
+	///
+	/// * '@foo' on 'Telegram'
+	Messaging {handle: String, service: String, export: bool},
+
 	/// # Summary
 	///
 	/// A phone number.
@@ -46,4 +64,13 @@ pub enum ContactView
 	/// * '603-555-1234'
 	/// * '6035551234'
 	Phone {phone: String, export: bool},
+
+	/// # Summary
+	///
+	/// A website.
+	///
+	/// # Example
+	///
+	/// * 'https://foo.io'
+	Website {url: String, export: bool},
 }
@@ -13,7 +13,10 @@ impl Display for ContactView
 		{
 			ContactView::Address {location, export: _} => location.fmt(formatter),
 			ContactView::Email {email, export: _} => write!(formatter, "{}", email),
+			ContactView::Fax {fax, export: _} => write!(formatter, "{} (fax)", fax),
+			ContactView::Messaging {handle, service, export: _} => write!(formatter, "{} on {}", handle, service),
 			ContactView::Phone {phone, export: _} => write!(formatter, "{}", phone),
+			ContactView::Website {url, export: _} => write!(formatter, "{}", url),
 		}
 	}
 }
@@ -37,43 +40,56 @@ mod tests
 	{
 		let earth_view = LocationView
 		{
+			address: None,
 			name: "Earth".into(),
 			id: Id::new_v4(),
 			outer: None,
+			taxes: None,
 		};
 
 		let usa_view = LocationView
 		{
+			address: None,
 			name: "USA".into(),
 			id: Id::new_v4(),
 			outer: Some(earth_view.into()),
+			taxes: None,
 		};
 
 		let arizona_view = LocationView
 		{
+			address: None,
 			name: "Arizona".into(),
 			id: Id::new_v4(),
-			outer: Some(usa_view.into())
+			outer: Some(usa_view.into()),
+			taxes: None,
 		};
 
 		let phoenix_view = LocationView
 		{
+			address: None,
 			name: "Phoenix".into(),
 			id: Id::new_v4(),
 			outer: Some(arizona_view.into()),
+			taxes: None,
 		};
 
 		let street_view = LocationView
 		{
+			address: None,
 			name: "1337 Some Street".into(),
 			id: Id::new_v4(),
 			outer: Some(phoenix_view.into()),
+			taxes: None,
 		};
 
 		let start = Instant::now();
 		assert_eq!(format!("{}", ContactView::Address {location: street_view, export: false}), "1337 Some Street, Phoenix, Arizona, USA, Earth");
 		assert_eq!(format!("{}", ContactView::Email {email: "foo@bar.io".into(), export: false}), "foo@bar.io");
+		assert_eq!(format!("{}", ContactView::Fax {fax: "+16035555555".into(), export: false}), "+16035555555 (fax)");
+		assert_eq!(format!("{}", ContactView::Messaging {handle: "@foo".into(), service: "Telegram".into(), export: false}), "@foo on Telegram");
 		assert_eq!(format!("{}", ContactView::Phone {phone: "1-603-555-5555".into(), export: false}), "1-603-555-5555");
-		println!("\n>>>>> ContactView::test_display {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / 3);
+		assert_eq!(format!("{}", ContactView::Website {url: "https://bar.io".into(), export: false}), "https://bar.io");
+		println!("\n>>>>> ContactView::test_display {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / 6);
 	}
 }
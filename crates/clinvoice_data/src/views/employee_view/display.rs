@@ -3,13 +3,17 @@ use
 	core::fmt::{Display, Formatter, Result},
 
 	super::EmployeeView,
+
+	chrono::Utc,
 };
 
 impl Display for EmployeeView
 {
 	fn fmt(&self, formatter: &mut Formatter) -> Result
 	{
-		writeln!(formatter, "{} {}", self.title, self.person.name)?;
+		let now = Utc::now();
+
+		writeln!(formatter, "{} {}", self.title_on(now), self.person.name)?;
 		writeln!(formatter, "\tEmployer: {}", self.organization)?;
 
 		if !self.contact_info.is_empty()
@@ -21,7 +25,22 @@ impl Display for EmployeeView
 			sorted_employee_contact_info.into_iter().try_for_each(|c| writeln!(formatter, "\t\t- {}: {}", c, self.contact_info[c]))?;
 		}
 
-		write!(formatter, "\tStatus: {}", self.status)
+		if let Some(rate) = self.hourly_rate
+		{
+			writeln!(formatter, "\tHourly Rate: {}", rate)?;
+		}
+
+		if !self.employment_changes.is_empty()
+		{
+			writeln!(formatter, "\tEmployment History:")?;
+			writeln!(formatter, "\t\t- {} as {}", self.status, self.title)?;
+
+			let mut sorted_employment_changes: Vec<_> = self.employment_changes.iter().collect();
+			sorted_employment_changes.sort_by_key(|c| c.effective_from);
+			sorted_employment_changes.into_iter().try_for_each(|c| writeln!(formatter, "\t\t- {}", c))?;
+		}
+
+		write!(formatter, "\tStatus: {}", self.status_on(now))
 	}
 }
 
@@ -45,37 +64,47 @@ mod tests
 	{
 		let earth_view = LocationView
 		{
+			address: None,
 			name: "Earth".into(),
 			id: Id::new_v4(),
 			outer: None,
+			taxes: None,
 		};
 
 		let usa_view = LocationView
 		{
+			address: None,
 			name: "USA".into(),
 			id: Id::new_v4(),
 			outer: Some(earth_view.into()),
+			taxes: None,
 		};
 
 		let arizona_view = LocationView
 		{
+			address: None,
 			name: "Arizona".into(),
 			id: Id::new_v4(),
-			outer: Some(usa_view.into())
+			outer: Some(usa_view.into()),
+			taxes: None,
 		};
 
 		let phoenix_view = LocationView
 		{
+			address: None,
 			name: "Phoenix".into(),
 			id: Id::new_v4(),
 			outer: Some(arizona_view.into()),
+			taxes: None,
 		};
 
 		let work_street_view = LocationView
 		{
+			address: None,
 			name: "1234 Work Street".into(),
 			id: Id::new_v4(),
 			outer: Some(phoenix_view.into()),
+			taxes: None,
 		};
 
 		let employee = EmployeeView
@@ -84,12 +113,21 @@ mod tests
 				("Place of Work".into(), ContactView::Address {location: work_street_view.clone(), export: false}),
 				("Work Email".into(), ContactView::Email {email: "foo@bar.io".into(), export: false}),
 			].into_iter().collect(),
+			employment_changes: Vec::new(),
+			hourly_rate: None,
 			id: Id::new_v4(),
 			organization: OrganizationView
 			{
+				bill_to: Default::default(),
+				expense_markup: Default::default(),
 				id: Id::new_v4(),
 				location: work_street_view,
 				name: "Big Old Test".into(),
+				overtime: None,
+				payment_methods: Vec::new(),
+				payment_terms: None,
+				tax_ids: Default::default(),
+				taxes: None,
 			},
 			person: PersonView
 			{
@@ -3,8 +3,13 @@ mod restorable_serde;
 
 use
 {
+	core::fmt::Display,
+	std::collections::BTreeMap,
+
 	super::{OrganizationView, TimesheetView},
-	crate::{Id, Invoice},
+	crate::{Adjustment, BillingModel, BillTo, ExpenseMarkup, Id, Invoice, Overtime, PaymentTerms, RateChange, Taxes},
+
+	clinvoice_finance::Money,
 
 	chrono::{DateTime, Utc},
 };
@@ -19,6 +24,29 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature="serde_support", derive(Deserialize, Serialize))]
 pub struct JobView
 {
+	/// # Summary
+	///
+	/// Changes to the amount owed for this [`Job`] which are not tied to any work, such as discounts
+	/// or rush fees.
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub adjustments: Vec<Adjustment>,
+
+	/// # Summary
+	///
+	/// Who [`Invoice`]s for this [`Job`] are addressed to, and where they are sent, instead of
+	/// what the `client` specifies.
+	///
+	/// # Remarks
+	///
+	/// Anything which is not set here falls back to that of the client.
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub bill_to: BillTo,
+
+	/// # Summary
+	///
+	/// How the client is charged for the work.
+	pub billing_model: BillingModel,
+
 	/// # Summary
 	///
 	/// The [`Organization`](crate::Organization) who the work is being performed for.
@@ -34,6 +62,41 @@ pub struct JobView
 	/// The [date](DateTime) upon which the client requested the work.
 	pub date_open: DateTime<Utc>,
 
+	/// # Summary
+	///
+	/// The [default rate](crate::Employee::hourly_rate) of each [`Employee`](crate::Employee) (by
+	/// [`Id`]) who has worked on this [`Job`], as it was when they started.
+	///
+	/// # Remarks
+	///
+	/// These are billed instead of the rate of the [`BillingModel::Hourly`], but any rate which was
+	/// set on this [`Job`] takes precedence over them.
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub employee_default_rates: BTreeMap<Id, Money>,
+
+	/// # Summary
+	///
+	/// The rate which each [`Employee`](crate::Employee) (by [`Id`]) bills per hour on this
+	/// [`Job`], instead of the rate of the [`BillingModel::Hourly`].
+	///
+	/// # Remarks
+	///
+	/// A rate on a [`Timesheet`], or any of the `rate_changes` for only that
+	/// [`Employee`](crate::Employee) which has taken effect, takes precedence over these.
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub employee_rates: BTreeMap<Id, Money>,
+
+	/// # Summary
+	///
+	/// How much the [`Expense`](crate::Expense)s on this [`Job`] are marked up by when they are
+	/// billed.
+	///
+	/// # Remarks
+	///
+	/// Copied from the [client](OrganizationView) when the [`Job`] is created.
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub expense_markup: ExpenseMarkup,
+
 	/// # Summary
 	///
 	/// The [`Job`] number.
@@ -42,8 +105,9 @@ pub struct JobView
 
 	/// # Summary
 	///
-	/// The [`Invoice`] which will be sent to the [client](Organization) after the [`Job`] is done.
-	pub invoice: Invoice,
+	/// The [`Invoice`]s which have been sent to the [client](Organization) for the work on this
+	/// [`Job`], in the order they were issued.
+	pub invoices: Vec<Invoice>,
 
 	/// # Summary
 	///
@@ -75,8 +139,77 @@ pub struct JobView
 	/// ```
 	pub objectives: String,
 
+	/// # Summary
+	///
+	/// The [`Overtime`] which applies to the work on this [`Job`] when it is billed by the hour.
+	///
+	/// # Remarks
+	///
+	/// Copied from the [client](crate::Organization) when the [`Job`] is created.
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub overtime: Option<Overtime>,
+
+	/// # Summary
+	///
+	/// Changes to the rate of the [`BillingModel::Hourly`] over time, such as a yearly increase.
+	///
+	/// # Remarks
+	///
+	/// The rate of the [`BillingModel::Hourly`] is billed until the first [`RateChange`] takes
+	/// effect. Once one for only some [`Employee`](crate::Employee) has taken effect, it is billed
+	/// for their work instead of their rate in the `employee_rates`. One for everyone is only
+	/// billed instead of the `employee_default_rates`.
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub rate_changes: Vec<RateChange>,
+
 	/// # Summary
 	///
 	/// The periods of time during which work was performed for this [`Job`].
 	pub timesheets: Vec<TimesheetView>,
 }
+
+impl JobView
+{
+	/// # Summary
+	///
+	/// Get the [`BillTo`] of this [`Job`]: its own, with anything which it does not set being
+	/// filled in by that of the `client`.
+	pub fn applicable_bill_to(&self) -> BillTo
+	{
+		self.bill_to.or(&self.client.bill_to)
+	}
+
+	/// # Summary
+	///
+	/// Get the [`PaymentTerms`] which apply to some `invoice` (or the work which has not been billed
+	/// yet, if [`None`]): the ones which were recorded when it was issued, or else those of the
+	/// `client`, or else some `default`.
+	pub fn applicable_payment_terms(&self, invoice: Option<&Invoice>, default: PaymentTerms) -> PaymentTerms
+	{
+		invoice.and_then(|i| i.payment_terms).or(self.client.payment_terms).unwrap_or(default)
+	}
+
+	/// # Summary
+	///
+	/// Get the [`Taxes`] which apply to some `invoice` (or the work which has not been billed yet, if
+	/// [`None`]): the ones which were recorded when it was issued, or else those of the `client`, or
+	/// else some `default`.
+	pub fn applicable_taxes<'taxes>(&'taxes self, invoice: Option<&'taxes Invoice>, default: &'taxes Taxes) -> &'taxes Taxes
+	{
+		invoice.and_then(|i| i.taxes.as_ref()).or_else(|| self.client.applicable_taxes()).unwrap_or(default)
+	}
+
+	/// # Summary
+	///
+	/// Get a [`Display`] of this [`JobView`] which also shows the total amount owed on each of its
+	/// invoices, and on the work which has not been billed yet.
+	///
+	/// # Parameters
+	///
+	/// * `default_taxes`, the [`Taxes`] which are charged when neither an [`Invoice`] nor the
+	///   `client` specify any (see [`JobView::applicable_taxes`]).
+	pub fn with_totals<'view>(&'view self, default_taxes: &'view Taxes) -> impl Display + 'view
+	{
+		display::WithTotals {default_taxes, job: self}
+	}
+}
@@ -1,16 +1,61 @@
 use
 {
-	core::fmt::{Display, Error, Formatter, Result},
+	core::fmt::{Display, Formatter, Result},
+	std::collections::BTreeMap,
 
 	super::JobView,
-	crate::Job,
+	crate::{Invoice, Job, Taxes},
+	clinvoice_finance::Decimal,
 
-	chrono::{DateTime, Local},
+	chrono::{DateTime, Local, Utc},
 };
 
+/// # Summary
+///
+/// A [`JobView`] which is displayed along with the totals of its invoices.
+///
+/// See [`JobView::with_totals`].
+pub(super) struct WithTotals<'view>
+{
+	/// # Summary
+	///
+	/// The [`Taxes`] which are charged when neither an [`Invoice`] nor the client specify any.
+	pub(super) default_taxes: &'view Taxes,
+
+	/// # Summary
+	///
+	/// The [`JobView`] being displayed.
+	pub(super) job: &'view JobView,
+}
+
 impl Display for JobView
 {
 	fn fmt(&self, formatter: &mut Formatter) -> Result
+	{
+		self.write(formatter, None)
+	}
+}
+
+impl Display for WithTotals<'_>
+{
+	fn fmt(&self, formatter: &mut Formatter) -> Result
+	{
+		self.job.write(formatter, Some(self.default_taxes))
+	}
+}
+
+impl JobView
+{
+	/// # Summary
+	///
+	/// Write this [`JobView`] to some `formatter`. When there are `default_taxes`, the totals of
+	/// each [`Invoice`] (and the work which has not been billed yet) are written as well.
+	///
+	/// # Remarks
+	///
+	/// Totals which cannot be calculated (e.g. because an exchange rate is missing) are written as
+	/// the reason why, so that the rest of the [`JobView`] is still shown.
+	fn write(&self, formatter: &mut Formatter, default_taxes: Option<&Taxes>) -> Result
 	{
 		write!(formatter, "Job #{} for {}: {} – ",
 			self.id,
@@ -32,9 +77,95 @@ impl Display for JobView
 		/// Two indents in, with a newline.
 		const DEPTH_2: &str =  "\n\t\t";
 
-		writeln!(formatter, "\tInvoice:{}{}", DEPTH_2, self.invoice.to_string().replace('\n', DEPTH_2))?;
-		let total = Job::from(self).total().map_err(|_| Error)?;
-		writeln!(formatter, "\t\tTotal Amount Owed: {}", total)?;
+		let job = Job::from(self);
+		let (mut billable_hours, mut hours) = (job.billable_hours(), job.hours());
+		let utilization = if billable_hours < hours { Some(billable_hours / hours * Decimal::new(100, 0)) } else { None };
+		billable_hours.rescale(2);
+		hours.rescale(2);
+
+		writeln!(formatter, "\tBilling:{}{}", DEPTH_2, self.billing_model.to_string().replace('\n', DEPTH_2))?;
+
+		if !self.rate_changes.is_empty()
+		{
+			let mut rate_changes: Vec<_> = self.rate_changes.iter().collect();
+			rate_changes.sort_by_key(|change| change.effective_from);
+
+			writeln!(formatter, "\t\tRate Changes:")?;
+			rate_changes.into_iter().try_for_each(|change| writeln!(formatter, "\t\t\t{}", change))?;
+		}
+
+		if let Some(overtime) = self.overtime.as_ref().filter(|o| !o.rules.is_empty())
+		{
+			writeln!(formatter, "\t\tOvertime:")?;
+			overtime.rules.iter().try_for_each(|rule| writeln!(formatter, "\t\t\t{}", rule))?;
+		}
+
+		// Categories may mark up their expenses themselves, when the job does not say otherwise.
+		let category_markups: BTreeMap<_, _> = self.timesheets.iter().flat_map(|t| t.expenses.iter())
+			.filter(|e| !self.expense_markup.categories.contains_key(&e.category.code))
+			.filter_map(|e| e.category.markup.filter(|m| !m.is_zero()).map(|m| (&e.category.code, m)))
+			.chain(self.expense_markup.categories.iter().map(|(code, markup)| (code, *markup)))
+			.collect();
+
+		if !(self.expense_markup.is_empty() && category_markups.values().all(Decimal::is_zero))
+		{
+			// The default is left out when it is zero, so that it does not read as conflicting with the categories.
+			write!(formatter, "\t\tExpense Markup:")?;
+			if !self.expense_markup.percent.is_zero()
+			{
+				write!(formatter, " {}%", self.expense_markup.percent)?;
+			}
+
+			writeln!(formatter)?;
+			category_markups.into_iter().try_for_each(|(code, markup)| writeln!(formatter, "\t\t\tOn {}: {}%", code, markup))?;
+		}
+
+		writeln!(formatter, "\t\tHours Worked: {}", hours)?;
+
+		if let Some(mut utilization) = utilization
+		{
+			utilization.rescale(0);
+			writeln!(formatter, "\t\tBillable Hours: {} ({}% utilization)", billable_hours, utilization)?;
+		}
+
+		let write_totals = |formatter: &mut Formatter, invoice: Option<&Invoice>, default_taxes: &Taxes| -> Result
+		{
+			let taxes = self.applicable_taxes(invoice, default_taxes);
+			match job.invoice_totals(invoice.map(|i| i.id), taxes)
+			{
+				Ok(totals) if totals.taxes.is_empty() && totals.adjustments.is_empty() =>
+					writeln!(formatter, "\t\tTotal Amount Owed: {}", totals.gross)?,
+				Ok(totals) =>
+				{
+					/// # Summary
+					///
+					/// Three indents in, with a newline.
+					const DEPTH_3: &str =  "\n\t\t\t";
+
+					writeln!(formatter, "\t\tTotal Amount Owed:{}{}", DEPTH_3, totals.to_string().replace('\n', DEPTH_3))?
+				},
+				Err(e) => writeln!(formatter, "\t\tTotal Amount Owed: could not be calculated ({})", e)?,
+			};
+
+			match invoice.filter(|i| !i.payments.is_empty()).map(|i| job.invoice_balance(i, taxes))
+			{
+				Some(Ok(balance)) => writeln!(formatter, "\t\tBalance Due: {}", balance),
+				Some(Err(e)) => writeln!(formatter, "\t\tBalance Due: could not be calculated ({})", e),
+				_ => Ok(()),
+			}
+		};
+
+		self.invoices.iter().try_for_each(|invoice| -> Result
+		{
+			writeln!(formatter, "\tInvoice:{}{}", DEPTH_2, invoice.to_string().replace('\n', DEPTH_2))?;
+			default_taxes.map(|t| write_totals(formatter, Some(invoice), t)).unwrap_or(Ok(()))
+		})?;
+
+		if let Some(taxes) = default_taxes.filter(|_| self.invoices.is_empty() || job.has_unbilled_work(Utc::now()))
+		{
+			writeln!(formatter, "\tUnbilled:")?;
+			write_totals(formatter, None, taxes)?;
+		}
 
 		if !self.objectives.is_empty()
 		{
@@ -66,10 +197,10 @@ mod tests
 		super::{DateTime, JobView, Local},
 		crate::
 		{
-			EmployeeStatus, Id, Invoice, Job,
+			BillingModel, EmployeeStatus, Expense, ExpenseCategory, Id, Invoice, Job, Overtime, OvertimeRule, Tax, Taxes,
 			views::{ContactView, EmployeeView, LocationView, OrganizationView, PersonView, TimesheetView},
 		},
-		clinvoice_finance::{Currency, Money},
+		clinvoice_finance::{Currency, Decimal, Money},
 
 		chrono::Utc,
 	};
@@ -79,20 +210,31 @@ mod tests
 	{
 		let earth_view = LocationView
 		{
+			address: None,
 			id: Id::new_v4(),
 			name: "Earth".into(),
 			outer: None,
+			taxes: None,
 		};
 
 		let ceo_testy_view = EmployeeView
 		{
 			contact_info: vec![("Office".into(), ContactView::Address {location: earth_view.clone(), export: false})].into_iter().collect(),
+			employment_changes: Vec::new(),
+			hourly_rate: None,
 			id: Id::new_v4(),
 			organization: OrganizationView
 			{
+				bill_to: Default::default(),
+				expense_markup: Default::default(),
 				id: Id::new_v4(),
 				location: earth_view.clone(),
 				name: "Big Old Test".into(),
+				overtime: None,
+				payment_methods: Vec::new(),
+				payment_terms: None,
+				tax_ids: Default::default(),
+				taxes: None,
 			},
 			person: PersonView
 			{
@@ -103,23 +245,31 @@ mod tests
 			title: "CEO of Tests".into(),
 		};
 
+		let invoice = Invoice {credit_notes: Vec::new(), date: None, id: Id::new_v4(), number: None, payment_terms: None, payments: Vec::new(), taxes: None};
 		let create_job_view = JobView
 		{
+			adjustments: Vec::new(),
+			bill_to: Default::default(),
+			billing_model: BillingModel::Hourly(Money::new(20_00, 2, Currency::USD)),
 			client: ceo_testy_view.organization.clone(),
 			date_close: Some(Utc::today().and_hms(23, 59, 59)),
 			date_open: Utc::now(),
+			employee_default_rates: Default::default(),
+			employee_rates: Default::default(),
+			expense_markup: Default::default(),
 			id: Id::new_v4(),
-			invoice: Invoice
-			{
-				date: None,
-				hourly_rate: Money::new(20_00, 2, Currency::USD),
-			},
+			invoices: vec![invoice.clone()],
 			notes: "Remember not to work with these guys again!".into(),
 			objectives: "Get into the mainframe, or something like that".into(),
+			overtime: None,
+			rate_changes: Vec::new(),
 			timesheets: vec![TimesheetView
 			{
+				billable: true,
 				employee: ceo_testy_view,
 				expenses: Vec::new(),
+				hourly_rate: None,
+				invoice_id: Some(invoice.id),
 				time_begin: Utc::now(),
 				time_end: Some(Utc::today().and_hms(23, 59, 59)),
 				work_notes: "Went to non-corporate fast food restaurant for business meeting".into(),
@@ -131,10 +281,11 @@ mod tests
 			format!("{}", create_job_view),
 			format!(
 "Job #{} for Big Old Test: {} – {}
-	Invoice:
+	Billing:
 		Hourly Rate: 20.00 USD
+		Hours Worked: {}
+	Invoice:
 		Status: Not issued
-		Total Amount Owed: {}
 	Objectives:
 		Get into the mainframe, or something like that
 	Notes:
@@ -146,11 +297,67 @@ mod tests
 				create_job_view.id,
 				DateTime::<Local>::from(create_job_view.date_open).naive_local(),
 				DateTime::<Local>::from(create_job_view.date_close.unwrap()).naive_local(),
-				Job::from(&create_job_view).total().unwrap(),
+				{
+					let mut hours = Job::from(&create_job_view).hours();
+					hours.rescale(2);
+					hours
+				},
 				DateTime::<Local>::from(create_job_view.timesheets.first().unwrap().time_begin).naive_local(),
 				DateTime::<Local>::from(create_job_view.timesheets.first().unwrap().time_end.unwrap()).naive_local(),
 			),
 		);
 		println!("\n>>>>> JobView::fmt {}us <<<<<\n", Instant::now().duration_since(start).as_micros());
+
+		// The totals are only shown when asked for, with the taxes which were configured.
+		let vat = Taxes {rates: vec![Tax::Rate {name: "VAT".into(), percent: Decimal::new(19, 0)}], ..Default::default()};
+		let job = Job::from(&create_job_view);
+		assert!(create_job_view.with_totals(&Taxes::default()).to_string().contains(&format!(
+			"\t\tStatus: Not issued\n\t\tTotal Amount Owed: {}\n",
+			job.total().unwrap(),
+		)));
+		assert!(create_job_view.with_totals(&vat).to_string().contains(&format!(
+			"\t\tTotal Amount Owed:\n\t\t\tNet: {}\n\t\t\tVAT 19%: ",
+			job.total().unwrap(),
+		)));
+		assert!(!create_job_view.to_string().contains("Total Amount Owed"));
+
+		// A job with a holiday rule, but without any calendar of holidays, still shows its totals.
+		let mut holiday_job_view = create_job_view.clone();
+		holiday_job_view.overtime = Some(Overtime
+		{
+			holidays: Default::default(),
+			rules: vec![OvertimeRule::Holidays {multiplier: Decimal::new(2, 0)}],
+		});
+		assert!(holiday_job_view.with_totals(&Taxes::default()).to_string().contains(&format!(
+			"\t\tTotal Amount Owed: {}\n",
+			Job::from(&holiday_job_view).total().unwrap(),
+		)));
+
+		// An expense whose category marks it up and taxes it shows both, even though the job does not.
+		let mut lodging_job_view = create_job_view.clone();
+		lodging_job_view.timesheets[0].expenses.push(Expense
+		{
+			attachments: Vec::new(),
+			billable: true,
+			category: ExpenseCategory
+			{
+				code: "6100".into(),
+				markup: Some(Decimal::new(10, 0)),
+				name: "Lodging".into(),
+				taxes: Some(vec![Tax::Rate {name: "VAT".into(), percent: Decimal::new(7, 0)}]),
+			},
+			cost: Money::new(95_00, 2, Currency::USD),
+			description: "Hotel".into(),
+			kind: Default::default(),
+		});
+
+		let lodging_job_display = lodging_job_view.with_totals(&Taxes::default()).to_string();
+		assert!(lodging_job_display.contains("\t\tExpense Markup:\n\t\t\tOn 6100: 10%\n"));
+
+		// A default markup is shown on its own line from the categories which differ from it.
+		lodging_job_view.expense_markup.percent = Decimal::new(5, 0);
+		lodging_job_view.expense_markup.categories.insert("6200".into(), Decimal::default());
+		assert!(lodging_job_view.to_string().contains("\t\tExpense Markup: 5%\n\t\t\tOn 6100: 10%\n\t\t\tOn 6200: 0%\n"));
+		assert!(lodging_job_display.contains("\t\t\tNet: "));
 	}
 }
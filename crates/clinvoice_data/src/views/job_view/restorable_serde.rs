@@ -10,5 +10,6 @@ impl RestorableSerde for JobView
 	{
 		self.client.restore(&original.client);
 		self.id = original.id;
+		self.invoices.iter_mut().zip(&original.invoices).for_each(|(edited, o)| edited.id = o.id);
 	}
 }
@@ -1,7 +1,7 @@
 mod display;
 mod restorable_serde;
 
-use crate::Id;
+use crate::{Id, PostalAddress, Taxes};
 
 #[cfg(feature="serde_support")]
 use serde::{Deserialize, Serialize};
@@ -13,6 +13,12 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature="serde_support", derive(Deserialize, Serialize))]
 pub struct LocationView
 {
+	/// # Summary
+	///
+	/// The [`PostalAddress`] of the [`Location`](crate::Location).
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub address: Option<PostalAddress>,
+
 	/// # Summary
 	///
 	/// The reference number of the [`Location`].
@@ -32,6 +38,12 @@ pub struct LocationView
 	///
 	/// The name of the [`Location`].
 	pub name: String,
+
+	/// # Summary
+	///
+	/// The [`Taxes`] which are charged to clients in this [`Location`], instead of those of the
+	/// `outer` [`Location`].
+	pub taxes: Option<Taxes>,
 }
 
 impl LocationView
@@ -39,13 +51,24 @@ impl LocationView
 	/// # Summary
 	///
 	/// Create a new [`LocationView`].
-	pub fn new(id: Id, name: String, outer: Option<&Self>) -> Self
+	pub fn new(address: Option<PostalAddress>, id: Id, name: String, outer: Option<&Self>, taxes: Option<Taxes>) -> Self
 	{
 		Self
 		{
+			address,
 			id,
 			name,
 			outer: outer.map(|l| l.clone().into()),
+			taxes,
 		}
 	}
+
+	/// # Summary
+	///
+	/// Get the [`Taxes`] which are charged to clients in this [`Location`]: either its own, or
+	/// those of the nearest `outer` [`Location`] which has some.
+	pub fn applicable_taxes(&self) -> Option<&Taxes>
+	{
+		self.taxes.as_ref().or_else(|| self.outer.as_ref().and_then(|o| o.applicable_taxes()))
+	}
 }
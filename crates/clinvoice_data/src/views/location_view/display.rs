@@ -9,6 +9,11 @@ impl Display for LocationView
 {
 	fn fmt(&self, formatter: &mut Formatter) -> Result
 	{
+		if let Some(address) = &self.address
+		{
+			return address.fmt(formatter);
+		}
+
 		write!(formatter, "{}", self.name)?;
 
 		let mut outer = &self.outer;
@@ -30,7 +35,7 @@ mod tests
 		std::time::Instant,
 
 		super::LocationView,
-		crate::Id,
+		crate::{Id, PostalAddress},
 	};
 
 	/// # Summary
@@ -41,41 +46,61 @@ mod tests
 	{
 		let earth_view = LocationView
 		{
+			address: None,
 			name: "Earth".into(),
 			id: Id::new_v4(),
 			outer: None,
+			taxes: None,
 		};
 
 		let usa_view = LocationView
 		{
+			address: None,
 			name: "USA".into(),
 			id: Id::new_v4(),
 			outer: Some(earth_view.into()),
+			taxes: None,
 		};
 
 		let arizona_view = LocationView
 		{
+			address: None,
 			name: "Arizona".into(),
 			id: Id::new_v4(),
-			outer: Some(usa_view.into())
+			outer: Some(usa_view.into()),
+			taxes: None,
 		};
 
 		let phoenix_view = LocationView
 		{
+			address: None,
 			name: "Phoenix".into(),
 			id: Id::new_v4(),
 			outer: Some(arizona_view.into()),
+			taxes: None,
 		};
 
-		let street_view = LocationView
+		let mut street_view = LocationView
 		{
+			address: None,
 			name: "1337 Some Street".into(),
 			id: Id::new_v4(),
 			outer: Some(phoenix_view.into()),
+			taxes: None,
 		};
 
 		let start = Instant::now();
 		assert_eq!(format!("{}", street_view), "1337 Some Street, Phoenix, Arizona, USA, Earth");
-		println!("\n>>>>> LocationView::fmt {}us <<<<<\n", Instant::now().duration_since(start).as_micros());
+
+		street_view.address = Some(PostalAddress
+		{
+			city: "Phoenix".into(),
+			country: "US".into(),
+			postal_code: Some("85004".into()),
+			region: Some("AZ".into()),
+			street: vec!["1337 Some Street".into()],
+		});
+		assert_eq!(format!("{}", street_view), "1337 Some Street, Phoenix, AZ 85004, US");
+		println!("\n>>>>> LocationView::fmt {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / 2);
 	}
 }
@@ -4,6 +4,8 @@
 /// crate should look like. These structures differ from their counterparts in typically obvious
 /// ways (e.g. joins have been performed on relational data, and `Display` is implemented).
 
+mod bill_to_view;
+mod billing_contact_view;
 mod contact_view;
 mod employee_view;
 mod job_view;
@@ -15,6 +17,8 @@ mod timesheet_view;
 
 pub use
 {
+	bill_to_view::BillToView,
+	billing_contact_view::BillingContactView,
 	contact_view::ContactView,
 	employee_view::EmployeeView,
 	job_view::JobView,
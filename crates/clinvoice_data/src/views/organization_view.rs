@@ -3,8 +3,10 @@ mod restorable_serde;
 
 use
 {
+	std::collections::BTreeMap,
+
 	super::LocationView,
-	crate::Id,
+	crate::{BillTo, ExpenseMarkup, Id, Overtime, PaymentMethod, PaymentTerms, Taxes},
 };
 
 #[cfg(feature="serde_support")]
@@ -25,6 +27,23 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature="serde_support", derive(Deserialize, Serialize))]
 pub struct OrganizationView
 {
+	/// # Summary
+	///
+	/// Who [`Invoice`](crate::Invoice)s are addressed to, and where they are sent, when this
+	/// [`Organization`] is a client.
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub bill_to: BillTo,
+
+	/// # Summary
+	///
+	/// How much the [`Expense`](crate::Expense)s on work for this client are marked up by.
+	///
+	/// # Remarks
+	///
+	/// Copied to each [`Job`](crate::Job) for this client when it is created.
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub expense_markup: ExpenseMarkup,
+
 	/// # Summary
 	///
 	/// The unique reference number for this [`Organization`].
@@ -41,4 +60,49 @@ pub struct OrganizationView
 	///
 	/// The name of the [`Organization`].
 	pub name: String,
+
+	/// # Summary
+	///
+	/// The [`Overtime`] which applies to [`Job`](crate::Job)s for this [`Organization`] when it is
+	/// a client.
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub overtime: Option<Overtime>,
+
+	/// # Summary
+	///
+	/// The ways which this [`Organization`] can be paid, which are shown on the invoices it issues.
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub payment_methods: Vec<PaymentMethod>,
+
+	/// # Summary
+	///
+	/// The [`PaymentTerms`] which this [`Organization`] is given when it is a client, instead of
+	/// those in the user's configuration.
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub payment_terms: Option<PaymentTerms>,
+
+	/// # Summary
+	///
+	/// The identifiers which this [`Organization`] is registered for taxes with, by kind (e.g.
+	/// `VAT` → `DE123456789`).
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub tax_ids: BTreeMap<String, String>,
+
+	/// # Summary
+	///
+	/// The [`Taxes`] which are charged to this [`Organization`] when it is a client, instead of
+	/// those of its `location`.
+	pub taxes: Option<Taxes>,
+}
+
+impl OrganizationView
+{
+	/// # Summary
+	///
+	/// Get the [`Taxes`] which are charged to this [`Organization`] when it is a client: either its
+	/// own, or those of its `location`.
+	pub fn applicable_taxes(&self) -> Option<&Taxes>
+	{
+		self.taxes.as_ref().or_else(|| self.location.applicable_taxes())
+	}
 }
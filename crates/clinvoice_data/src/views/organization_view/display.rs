@@ -29,24 +29,37 @@ mod tests
 	{
 		let organization = OrganizationView
 		{
+			bill_to: Default::default(),
+			expense_markup: Default::default(),
 			id: Id::new_v4(),
 			location: LocationView
 			{
+				address: None,
 				id: Id::new_v4(),
 				name: "Arizona".into(),
 				outer: Some(LocationView
 				{
+					address: None,
 					id: Id::new_v4(),
 					name: "USA".into(),
 					outer: Some(LocationView
 					{
+						address: None,
 						id: Id::new_v4(),
 						name: "Earth".into(),
 						outer: None,
+						taxes: None,
 					}.into()),
+					taxes: None,
 				}.into()),
+				taxes: None,
 			},
 			name: "Big Old Test".into(),
+			overtime: None,
+			payment_methods: Vec::new(),
+			payment_terms: None,
+			tax_ids: Default::default(),
+			taxes: None,
 		};
 
 		let start = Instant::now();
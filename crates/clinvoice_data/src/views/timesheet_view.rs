@@ -3,7 +3,8 @@ mod display;
 use
 {
 	super::EmployeeView,
-	crate::Expense,
+	crate::{Expense, Id},
+	clinvoice_finance::Money,
 	chrono::{DateTime, Utc},
 };
 
@@ -24,6 +25,18 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature="serde_support", derive(Deserialize, Serialize))]
 pub struct TimesheetView
 {
+	/// # Summary
+	///
+	/// Whether the client is charged for the hours worked during this time.
+	///
+	/// # Remarks
+	///
+	/// The hours of a [`Timesheet`](crate::Timesheet) which is not billable (e.g. an internal
+	/// meeting) are still tracked, but never count towards the amount owed. Its [`Expense`]s may
+	/// still be billable.
+	#[cfg_attr(feature="serde_support", serde(default="crate::default_true"))]
+	pub billable: bool,
+
 	/// # Summary
 	///
 	/// The ID of the [`Employee`](crate::Employee) who performed this work.
@@ -34,6 +47,26 @@ pub struct TimesheetView
 	/// [`Expense`]s which were incurred during this time.
 	pub expenses: Vec<Expense>,
 
+	/// # Summary
+	///
+	/// The rate which is billed per hour for this work, instead of any other rate.
+	///
+	/// # Remarks
+	///
+	/// This only applies to a [`Job`](crate::Job) which is billed by the hour.
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub hourly_rate: Option<Money>,
+
+	/// # Summary
+	///
+	/// The [`Id`](crate::Id) of the [`Invoice`](crate::Invoice) which this work was billed on.
+	///
+	/// # Remarks
+	///
+	/// Work which has not been billed yet has no [`Invoice`](crate::Invoice), and work is only
+	/// ever billed once.
+	pub invoice_id: Option<Id>,
+
 	/// # Summary
 	///
 	/// The time at which this period of work began.
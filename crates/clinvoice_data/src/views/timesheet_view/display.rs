@@ -21,6 +21,16 @@ impl Display for TimesheetView
 
 		const DEPTH_2: &str = "\n\t\t";
 
+		if !self.billable
+		{
+			writeln!(formatter, "\tNon-billable")?;
+		}
+
+		if let Some(rate) = self.hourly_rate
+		{
+			writeln!(formatter, "\tHourly Rate: {}", rate)?;
+		}
+
 		if !self.expenses.is_empty()
 		{
 			writeln!(formatter, "\tExpenses:")?;
@@ -59,37 +69,47 @@ mod tests
 	{
 		let earth_view = LocationView
 		{
+			address: None,
 			name: "Earth".into(),
 			id: Id::new_v4(),
 			outer: None,
+			taxes: None,
 		};
 
 		let usa_view = LocationView
 		{
+			address: None,
 			name: "USA".into(),
 			id: Id::new_v4(),
 			outer: Some(earth_view.into()),
+			taxes: None,
 		};
 
 		let arizona_view = LocationView
 		{
+			address: None,
 			name: "Arizona".into(),
 			id: Id::new_v4(),
-			outer: Some(usa_view.into())
+			outer: Some(usa_view.into()),
+			taxes: None,
 		};
 
 		let phoenix_view = LocationView
 		{
+			address: None,
 			name: "Phoenix".into(),
 			id: Id::new_v4(),
 			outer: Some(arizona_view.into()),
+			taxes: None,
 		};
 
 		let street_view = LocationView
 		{
+			address: None,
 			name: "1337 Some Street".into(),
 			id: Id::new_v4(),
 			outer: Some(phoenix_view.into()),
+			taxes: None,
 		};
 
 		let contact_info: HashMap<String, ContactView> = vec![
@@ -100,15 +120,25 @@ mod tests
 
 		let timesheet =  TimesheetView
 		{
+			billable: true,
 			employee: EmployeeView
 			{
 				contact_info: contact_info.clone(),
+				employment_changes: Vec::new(),
+				hourly_rate: None,
 				id: Id::new_v4(),
 				organization: OrganizationView
 				{
+					bill_to: Default::default(),
+					expense_markup: Default::default(),
 					id: Id::new_v4(),
 					location: street_view,
 					name: "Big Test Organization".into(),
+					overtime: None,
+					payment_methods: Vec::new(),
+					payment_terms: None,
+					tax_ids: Default::default(),
+					taxes: None,
 				},
 				person: PersonView
 				{
@@ -121,20 +151,28 @@ mod tests
 			expenses: vec![
 				Expense
 				{
-					category: ExpenseCategory::Food,
+					attachments: Vec::new(),
+					billable: true,
+					category: ExpenseCategory::new("Food"),
 					cost: Money::new(20_50, 2, Currency::USD),
 					description: "Fast Food™".into(),
+					kind: Default::default(),
 				},
 				Expense
 				{
-					category: ExpenseCategory::Travel,
+					attachments: Vec::new(),
+					billable: false,
+					category: ExpenseCategory::new("Travel"),
 					cost: Money::new(10_00, 2, Currency::USD),
 					description: "Gas".into(),
+					kind: Default::default(),
 				},
 			],
+			hourly_rate: None,
 			time_begin: Utc::now(),
 			time_end: Some(Utc::today().and_hms(23, 59, 59)),
 			work_notes: "Went to non-corporate fast food restaurant for business meeting".into(),
+			invoice_id: None,
 		};
 
 		let start = Instant::now();
@@ -145,7 +183,7 @@ mod tests
 	Expenses:
 		Food – 20.50 USD
 			Fast Food™
-		Travel – 10.00 USD
+		Travel – 10.00 USD (Non-billable)
 			Gas
 	Work Notes:
 		Went to non-corporate fast food restaurant for business meeting",
@@ -12,10 +12,10 @@ use
 
 	clinvoice_data::
 	{
-		chrono::{DateTime, Local},
-		finance::Result as FinanceResult,
-		Id, Job,
-		views::{ContactView, JobView, TimesheetView},
+		chrono::{DateTime, Local, Utc},
+		finance::{Decimal, Money, Result as FinanceResult},
+		AdjustmentAmount, BillingModel, CreditNote, ExpenseKind, ExpenseMarkup, Id, Invoice, Job, OvertimeRule, Tax, Taxes, Totals,
+		views::{BillToView, ContactView, JobView, OrganizationView, TimesheetView},
 	},
 };
 
@@ -41,7 +41,20 @@ impl Target
 	///
 	/// Tracks the previously `exported_employees` so that their contact information is not
 	/// reiterated every time.
-	fn export_timesheet(&self, exported_employees: &mut HashSet<Id>, output: &mut String, timesheet: &TimesheetView)
+	///
+	/// The `rate_periods` are those of [`Job::rate_periods`], so that the rate which was billed for
+	/// each period of the `timesheet` can be shown. Only the billable
+	/// [`Expense`](clinvoice_data::Expense)s are exported, at their cost once the `expense_markup`
+	/// is applied, along with how that cost was calculated (if it was) and the names of any
+	/// receipts attached to them.
+	fn export_timesheet(
+		&self,
+		exported_employees: &mut HashSet<Id>,
+		output: &mut String,
+		timesheet: &TimesheetView,
+		rate_periods: &[(DateTime<Utc>, DateTime<Utc>, Money)],
+		expense_markup: &ExpenseMarkup,
+	)
 	{
 		match self
 		{
@@ -59,6 +72,40 @@ impl Target
 					),
 				}).unwrap();
 
+				match rate_periods
+				{
+					[] => (),
+					[(_, _, rate)] => writeln!(output, "{}: {}",
+						markdown::Element::UnorderedList {depth: 0, text: markdown::Text::Bold("Hourly Rate")},
+						rate,
+					).unwrap(),
+					_ =>
+					{
+						writeln!(output, "{}:", markdown::Element::UnorderedList
+						{
+							depth: 0,
+							text: markdown::Text::Bold("Hourly Rates"),
+						}).unwrap();
+
+						rate_periods.iter().try_for_each(|(begin, end, rate)| writeln!(output, "{}: {}",
+							markdown::Element::UnorderedList
+							{
+								depth: 1,
+								text: markdown::Text::Bold(format!("{} – {}",
+									DateTime::<Local>::from(*begin).naive_local(),
+									DateTime::<Local>::from(*end).naive_local(),
+								)),
+							},
+							rate,
+						)).unwrap();
+					},
+				};
+
+				if !rate_periods.is_empty()
+				{
+					writeln!(output, "{}", markdown::Element::<&str>::Break).unwrap();
+				}
+
 				writeln!(output, "{}", markdown::Element::Heading {depth: 4, text: "Employee Information"}).unwrap();
 				writeln!(output, "{}: {}",
 					markdown::Element::UnorderedList {depth: 0, text: markdown::Text::Bold("Name")},
@@ -70,7 +117,7 @@ impl Target
 				).unwrap();
 				writeln!(output, "{}: {}",
 					markdown::Element::UnorderedList {depth: 0, text: markdown::Text::Bold("Title")},
-					timesheet.employee.title,
+					timesheet.employee.title_on(timesheet.time_begin),
 				).unwrap();
 
 				if exported_employees.contains(&timesheet.employee.id)
@@ -79,7 +126,10 @@ impl Target
 					{
 						ContactView::Address {location: _, export} => *export,
 						ContactView::Email {email: _, export} => *export,
+						ContactView::Fax {fax: _, export} => *export,
+						ContactView::Messaging {export, ..} => *export,
 						ContactView::Phone {phone: _, export} => *export,
+						ContactView::Website {url: _, export} => *export,
 					}).collect();
 
 					if !employee_contact_info.is_empty()
@@ -102,14 +152,28 @@ impl Target
 
 				writeln!(output, "{}", markdown::Element::<&str>::Break).unwrap();
 
-				if !timesheet.expenses.is_empty()
+				let expenses: Vec<_> = timesheet.expenses.iter().filter(|e| e.billable).collect();
+				if !expenses.is_empty()
 				{
 					writeln!(output, "{}", markdown::Element::Heading {depth: 4, text: "Expenses"}).unwrap();
 
-					timesheet.expenses.iter().try_for_each(|e| writeln!(output, "{}\n{}",
-						markdown::Element::Heading {depth: 5, text: format!("{} – {}", e.category, e.cost)},
-						markdown::Element::BlockText(&e.description),
-					)).unwrap();
+					expenses.into_iter().try_for_each(|e| -> core::fmt::Result
+					{
+						writeln!(output, "{}", markdown::Element::Heading {depth: 5, text: format!("{} – {}", e.category, expense_markup.apply(e))})?;
+						if e.kind != ExpenseKind::Cost
+						{
+							writeln!(output, "{}\n", markdown::Text::Italic(&e.kind))?;
+						}
+
+						writeln!(output, "{}", markdown::Element::BlockText(&e.description))?;
+						if !e.attachments.is_empty()
+						{
+							let names: Vec<_> = e.attachments.iter().map(|a| a.name.as_str()).collect();
+							writeln!(output, "{}: {}\n", markdown::Element::UnorderedList {depth: 0, text: markdown::Text::Bold("Receipts")}, names.join(", "))?;
+						}
+
+						Ok(())
+					}).unwrap();
 				}
 
 				if !timesheet.work_notes.is_empty()
@@ -125,8 +189,73 @@ impl Target
 
 	/// # Summary
 	///
-	/// Export some `job` to the [`Target`] specified.
-	pub fn export_job(&self, job: &JobView) -> FinanceResult<String>
+	/// Export some `organization` to some `output`, under a `label` (e.g. "Client"). When the
+	/// location of the `organization` has a [`PostalAddress`](clinvoice_data::PostalAddress), it is
+	/// written as a postal block. Any [`Organization::tax_ids`](clinvoice_data::Organization::tax_ids)
+	/// follow it.
+	///
+	/// When there is a `bill_to`, it is written under "Bill To" instead of the `label`: its contact
+	/// is named, and its location is used instead of that of the `organization`.
+	fn export_organization(&self, output: &mut String, label: &str, organization: &OrganizationView, bill_to: Option<&BillToView>)
+	{
+		match self
+		{
+			#[cfg(feature="markdown")]
+			Self::Markdown =>
+			{
+				let contact = bill_to.and_then(|b| b.contact.as_ref());
+				let location = bill_to.and_then(|b| b.location.as_ref()).unwrap_or(&organization.location);
+
+				let mut lines = match &location.address
+				{
+					Some(address) =>
+					{
+						let mut lines = vec![organization.name.clone()];
+						lines.extend(contact.map(|c| format!("Attn: {}", c)));
+						lines.extend(address.lines());
+						lines
+					},
+					None =>
+					{
+						let mut lines = vec![format!("{} @ {}", organization.name, location)];
+						lines.extend(contact.map(|c| format!("Attn: {}", c)));
+						lines
+					},
+				};
+				lines.extend(contact.and_then(|c| c.email()).map(String::from));
+
+				// Each line ends in a hard line break, and is indented so that it stays in the list item.
+				writeln!(output, "{}: {}",
+					markdown::Element::UnorderedList {depth: 0, text: markdown::Text::Bold(if bill_to.is_some() { "Bill To" } else { label })},
+					lines.join("  \n  "),
+				).unwrap();
+
+				organization.tax_ids.iter().try_for_each(|(kind, id)| writeln!(output, "{}: {}",
+					markdown::Element::UnorderedList {depth: 1, text: markdown::Text::Bold(kind)},
+					id,
+				)).unwrap();
+			},
+		};
+	}
+
+	/// # Summary
+	///
+	/// Export a `credit_note` which corrects some `invoice` of a `job` to the [`Target`]
+	/// specified.
+	///
+	/// The `credit_note` is taxed like the `invoice` it corrects (see
+	/// [`JobView::applicable_taxes`]). When there is an `issuer`, it is shown as who the
+	/// `credit_note` is from. When there is a `bill_to`, it is addressed to that instead of the
+	/// client.
+	pub fn export_credit_note(
+		&self,
+		job: &JobView,
+		invoice: &Invoice,
+		credit_note: &CreditNote,
+		issuer: Option<&OrganizationView>,
+		bill_to: Option<&BillToView>,
+		default_taxes: &Taxes,
+	) -> String
 	{
 		let mut output = String::new();
 
@@ -135,13 +264,93 @@ impl Target
 			#[cfg(feature="markdown")]
 			Self::Markdown =>
 			{
-				writeln!(output, "{}", markdown::Element::Heading {depth: 1, text: format!("Job #{}", job.id)}).unwrap();
+				writeln!(output, "{}", markdown::Element::Heading {depth: 1, text: format!("Credit Note {}", credit_note.number)}).unwrap();
+
+				if let Some(organization) = issuer
+				{
+					self.export_organization(&mut output, "From", organization, None);
+				}
+
+				self.export_organization(&mut output, "Client", &job.client, bill_to);
 
 				writeln!(output, "{}: {}",
-					markdown::Element::UnorderedList {depth: 0, text: markdown::Text::Bold("Client")},
-					job.client,
+					markdown::Element::UnorderedList {depth: 0, text: markdown::Text::Bold("Date Issued")},
+					DateTime::<Local>::from(credit_note.date).naive_local(),
 				).unwrap();
 
+				writeln!(output, "{}: {}",
+					markdown::Element::UnorderedList {depth: 0, text: markdown::Text::Bold("Corrects Invoice")},
+					invoice.number.clone().unwrap_or_else(|| invoice.id.to_string()),
+				).unwrap();
+
+				if !credit_note.reason.is_empty()
+				{
+					writeln!(output, "{}: {}",
+						markdown::Element::UnorderedList {depth: 0, text: markdown::Text::Bold("Reason")},
+						credit_note.reason,
+					).unwrap();
+				}
+
+				writeln!(output, "{}", markdown::Element::<&str>::Break).unwrap();
+
+				let currency = job.billing_model.currency();
+				writeln!(output, "{}", markdown::Element::Heading {depth: 2, text: "Credited"}).unwrap();
+				credit_note.lines.iter().try_for_each(|line| writeln!(output, "{}: {}",
+					markdown::Element::UnorderedList {depth: 0, text: markdown::Text::Bold(&line.description)},
+					Money {amount: line.amount, currency},
+				)).unwrap();
+
+				writeln!(output, "{}", markdown::Element::<&str>::Break).unwrap();
+
+				let totals = credit_note.totals(currency, job.applicable_taxes(Some(invoice), default_taxes));
+				writeln!(output, "{}", markdown::Element::Heading {depth: 2, text: "Total"}).unwrap();
+				self.export_totals(&mut output, &totals, !totals.taxes.is_empty(), "Credited");
+			},
+		};
+
+		output
+	}
+
+	/// # Summary
+	///
+	/// Export some `invoice` of a `job` to the [`Target`] specified, along with the work which was
+	/// billed on it.
+	///
+	/// If there is no `invoice`, the work which has not been billed yet is exported instead.
+	///
+	/// When there is an `issuer`, it is shown as who the `invoice` is from, and its
+	/// [`PaymentMethod`](clinvoice_data::PaymentMethod)s are given as payment instructions. When
+	/// there is a `bill_to` (see [`JobView::applicable_bill_to`]), the `invoice` is addressed to
+	/// that instead of the client.
+	///
+	/// The `default_taxes` are charged if neither the `invoice` nor the `job` have [`Taxes`] of
+	/// their own (see [`JobView::applicable_taxes`]).
+	pub fn export_invoice(
+		&self,
+		job: &JobView,
+		invoice: Option<&Invoice>,
+		issuer: Option<&OrganizationView>,
+		bill_to: Option<&BillToView>,
+		default_taxes: &Taxes,
+	) -> FinanceResult<String>
+	{
+		let invoice_id = invoice.map(|i| i.id);
+		let mut output = String::new();
+
+		match self
+		{
+			#[cfg(feature="markdown")]
+			Self::Markdown =>
+			{
+				writeln!(output, "{}", markdown::Element::Heading {depth: 1, text: format!("Job #{}", job.id)}).unwrap();
+
+				if let Some(organization) = issuer
+				{
+					self.export_organization(&mut output, "From", organization, None);
+				}
+
+				self.export_organization(&mut output, "Client", &job.client, bill_to);
+
 				writeln!(output, "{}: {}",
 					markdown::Element::UnorderedList {depth: 0, text: markdown::Text::Bold("Date Opened")},
 					DateTime::<Local>::from(job.date_open),
@@ -158,12 +367,146 @@ impl Target
 				writeln!(output, "{}", markdown::Element::<&str>::Break).unwrap();
 
 				writeln!(output, "{}", markdown::Element::Heading {depth: 2, text: "Invoice"}).unwrap();
-				writeln!(output, "{} {}",
-					markdown::Element::UnorderedList {depth: 0, text: markdown::Text::Bold("Hourly Rate")},
-					job.invoice.hourly_rate,
-				).unwrap();
 
-				if let Some(date) = &job.invoice.date
+				if let Some(number) = invoice.and_then(|i| i.number.as_ref())
+				{
+					writeln!(output, "{}: {}",
+						markdown::Element::UnorderedList {depth: 0, text: markdown::Text::Bold("Number")},
+						number,
+					).unwrap();
+				}
+
+				match &job.billing_model
+				{
+					BillingModel::FixedPrice(price) => writeln!(output, "{}: {}",
+						markdown::Element::UnorderedList {depth: 0, text: markdown::Text::Bold("Fixed Price")},
+						price,
+					),
+					BillingModel::Hourly(rate) =>
+					{
+						writeln!(output, "{} {}",
+							markdown::Element::UnorderedList {depth: 0, text: markdown::Text::Bold("Hourly Rate")},
+							rate,
+						).unwrap();
+
+						// The hours which each employee worked at each of their rates, and each overtime multiplier.
+						let data = Job::from(job);
+						let mut hours: Vec<(Id, &str, Money, Option<&OvertimeRule>, Decimal)> = Vec::new();
+						data.priced_hours().into_iter().filter(|p| p.timesheet.billable && p.timesheet.invoice_id == invoice_id).for_each(|priced|
+						{
+							match hours.iter_mut().find(|(id, _, rate, overtime, _)|
+								*id == priced.timesheet.employee_id && *rate == priced.rate && *overtime == priced.overtime
+							)
+							{
+								Some((.., total)) => *total += priced.hours,
+								_ => hours.push((
+									priced.timesheet.employee_id,
+									job.timesheets.iter().find(|t| t.employee.id == priced.timesheet.employee_id).map(|t| t.employee.person.name.as_str()).unwrap_or_default(),
+									priced.rate,
+									priced.overtime,
+									priced.hours,
+								)),
+							};
+						});
+
+						if !hours.is_empty()
+						{
+							writeln!(output, "{}:", markdown::Element::UnorderedList
+							{
+								depth: 0,
+								text: markdown::Text::Bold("Hours"),
+							}).unwrap();
+						}
+
+						hours.into_iter().try_for_each(|(_, name, rate, overtime, mut worked)|
+						{
+							worked.rescale(2);
+							write!(output, "{}: {} hours at {}",
+								markdown::Element::UnorderedList {depth: 1, text: markdown::Text::Bold(name)},
+								worked,
+								rate,
+							)?;
+
+							match overtime
+							{
+								Some(rule) => writeln!(output, " ({})", rule),
+								_ => writeln!(output),
+							}
+						})
+					},
+					BillingModel::Milestones {currency, milestones} =>
+					{
+						writeln!(output, "{}:", markdown::Element::UnorderedList
+						{
+							depth: 0,
+							text: markdown::Text::Bold("Milestones"),
+						}).unwrap();
+
+						milestones.iter().filter(|m| m.invoice_id == invoice_id).try_for_each(|m| writeln!(output, "{}: {} ({})",
+							markdown::Element::UnorderedList {depth: 1, text: markdown::Text::Bold(&m.name)},
+							Money {amount: m.amount, currency: *currency},
+							m.date_complete.map(|date|
+								format!("completed on {}", DateTime::<Local>::from(date).naive_local())
+							).unwrap_or_else(||
+								"not completed".into()
+							),
+						))
+					},
+					BillingModel::Retainer(retainer) =>
+					{
+						writeln!(output, "{}: {} per {}",
+							markdown::Element::UnorderedList {depth: 0, text: markdown::Text::Bold("Retainer")},
+							retainer.amount,
+							retainer.period,
+						).unwrap();
+
+						let data = Job::from(job);
+						let period = match invoice_id
+						{
+							Some(id) => retainer.periods_billed.iter().find(|p| p.invoice_id == id).map(|p| (p.begin, p.end)),
+							_ => data.next_retainer_period(),
+						};
+
+						if let Some((begin, end)) = period
+						{
+							writeln!(output, "{}: {} – {}",
+								markdown::Element::UnorderedList {depth: 0, text: markdown::Text::Bold("Period")},
+								DateTime::<Local>::from(begin).naive_local(),
+								DateTime::<Local>::from(end).naive_local(),
+							).unwrap();
+						}
+
+						match data.retainer_usage().into_iter().find(|(id, ..)| *id == invoice_id)
+						{
+							Some((_, mut available, mut used)) =>
+							{
+								available.rescale(2);
+								used.rescale(2);
+								writeln!(output, "{}: {} of {}",
+									markdown::Element::UnorderedList {depth: 0, text: markdown::Text::Bold("Hours Used")},
+									used,
+									available,
+								).unwrap();
+
+								if used > available
+								{
+									writeln!(output, "{}: {} hours at {}",
+										markdown::Element::UnorderedList {depth: 0, text: markdown::Text::Bold("Overage")},
+										used - available,
+										retainer.overage_rate,
+									)
+								}
+								else
+								{
+									Ok(())
+								}
+							},
+							_ => Ok(()),
+						}
+					},
+				}.unwrap();
+
+				if let Some(date) = invoice.and_then(|i| i.date.as_ref())
 				{
 					writeln!(output, "{}: {}",
 						markdown::Element::UnorderedList {depth: 0, text: markdown::Text::Bold("Status")},
@@ -171,12 +514,65 @@ impl Target
 					).unwrap();
 				}
 
-				writeln!(output, "{}: {}",
-					markdown::Element::UnorderedList {depth: 0, text: markdown::Text::Bold("Total Amount Owed")},
-					Job::from(job).total()?,
-				).unwrap();
+				if let Some(due) = invoice.and_then(|i| i.date_due())
+				{
+					writeln!(output, "{}: {}{}",
+						markdown::Element::UnorderedList {depth: 0, text: markdown::Text::Bold("Due")},
+						DateTime::<Local>::from(due).naive_local().date(),
+						invoice.and_then(|i| i.payment_terms).map(|t| format!(" ({})", t)).unwrap_or_default(),
+					).unwrap();
+				}
+
+				let taxes = job.applicable_taxes(invoice, default_taxes);
+				let totals = Job::from(job).invoice_totals(invoice_id, taxes)?;
+				self.export_totals(&mut output, &totals, !(totals.taxes.is_empty() && totals.adjustments.is_empty()), "Owed");
+
+				if let Some(i) = invoice.filter(|i| !(i.credit_notes.is_empty() && i.payments.is_empty()))
+				{
+					if !i.credit_notes.is_empty()
+					{
+						writeln!(output, "{}:", markdown::Element::UnorderedList
+						{
+							depth: 0,
+							text: markdown::Text::Bold("Credit Notes"),
+						}).unwrap();
+
+						i.credit_notes.iter().try_for_each(|c| writeln!(output, "{}: {}",
+							markdown::Element::UnorderedList {depth: 1, text: c},
+							c.totals(totals.gross.currency, taxes).gross,
+						)).unwrap();
+					}
+
+					if !i.payments.is_empty()
+					{
+						writeln!(output, "{}:", markdown::Element::UnorderedList
+						{
+							depth: 0,
+							text: markdown::Text::Bold("Payments"),
+						}).unwrap();
+
+						i.payments.iter().try_for_each(|p|
+							writeln!(output, "{}", markdown::Element::UnorderedList {depth: 1, text: p})
+						).unwrap();
+					}
+
+					writeln!(output, "{}: {}",
+						markdown::Element::UnorderedList {depth: 0, text: markdown::Text::Bold("Balance Due")},
+						Job::from(job).invoice_balance(i, taxes)?,
+					).unwrap();
+				}
+
 				writeln!(output, "{}", markdown::Element::<&str>::Break).unwrap();
 
+				if let Some(methods) = issuer.map(|i| &i.payment_methods).filter(|m| !m.is_empty())
+				{
+					writeln!(output, "{}", markdown::Element::Heading {depth: 2, text: "Payment Instructions"}).unwrap();
+					methods.iter().try_for_each(|m|
+						writeln!(output, "{}", markdown::Element::UnorderedList {depth: 0, text: m})
+					).unwrap();
+					writeln!(output, "{}", markdown::Element::<&str>::Break).unwrap();
+				}
+
 				writeln!(output, "{}", markdown::Element::Heading {depth: 2, text: "Objectives"}).unwrap();
 				writeln!(output, "{}", markdown::Element::BlockText(&job.objectives)).unwrap();
 
@@ -186,11 +582,22 @@ impl Target
 					writeln!(output, "{}", markdown::Element::BlockText(&job.notes)).unwrap();
 				}
 
-				if !job.timesheets.is_empty()
+				let data = Job::from(job);
+				// Timesheets are only exported when there is something on them that the client is charged for.
+				let timesheets: Vec<_> = job.timesheets.iter().zip(data.timesheets.iter())
+					.filter(|(t, _)| t.invoice_id == invoice_id && (t.billable || t.expenses.iter().any(|e| e.billable)))
+					.collect();
+				if !timesheets.is_empty()
 				{
 					writeln!(output, "{}", markdown::Element::Heading {depth: 2, text: "Timesheets"}).unwrap();
 					let mut employees = HashSet::new();
-					job.timesheets.iter().for_each(|t| self.export_timesheet(&mut employees, &mut output, t));
+					timesheets.into_iter().for_each(|(view, t)| self.export_timesheet(
+						&mut employees,
+						&mut output,
+						view,
+						&if t.billable { data.rate_periods(t) } else { Vec::new() },
+						&job.expense_markup,
+					));
 				}
 			},
 		};
@@ -198,6 +605,71 @@ impl Target
 		Ok(output)
 	}
 
+	/// # Summary
+	///
+	/// Export some `totals` to the [`Target`] specified, appending to some pre-existing `output`.
+	///
+	/// When `itemized`, the net amount, [`Tax`]es, and gross amount are each exported. Otherwise,
+	/// only the total is. Either way, the total is described as the amount which is `owed_or_credited`.
+	fn export_totals(&self, output: &mut String, totals: &Totals, itemized: bool, owed_or_credited: &str)
+	{
+		match self
+		{
+			#[cfg(feature="markdown")]
+			Self::Markdown =>
+			{
+				if !itemized
+				{
+					writeln!(output, "{}: {}",
+						markdown::Element::UnorderedList {depth: 0, text: markdown::Text::Bold(format!("Total Amount {}", owed_or_credited))},
+						totals.gross,
+					).unwrap();
+
+					return;
+				}
+
+				let write_adjustments = |output: &mut String, before_tax: bool| totals.adjustments.iter()
+					.filter(|a| a.adjustment.before_tax == before_tax)
+					.try_for_each(|a| writeln!(output, "{}: {}{}",
+						markdown::Element::UnorderedList {depth: 0, text: markdown::Text::Bold(&a.adjustment.description)},
+						a.amount,
+						match a.adjustment.amount
+						{
+							AdjustmentAmount::Percent(percent) => format!(" ({}%)", percent),
+							_ => String::new(),
+						},
+					)).unwrap();
+
+				write_adjustments(output, true);
+
+				writeln!(output, "{}: {}",
+					markdown::Element::UnorderedList {depth: 0, text: markdown::Text::Bold("Net")},
+					totals.net,
+				).unwrap();
+
+				totals.taxes.iter().try_for_each(|t| match t.tax
+				{
+					Tax::Rate {..} => writeln!(output, "{}: {} on {}",
+						markdown::Element::UnorderedList {depth: 0, text: markdown::Text::Bold(&t.tax)},
+						t.amount,
+						t.base,
+					),
+					_ => writeln!(output, "{}: {}",
+						markdown::Element::UnorderedList {depth: 0, text: markdown::Text::Bold(&t.tax)},
+						t.base,
+					),
+				}).unwrap();
+
+				write_adjustments(output, false);
+
+				writeln!(output, "{}: {}",
+					markdown::Element::UnorderedList {depth: 0, text: markdown::Text::Bold(format!("Gross Amount {}", owed_or_credited))},
+					totals.gross,
+				).unwrap();
+			},
+		};
+	}
+
 	/// # Summary
 	///
 	/// Return the file extension of an export [`Target`].
@@ -215,58 +687,77 @@ mod tests
 {
 	use
 	{
-		std::{collections::HashMap, time::Instant},
+		std::{collections::HashMap, mem, time::Instant},
 
-		super::{JobView, Target, TimesheetView},
+		super::{AdjustmentAmount, BillToView, ExpenseKind, JobView, Tax, Target, Taxes, TimesheetView},
 
 		clinvoice_data::
 		{
 			chrono::{DateTime, Local, Utc},
-			finance::{Currency, Money},
-			EmployeeStatus, Expense, ExpenseCategory, Id, Invoice,
-			views::{EmployeeView, LocationView, OrganizationView, PersonView},
+			finance::{Currency, Decimal, Money},
+			Adjustment, Attachment, BillingModel, CreditNote, CreditNoteLine, DistanceUnit, EmployeeStatus, Expense, ExpenseCategory, Id, Invoice, InvoiceDate, Milestone, Overtime, OvertimeRule, Payment, PaymentMethod, PaymentTerms, PostalAddress, RateChange, Recurrence, Retainer, RetainerPeriod, Rollover,
+			views::{BillingContactView, EmployeeView, LocationView, OrganizationView, PersonView},
 		},
 	};
 
 	#[test]
-	fn export_job()
+	fn export_invoice()
 	{
 		let organization = OrganizationView
 		{
+			bill_to: Default::default(),
+			expense_markup: Default::default(),
 			id: Id::new_v4(),
 			location: LocationView
 			{
+				address: None,
 				id: Id::new_v4(),
 				outer: Some(LocationView
 				{
+					address: None,
 					id: Id::new_v4(),
 					outer: Some(LocationView
 					{
+						address: None,
 						id: Id::new_v4(),
 						outer: Some(LocationView
 						{
+							address: None,
 							id: Id::new_v4(),
 							outer: Some(LocationView
 							{
+								address: None,
 								id: Id::new_v4(),
 								outer: None,
 								name: "Earth".into(),
+								taxes: None,
 							}.into()),
 							name: "USA".into(),
+							taxes: None,
 						}.into()),
 						name: "Arizona".into(),
+						taxes: None,
 					}.into()),
 					name: "Phoenix".into(),
+					taxes: None,
 				}.into()),
 				name: "1337 Some Street".into(),
+				taxes: None,
 			},
 			name: "Big Old Test".into(),
+			overtime: None,
+			payment_methods: Vec::new(),
+			payment_terms: None,
+			tax_ids: Default::default(),
+			taxes: None,
 		};
 
 		let testy_mctesterson = EmployeeView
 		{
 			contact_info: vec![
 			].into_iter().collect(),
+			employment_changes: Vec::new(),
+			hourly_rate: None,
 			id: Id::new_v4(),
 			organization: organization.clone(),
 			person: PersonView
@@ -281,6 +772,8 @@ mod tests
 		let bob = EmployeeView
 		{
 			contact_info: HashMap::new(),
+			employment_changes: Vec::new(),
+			hourly_rate: None,
 			id: Id::new_v4(),
 			organization: organization.clone(),
 			person: PersonView
@@ -294,23 +787,27 @@ mod tests
 
 		let mut job = JobView
 		{
+			adjustments: Vec::new(),
+			bill_to: Default::default(),
+			billing_model: BillingModel::Hourly(Money::new(20_00, 2, Currency::USD)),
 			client: organization,
 			date_close: None,
 			date_open: Utc::today().and_hms(0, 0, 0),
+			employee_default_rates: Default::default(),
+			employee_rates: Default::default(),
+			expense_markup: Default::default(),
 			id: Id::new_v4(),
-			invoice: Invoice
-			{
-				date: None,
-				hourly_rate: Money::new(20_00, 2, Currency::USD),
-			},
+			invoices: Vec::new(),
 			notes: "- I tested the function.".into(),
 			objectives: "- I want to test this function.".into(),
+			overtime: None,
+			rate_changes: Vec::new(),
 			timesheets: vec![],
 		};
 
 		let start = Instant::now();
 		assert_eq!(
-			Target::Markdown.export_job(&job).unwrap(),
+			Target::Markdown.export_invoice(&job, None, None, None, &Taxes::default()).unwrap(),
 			format!(
 "# Job #{}
 
@@ -336,27 +833,65 @@ mod tests
 		let middle = Instant::now().duration_since(start);
 
 		job.date_close = Some(Utc::today().and_hms(4, 30, 0));
+		job.invoices.push(Invoice
+		{
+			credit_notes: Vec::new(),
+			date: None,
+			id: Id::new_v4(),
+			number: Some("INV-2021-0001".into()),
+			payment_terms: None,
+			payments: Vec::new(),
+			taxes: None,
+		});
+		let invoice_id = Some(job.invoices[0].id);
+
+		// Bob bills at a higher rate on this job, which he keeps when the rate of everyone else is
+		// raised halfway through the first timesheet.
+		job.employee_rates = vec![(bob.id, Money::new(40_00, 2, Currency::USD))].into_iter().collect();
+		job.rate_changes = vec![
+			RateChange
+			{
+				effective_from: Utc::today().and_hms(2, 15, 0),
+				employee_id: None,
+				rate: Money::new(30_00, 2, Currency::USD),
+			},
+		];
 
+		// Everyone is paid double after the first quarter hour of each day.
+		job.overtime = Some(Overtime
+		{
+			holidays: Default::default(),
+			rules: vec![OvertimeRule::Daily {hours: Decimal::new(25, 2), multiplier: Decimal::new(2, 0)}],
+		});
 		job.timesheets = vec![
 			TimesheetView
 			{
+				billable: true,
 				employee: testy_mctesterson,
 				expenses: Vec::new(),
+				hourly_rate: None,
+				invoice_id,
 				time_begin: Utc::today().and_hms(2, 0, 0),
 				time_end: Some(Utc::today().and_hms(2, 30, 0)),
 				work_notes: "- Wrote the test.".into(),
 			},
 			TimesheetView
 			{
+				billable: true,
 				employee: bob,
 				expenses: vec![
 					Expense
 					{
-						category: ExpenseCategory::Service,
+						attachments: Vec::new(),
+						billable: true,
+						category: ExpenseCategory::new("Service"),
 						cost: Money::new(20_00, 2, Currency::USD),
 						description: "Paid for someone else to clean".into(),
+						kind: Default::default(),
 					},
 				],
+				hourly_rate: None,
+				invoice_id,
 				time_begin: Utc::today().and_hms(3, 0, 0),
 				time_end: Some(Utc::today().and_hms(3, 30, 0)),
 				work_notes: "- Clean the deck.".into(),
@@ -365,7 +900,7 @@ mod tests
 
 		let second_start = Instant::now();
 		assert_eq!(
-			Target::Markdown.export_job(&job).unwrap(),
+			Target::Markdown.export_invoice(&job, job.invoices.first(), None, None, &Taxes::default()).unwrap(),
 			format!(
 "# Job #{}
 
@@ -375,8 +910,14 @@ mod tests
 
 ## Invoice
 
+- **Number**: INV-2021-0001
 - **Hourly Rate** 20.00 USD
-- **Total Amount Owed**: 40.00 USD
+- **Hours**:
+	- **Testy McTesterson**: 0.25 hours at 20.00 USD
+	- **Testy McTesterson**: 0.25 hours at 30.00 USD (2× after 0.25 hours a day)
+	- **Bob**: 0.25 hours at 40.00 USD
+	- **Bob**: 0.25 hours at 40.00 USD (2× after 0.25 hours a day)
+- **Total Amount Owed**: 70.00 USD
 
 ## Objectives
 
@@ -390,6 +931,10 @@ mod tests
 
 ### {} – {}
 
+- **Hourly Rates**:
+	- **{} – {}**: 20.00 USD
+	- **{} – {}**: 30.00 USD
+
 #### Employee Information
 
 - **Name**: Testy McTesterson
@@ -402,6 +947,8 @@ mod tests
 
 ### {} – {}
 
+- **Hourly Rate**: 40.00 USD
+
 #### Employee Information
 
 - **Name**: Bob
@@ -417,6 +964,85 @@ Paid for someone else to clean
 #### Work Notes
 
 - Clean the deck.\n\n",
+				job.id,
+				DateTime::<Local>::from(job.date_open),
+				DateTime::<Local>::from(job.date_close.unwrap()).naive_local(),
+				job.timesheets[0].time_begin,
+				job.timesheets[0].time_end.unwrap().naive_local(),
+				DateTime::<Local>::from(job.timesheets[0].time_begin).naive_local(),
+				DateTime::<Local>::from(job.rate_changes[0].effective_from).naive_local(),
+				DateTime::<Local>::from(job.rate_changes[0].effective_from).naive_local(),
+				DateTime::<Local>::from(job.timesheets[0].time_end.unwrap()).naive_local(),
+				job.timesheets[1].time_begin,
+				job.timesheets[1].time_end.unwrap().naive_local(),
+			),
+		);
+		let middle = middle + Instant::now().duration_since(second_start);
+
+		job.invoices[0].taxes = Some(Taxes
+		{
+			expenses: vec![("Service".into(), vec![Tax::Exempt {reason: "§4 UStG".into()}])].into_iter().collect(),
+			rates: vec![Tax::Rate {name: "VAT".into(), percent: Decimal::new(19, 0)}],
+		});
+		job.notes.clear();
+		job.overtime = None;
+		job.rate_changes.clear();
+		job.timesheets[0].work_notes.clear();
+		job.timesheets[1].work_notes.clear();
+
+		let third_start = Instant::now();
+		assert_eq!(
+			Target::Markdown.export_invoice(&job, job.invoices.first(), None, None, &Taxes::default()).unwrap(),
+			format!(
+"# Job #{}
+
+- **Client**: Big Old Test @ 1337 Some Street, Phoenix, Arizona, USA, Earth
+- **Date Opened**: {}
+- **Date Closed**: {}
+
+## Invoice
+
+- **Number**: INV-2021-0001
+- **Hourly Rate** 20.00 USD
+- **Hours**:
+	- **Testy McTesterson**: 0.50 hours at 20.00 USD
+	- **Bob**: 0.50 hours at 40.00 USD
+- **Net**: 50.00 USD
+- **VAT 19%**: 5.70 USD on 30.00 USD
+- **Exempt (§4 UStG)**: 20.00 USD
+- **Gross Amount Owed**: 55.70 USD
+
+## Objectives
+
+- I want to test this function.
+
+## Timesheets
+
+### {} – {}
+
+- **Hourly Rate**: 20.00 USD
+
+#### Employee Information
+
+- **Name**: Testy McTesterson
+- **Employer**: Big Old Test @ 1337 Some Street, Phoenix, Arizona, USA, Earth
+- **Title**: CEO of Tests
+
+### {} – {}
+
+- **Hourly Rate**: 40.00 USD
+
+#### Employee Information
+
+- **Name**: Bob
+- **Employer**: Big Old Test @ 1337 Some Street, Phoenix, Arizona, USA, Earth
+- **Title**: Janitor
+
+#### Expenses
+
+##### Service – 20.00 USD
+
+Paid for someone else to clean\n\n",
 				job.id,
 				DateTime::<Local>::from(job.date_open),
 				DateTime::<Local>::from(job.date_close.unwrap()).naive_local(),
@@ -426,6 +1052,271 @@ Paid for someone else to clean
 				job.timesheets[1].time_end.unwrap().naive_local(),
 			),
 		);
-		println!("\n>>>>> Target::Markdown.export_job {}us <<<<<\n", (Instant::now().duration_since(second_start) + middle).as_micros());
+		println!("\n>>>>> Target::Markdown.export_invoice {}us <<<<<\n", (Instant::now().duration_since(third_start) + middle).as_micros());
+
+		// Expenses are marked up, and nothing which is not billable is exported.
+		let mut marked_up = job.clone();
+		marked_up.expense_markup.percent = Decimal::new(10, 0);
+		marked_up.timesheets[0].billable = false;
+		marked_up.timesheets[1].expenses.push(Expense
+		{
+			attachments: Vec::new(),
+			billable: false,
+			category: ExpenseCategory::new("Food"),
+			cost: Money::new(5_00, 2, Currency::USD),
+			description: "Coffee".into(),
+			kind: Default::default(),
+		});
+		let exported = Target::Markdown.export_invoice(&marked_up, marked_up.invoices.first(), None, None, &Taxes::default()).unwrap();
+		assert!(exported.contains("- **Hours**:\n\t- **Bob**: 0.50 hours at 40.00 USD\n- **Net**: 42.00 USD\n"));
+		assert!(exported.contains("##### Service – 22.00 USD\n"));
+		assert!(!(exported.contains("Coffee") || exported.contains("Testy McTesterson")));
+
+		// Expenses which were calculated from a rate show how.
+		let mut travelled = job.clone();
+		let kind = ExpenseKind::Mileage {distance: Decimal::new(120, 0), rate: Money::new(70, 2, Currency::USD), unit: DistanceUnit::Mile};
+		travelled.timesheets[1].expenses.push(Expense
+		{
+			attachments: vec![Attachment::new("log.pdf".into(), b"120 mi")],
+			billable: true,
+			category: ExpenseCategory::new("Travel"),
+			cost: kind.cost().unwrap(),
+			description: "Drove to the store".into(),
+			kind,
+		});
+		let exported = Target::Markdown.export_invoice(&travelled, travelled.invoices.first(), None, None, &Taxes::default()).unwrap();
+		assert!(exported.contains("##### Travel – 84.00 USD\n\n*120 mi at 0.70 USD per mi*\n\nDrove to the store\n\n- **Receipts**: log.pdf\n\n"));
+
+		// A client with a postal address is written as a postal block.
+		travelled.client.location.address = Some(PostalAddress
+		{
+			city: "Phoenix".into(),
+			country: "US".into(),
+			postal_code: Some("85004".into()),
+			region: Some("AZ".into()),
+			street: vec!["1337 Some Street".into(), "Suite 200".into()],
+		});
+		let exported = Target::Markdown.export_invoice(&travelled, travelled.invoices.first(), None, None, &Taxes::default()).unwrap();
+		assert!(exported.contains("- **Client**: Big Old Test  \n  1337 Some Street  \n  Suite 200  \n  Phoenix, AZ 85004  \n  US\n- **Date Opened**"));
+
+		// The issuer is shown with its tax identifiers, and explains how it may be paid.
+		let mut issuer = travelled.client.clone();
+		issuer.location.address = None;
+		issuer.name = "Test Issuer".into();
+		issuer.payment_methods = vec![PaymentMethod::Iban {bic: Some("TESTDEFFXXX".into()), iban: "DE89370400440532013000".into()}];
+		issuer.tax_ids = vec![("VAT".into(), "DE123456789".into())].into_iter().collect();
+		let exported = Target::Markdown.export_invoice(&travelled, travelled.invoices.first(), Some(&issuer), None, &Taxes::default()).unwrap();
+		assert!(exported.contains(&format!("\n\n- **From**: {}\n\t- **VAT**: DE123456789\n- **Client**: Big Old Test  \n", issuer)));
+		assert!(exported.contains("\n\n## Payment Instructions\n\n- IBAN DE89370400440532013000, BIC TESTDEFFXXX\n\n## Objectives\n"));
+
+		// Invoices are addressed to the billing contact, at the billing address, instead of the client.
+		let bill_to = BillToView
+		{
+			contact: Some(BillingContactView::Other {email: Some("ap@big.old".into()), name: "Accounts Payable".into()}),
+			location: Some(LocationView
+			{
+				address: Some(PostalAddress
+				{
+					city: "Tucson".into(),
+					country: "US".into(),
+					postal_code: Some("85701".into()),
+					region: Some("AZ".into()),
+					street: vec!["1 Billing Way".into()],
+				}),
+				id: Id::new_v4(),
+				name: "Billing Office".into(),
+				outer: None,
+				taxes: None,
+			}),
+		};
+		let exported = Target::Markdown.export_invoice(&travelled, travelled.invoices.first(), None, Some(&bill_to), &Taxes::default()).unwrap();
+		assert!(exported.contains("- **Bill To**: Big Old Test  \n  Attn: Accounts Payable  \n  1 Billing Way  \n  Tucson, AZ 85701  \n  US  \n  ap@big.old\n- **Date Opened**"));
+
+		job.billing_model = BillingModel::FixedPrice(Money::new(50_00, 2, Currency::USD));
+		let exported = Target::Markdown.export_invoice(&job, job.invoices.first(), None, None, &Taxes::default()).unwrap();
+		assert!(exported.contains("- **Fixed Price**: 50.00 USD\n- **Net**: 70.00 USD\n"));
+
+		job.billing_model = BillingModel::Milestones
+		{
+			currency: Currency::USD,
+			milestones: vec![
+				Milestone {amount: Decimal::new(30_00, 2), date_complete: job.date_close, invoice_id, name: "Design".into()},
+				Milestone {amount: Decimal::new(45_00, 2), date_complete: None, invoice_id: None, name: "Build".into()},
+			],
+		};
+		let exported = Target::Markdown.export_invoice(&job, job.invoices.first(), None, None, &Taxes::default()).unwrap();
+		assert!(exported.contains(&format!(
+"- **Milestones**:
+	- **Design**: 30.00 USD (completed on {})
+- **Net**: 50.00 USD
+",
+			DateTime::<Local>::from(job.date_close.unwrap()).naive_local(),
+		)));
+
+		// Work which has not been billed is exported on its own.
+		let exported = Target::Markdown.export_invoice(&job, None, None, None, &Taxes::default()).unwrap();
+		assert!(exported.contains("- **Milestones**:\n\t- **Build**: 45.00 USD (not completed)\n- **Total Amount Owed**: 0.00 USD\n"));
+		assert!(!exported.contains("## Timesheets"));
+
+		// An hour was worked during a period of a retainer which included three quarters of one.
+		let milestones = mem::replace(&mut job.billing_model, BillingModel::Retainer(Retainer
+		{
+			amount: Money::new(40_00, 2, Currency::USD),
+			included_hours: Decimal::new(75, 2),
+			overage_rate: Money::new(20_00, 2, Currency::USD),
+			period: Recurrence::Monthly,
+			periods_billed: vec![RetainerPeriod {begin: job.date_open, end: job.date_close.unwrap(), invoice_id: job.invoices[0].id}],
+			rollover: Rollover::Expire,
+		}));
+		let exported = Target::Markdown.export_invoice(&job, job.invoices.first(), None, None, &Taxes::default()).unwrap();
+		assert!(exported.contains(&format!(
+"- **Retainer**: 40.00 USD per month
+- **Period**: {} – {}
+- **Hours Used**: 1.00 of 0.75
+- **Overage**: 0.25 hours at 20.00 USD
+- **Net**: 65.00 USD
+",
+			DateTime::<Local>::from(job.date_open).naive_local(),
+			DateTime::<Local>::from(job.date_close.unwrap()).naive_local(),
+		)));
+		job.billing_model = milestones;
+
+		job.invoices[0].payments.push(Payment
+		{
+			amount: Money::new(20_00, 2, Currency::USD),
+			date: Utc::now(),
+			method: "Cash".into(),
+			reference: "".into(),
+		});
+		let exported = Target::Markdown.export_invoice(&job, job.invoices.first(), None, None, &Taxes::default()).unwrap();
+		assert!(exported.contains(&format!(
+			"- **Payments**:\n\t- {}\n- **Balance Due**: 35.70 USD\n",
+			job.invoices[0].payments[0],
+		)));
+
+		job.invoices[0].date = Some(InvoiceDate {issued: Utc::today().and_hms(5, 0, 0), paid: None});
+		job.invoices[0].payment_terms = Some(PaymentTerms::Net(15));
+		let exported = Target::Markdown.export_invoice(&job, job.invoices.first(), None, None, &Taxes::default()).unwrap();
+		assert!(exported.contains(&format!(
+			"- **Due**: {} (Net 15)\n",
+			DateTime::<Local>::from(job.invoices[0].date_due().unwrap()).naive_local().date(),
+		)));
+
+		job.adjustments.push(Adjustment
+		{
+			amount: AdjustmentAmount::Fixed(Decimal::new(25, 0)),
+			before_tax: false,
+			description: "Rush fee".into(),
+			invoice_id: None,
+		});
+		let exported = Target::Markdown.export_invoice(&job, None, None, None, &Taxes::default()).unwrap();
+		assert!(exported.contains("- **Net**: 0.00 USD\n- **Rush fee**: 25.00 USD\n- **Gross Amount Owed**: 25.00 USD\n"));
+
+		// A discount before taxes and a fee after them, on an invoice which was issued.
+		job.adjustments = vec![
+			Adjustment
+			{
+				amount: AdjustmentAmount::Percent(Decimal::new(-10, 0)),
+				before_tax: true,
+				description: "Loyalty discount".into(),
+				invoice_id: Some(job.invoices[0].id),
+			},
+			Adjustment
+			{
+				amount: AdjustmentAmount::Fixed(Decimal::new(25, 0)),
+				before_tax: false,
+				description: "Rush fee".into(),
+				invoice_id: Some(job.invoices[0].id),
+			},
+		];
+		let exported = Target::Markdown.export_invoice(&job, job.invoices.first(), None, None, &Taxes::default()).unwrap();
+		assert!(exported.contains(
+			"- **Loyalty discount**: -5.00 USD (-10%)\n- **Net**: 45.00 USD\n- **VAT 19%**: 5.13 USD on 27.00 USD\n- **Exempt (§4 UStG)**: 18.00 USD\n- **Rush fee**: 25.00 USD\n- **Gross Amount Owed**: 75.13 USD\n"
+		));
+		assert!(exported.contains("- **Balance Due**: 55.13 USD\n"));
+	}
+
+	#[test]
+	fn export_credit_note()
+	{
+		let job = JobView
+		{
+			adjustments: Vec::new(),
+			bill_to: Default::default(),
+			billing_model: BillingModel::Hourly(Money::new(20_00, 2, Currency::USD)),
+			client: OrganizationView
+			{
+				bill_to: Default::default(),
+				expense_markup: Default::default(),
+				id: Id::new_v4(),
+				location: LocationView {address: None, id: Id::new_v4(), name: "Earth".into(), outer: None, taxes: None},
+				name: "Big Old Test".into(),
+				overtime: None,
+				payment_methods: Vec::new(),
+				payment_terms: None,
+				tax_ids: Default::default(),
+				taxes: None,
+			},
+			date_close: None,
+			date_open: Utc::now(),
+			employee_default_rates: Default::default(),
+			employee_rates: Default::default(),
+			expense_markup: Default::default(),
+			id: Id::new_v4(),
+			invoices: vec![Invoice
+			{
+				credit_notes: vec![CreditNote
+				{
+					date: Utc::now(),
+					id: Id::new_v4(),
+					lines: vec![CreditNoteLine {amount: Decimal::new(-10_00, 2), category: None, description: "Billed twice for one meeting".into()}],
+					number: "CN-2021-0001".into(),
+					reason: "Overbilled".into(),
+				}],
+				date: Some(InvoiceDate {issued: Utc::now(), paid: None}),
+				id: Id::new_v4(),
+				number: Some("INV-2021-0001".into()),
+				payment_terms: None,
+				payments: Vec::new(),
+				taxes: Some(Taxes {rates: vec![Tax::Rate {name: "VAT".into(), percent: Decimal::new(19, 0)}], ..Default::default()}),
+			}],
+			notes: "".into(),
+			objectives: "".into(),
+			overtime: None,
+			rate_changes: Vec::new(),
+			timesheets: Vec::new(),
+		};
+
+		let start = Instant::now();
+		let exported = Target::Markdown.export_credit_note(&job, &job.invoices[0], &job.invoices[0].credit_notes[0], None, None, &Taxes::default());
+		println!("\n>>>>> Target::Markdown.export_credit_note {}us <<<<<\n", Instant::now().duration_since(start).as_micros());
+
+		assert_eq!(exported, format!(
+"# Credit Note CN-2021-0001
+
+- **Client**: Big Old Test @ Earth
+- **Date Issued**: {}
+- **Corrects Invoice**: INV-2021-0001
+- **Reason**: Overbilled
+
+## Credited
+
+- **Billed twice for one meeting**: -10.00 USD
+
+## Total
+
+- **Net**: -10.00 USD
+- **VAT 19%**: -1.90 USD on -10.00 USD
+- **Gross Amount Credited**: -11.90 USD
+",
+			DateTime::<Local>::from(job.invoices[0].credit_notes[0].date).naive_local(),
+		));
+
+		// The credit note reduces the balance of the invoice which it corrects.
+		let exported = Target::Markdown.export_invoice(&job, job.invoices.first(), None, None, &Taxes::default()).unwrap();
+		assert!(exported.contains(&format!(
+			"- **Credit Notes**:\n\t- {}: -11.90 USD\n- **Balance Due**: -11.90 USD\n",
+			job.invoices[0].credit_notes[0],
+		)));
 	}
 }
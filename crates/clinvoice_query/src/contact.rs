@@ -21,8 +21,17 @@ pub struct Contact<'m>
 	#[cfg_attr(feature="serde_support", serde(default))]
 	pub email: MatchStr<String>,
 
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub fax: MatchStr<String>,
+
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub messaging: MatchStr<String>,
+
 	#[cfg_attr(feature="serde_support", serde(default))]
 	pub phone: MatchStr<String>,
+
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub website: MatchStr<String>,
 }
 
 impl Contact<'_>
@@ -45,10 +54,25 @@ impl Contact<'_>
 				clinvoice_data::Contact::Email {email, export: _} => Some(email.as_ref()),
 				_ => None,
 			}))? &&
-			self.phone.set_matches(&mut contact_info.flat_map(|c| match c
+			self.fax.set_matches(&mut contact_info.by_ref().flat_map(|c| match c
+			{
+				clinvoice_data::Contact::Fax {fax, export: _} => Some(fax.as_ref()),
+				_ => None,
+			}))? &&
+			self.messaging.set_matches(&mut contact_info.by_ref().flat_map(|c| match c
+			{
+				clinvoice_data::Contact::Messaging {handle, ..} => Some(handle.as_ref()),
+				_ => None,
+			}))? &&
+			self.phone.set_matches(&mut contact_info.by_ref().flat_map(|c| match c
 			{
 				clinvoice_data::Contact::Phone {phone, export: _} => Some(phone.as_ref()),
 				_ => None,
+			}))? &&
+			self.website.set_matches(&mut contact_info.flat_map(|c| match c
+			{
+				clinvoice_data::Contact::Website {url, export: _} => Some(url.as_ref()),
+				_ => None,
 			}))?
 		)
 	}
@@ -69,10 +93,25 @@ impl Contact<'_>
 				ContactView::Email {email, export: _} => Some(email.as_ref()),
 				_ => None,
 			}))? &&
-			self.phone.set_matches(&mut contact_info.flat_map(|c| match c
+			self.fax.set_matches(&mut contact_info.by_ref().flat_map(|c| match c
+			{
+				ContactView::Fax {fax, export: _} => Some(fax.as_ref()),
+				_ => None,
+			}))? &&
+			self.messaging.set_matches(&mut contact_info.by_ref().flat_map(|c| match c
+			{
+				ContactView::Messaging {handle, ..} => Some(handle.as_ref()),
+				_ => None,
+			}))? &&
+			self.phone.set_matches(&mut contact_info.by_ref().flat_map(|c| match c
 			{
 				ContactView::Phone {phone, export: _} => Some(phone.as_ref()),
 				_ => None,
+			}))? &&
+			self.website.set_matches(&mut contact_info.flat_map(|c| match c
+			{
+				ContactView::Website {url, export: _} => Some(url.as_ref()),
+				_ => None,
 			}))?
 		)
 	}
@@ -2,7 +2,12 @@ use
 {
 	super::{Contact, Match, MatchStr, Organization, Person, Result},
 
-	clinvoice_data::{EmployeeStatus, Id, views::EmployeeView},
+	clinvoice_data::
+	{
+		chrono::{DateTime, Local, NaiveDateTime, TimeZone, Utc},
+		EmployeeStatus, Id,
+		views::EmployeeView,
+	},
 };
 
 #[cfg(feature="serde_support")]
@@ -15,6 +20,13 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature="serde_support", derive(Deserialize, Serialize))]
 pub struct Employee<'m>
 {
+	/// # Summary
+	///
+	/// The date on which the `status` and `title` of an [`Employee`](clinvoice_data::Employee)
+	/// are matched. When left blank, the current date is used.
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub as_of: Option<NaiveDateTime>,
+
 	#[cfg_attr(feature="serde_support", serde(default))]
 	pub contact_info: Contact<'m>,
 
@@ -36,18 +48,35 @@ pub struct Employee<'m>
 
 impl Employee<'_>
 {
+	/// # Summary
+	///
+	/// Get the date which the `status` and `title` of an [`Employee`](clinvoice_data::Employee)
+	/// should be matched on.
+	///
+	/// # Remarks
+	///
+	/// Like the other dates of a query, `as_of` is in local time.
+	fn as_of(&self) -> DateTime<Utc>
+	{
+		self.as_of.map_or_else(Utc::now, |date| Local.from_local_datetime(&date).earliest().map_or_else(
+			|| DateTime::from_utc(date, Utc),
+			|local| local.with_timezone(&Utc),
+		))
+	}
+
 	/// # Summary
 	///
 	/// Return `true` if `employee` is a match.
 	pub fn matches(&self, employee: &clinvoice_data::Employee) -> Result<bool>
 	{
+		let as_of = self.as_of();
 		Ok(
 			self.contact_info.set_matches(&mut employee.contact_info.values())? &&
 			self.id.matches(&employee.id) &&
 			self.organization.id.matches(&employee.organization_id) &&
 			self.person.id.matches(&employee.person_id) &&
-			self.title.matches(&employee.title)? &&
-			self.status.matches(&employee.status)
+			self.title.matches(employee.title_on(as_of))? &&
+			self.status.matches(&employee.status_on(as_of))
 		)
 	}
 
@@ -56,13 +85,14 @@ impl Employee<'_>
 	/// Return `true` if `employee` is a match.
 	pub fn matches_view(&self, employee: &EmployeeView) -> Result<bool>
 	{
+		let as_of = self.as_of();
 		Ok(
 			self.contact_info.set_matches_view(&mut employee.contact_info.values())? &&
 			self.id.matches(&employee.id) &&
 			self.organization.matches_view(&employee.organization)? &&
 			self.person.matches_view(&employee.person)? &&
-			self.title.matches(&employee.title)? &&
-			self.status.matches(&employee.status)
+			self.title.matches(employee.title_on(as_of))? &&
+			self.status.matches(&employee.status_on(as_of))
 		)
 	}
 
@@ -71,13 +101,79 @@ impl Employee<'_>
 	/// Return `true` if `employee` is a match.
 	pub fn set_matches_view<'item>(&self, employees: &mut impl Iterator<Item=&'item EmployeeView>) -> Result<bool>
 	{
+		let as_of = self.as_of();
 		Ok(
 			self.contact_info.set_matches_view(&mut employees.by_ref().map(|e| e.contact_info.values()).flatten())? &&
 			self.id.set_matches(&employees.by_ref().map(|e| &e.id).collect()) &&
 			self.organization.set_matches_view(&mut employees.by_ref().map(|e| &e.organization))? &&
 			self.person.set_matches_view(&mut employees.by_ref().map(|e| &e.person))? &&
-			self.title.set_matches(&mut employees.by_ref().map(|e| e.title.as_ref()))? &&
-			self.status.set_matches(&employees.map(|e| &e.status).collect())
+			self.title.set_matches(&mut employees.by_ref().map(|e| e.title_on(as_of)))? &&
+			self.status.set_matches(&employees.map(|e| e.status_on(as_of)).collect::<Vec<_>>().iter().collect())
 		)
 	}
 }
+
+#[cfg(test)]
+mod tests
+{
+	use
+	{
+		std::{borrow::Cow::Borrowed, collections::HashMap, time::Instant},
+
+		super::{Employee, EmployeeStatus, Id, Local, Match, MatchStr, TimeZone, Utc},
+
+		clinvoice_data::EmploymentChange,
+	};
+
+	#[test]
+	fn matches()
+	{
+		let janitor = clinvoice_data::Employee
+		{
+			contact_info: HashMap::new(),
+			employment_changes: vec![EmploymentChange
+			{
+				effective_from: Local.ymd(2021, 6, 1).and_hms(0, 0, 0).with_timezone(&Utc),
+				status: EmployeeStatus::NotEmployed,
+				title: "Retired Janitor".into(),
+			}],
+			hourly_rate: None,
+			id: Id::new_v4(),
+			organization_id: Id::new_v4(),
+			person_id: Id::new_v4(),
+			status: EmployeeStatus::Employed,
+			title: "Janitor".into(),
+		};
+
+		let employed = Employee
+		{
+			status: Match::EqualTo(Borrowed(&EmployeeStatus::Employed)),
+			title: MatchStr::EqualTo("Janitor".into()),
+			..Default::default()
+		};
+
+		let not_employed = Employee
+		{
+			status: Match::EqualTo(Borrowed(&EmployeeStatus::NotEmployed)),
+			title: MatchStr::EqualTo("Retired Janitor".into()),
+			..Default::default()
+		};
+
+		let start = Instant::now();
+
+		// The employee's change is in effect from midnight, local time.
+		let before = Some(Local.ymd(2021, 5, 31).and_hms(23, 59, 59).naive_local());
+		assert!(Employee {as_of: before, ..employed.clone()}.matches(&janitor).unwrap());
+		assert!(!Employee {as_of: before, ..not_employed.clone()}.matches(&janitor).unwrap());
+
+		let after = Some(Local.ymd(2021, 6, 1).and_hms(0, 0, 0).naive_local());
+		assert!(!Employee {as_of: after, ..employed.clone()}.matches(&janitor).unwrap());
+		assert!(Employee {as_of: after, ..not_employed.clone()}.matches(&janitor).unwrap());
+
+		// When no date is given, the current status is matched.
+		assert!(!employed.matches(&janitor).unwrap());
+		assert!(not_employed.matches(&janitor).unwrap());
+
+		println!("\n>>>>> Employee::matches {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / 6);
+	}
+}
@@ -1,4 +1,9 @@
-use thiserror::Error;
+use
+{
+	clinvoice_data::finance::Error as FinanceError,
+
+	thiserror::Error,
+};
 
 /// # Summary
 ///
@@ -6,6 +11,12 @@ use thiserror::Error;
 #[derive(Clone, Debug, Error, PartialEq)]
 pub enum Error
 {
+	/// # Summary
+	///
+	/// A query matched on an amount of money which could not be calculated.
+	#[error("{0}")]
+	Finance(String),
+
 	/// # Summary
 	///
 	/// A query was attmepted with regular expressions, and the regular expression was malformed.
@@ -13,5 +24,12 @@ pub enum Error
 	MalformedRegex(#[from] regex::Error),
 }
 
-clinvoice_error::AliasResult!();
+impl From<FinanceError> for Error
+{
+	fn from(error: FinanceError) -> Self
+	{
+		Self::Finance(error.to_string())
+	}
+}
 
+clinvoice_error::AliasResult!();
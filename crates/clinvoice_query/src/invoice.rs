@@ -1,8 +1,12 @@
 use
 {
-	super::Match,
+	super::{Match, MatchStr, Result},
 
-	clinvoice_data::{chrono::NaiveDateTime, finance::Money},
+	clinvoice_data::
+	{
+		chrono::NaiveDateTime,
+		finance::{Money, Result as FinanceResult},
+	},
 };
 
 #[cfg(feature="serde_support")]
@@ -15,14 +19,39 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature="serde_support", derive(Deserialize, Serialize))]
 pub struct Invoice<'m>
 {
+	/// # Summary
+	///
+	/// Matches the amount which is still owed on the [`Invoice`](clinvoice_data::Invoice) (see
+	/// [`Job::invoice_balance`](clinvoice_data::Job::invoice_balance)).
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub balance: Match<'m, Money>,
+
+	/// # Summary
+	///
+	/// Matches the date upon which the [`Invoice`](clinvoice_data::Invoice) is due (see
+	/// [`Invoice::date_due`](clinvoice_data::Invoice::date_due)).
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub date_due: Match<'m, Option<NaiveDateTime>>,
+
 	#[cfg_attr(feature="serde_support", serde(default))]
 	pub issued: Match<'m, Option<NaiveDateTime>>,
 
 	#[cfg_attr(feature="serde_support", serde(default))]
 	pub paid: Match<'m, Option<NaiveDateTime>>,
 
+	/// # Summary
+	///
+	/// Matches whether the [`Invoice`](clinvoice_data::Invoice) has been paid in full, i.e. whether
+	/// its [`balance`](Self::balance) is zero or less.
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub paid_in_full: Match<'m, bool>,
+
+	/// # Summary
+	///
+	/// Matches the number of the [`Invoice`](clinvoice_data::Invoice). An [`Invoice`](clinvoice_data::Invoice)
+	/// which has not been issued yet has no number, and is matched as an empty string.
 	#[cfg_attr(feature="serde_support", serde(default))]
-	pub hourly_rate: Match<'m, Money>,
+	pub number: MatchStr<String>,
 }
 
 impl Invoice<'_>
@@ -30,10 +59,33 @@ impl Invoice<'_>
 	/// # Summary
 	///
 	/// Return `true` if `invoice` is a match.
-	pub fn matches(&self, invoice: &clinvoice_data::Invoice) -> bool
+	///
+	/// # Remarks
+	///
+	/// The `balance` of the `invoice` is only calculated if it is being matched on. When it cannot be
+	/// known (i.e. it is `None`), the `balance` and `paid_in_full` of this query are not matched on.
+	pub fn matches(&self, invoice: &clinvoice_data::Invoice, balance: impl FnOnce(&clinvoice_data::Invoice) -> FinanceResult<Option<Money>>)
+		-> Result<bool>
 	{
-		self.hourly_rate.matches(&invoice.hourly_rate) &&
-		self.issued.matches(&invoice.date.as_ref().map(|d| d.issued.naive_local())) &&
-		self.paid.matches(&invoice.date.as_ref().and_then(|d| d.paid.map(|p| p.naive_local())))
+		if !(
+			self.date_due.matches(&invoice.date_due().map(|d| d.naive_local())) &&
+			self.issued.matches(&invoice.date.as_ref().map(|d| d.issued.naive_local())) &&
+			self.paid.matches(&invoice.date.as_ref().and_then(|d| d.paid.map(|p| p.naive_local()))) &&
+			self.number.matches(invoice.number.as_deref().unwrap_or_default())?
+		)
+		{
+			return Ok(false);
+		}
+
+		if self.balance == Match::Any && self.paid_in_full == Match::Any
+		{
+			return Ok(true);
+		}
+
+		Ok(match balance(invoice)?
+		{
+			Some(b) => self.balance.matches(&b) && self.paid_in_full.matches(&(b.amount <= 0.into())),
+			None => true,
+		})
 	}
 }
@@ -2,7 +2,13 @@ use
 {
 	super::{Invoice, Match, MatchStr, Organization, Timesheet, Result},
 
-	clinvoice_data::{chrono::NaiveDateTime, Id, views::JobView},
+	clinvoice_data::
+	{
+		chrono::NaiveDateTime,
+		finance::{Money, Result as FinanceResult},
+		BillingModel, Id, Taxes,
+		views::JobView,
+	},
 };
 
 #[cfg(feature="serde_support")]
@@ -15,6 +21,9 @@ use serde::{Deserialize, Serialize};
 #[cfg_attr(feature="serde_support", derive(Deserialize, Serialize))]
 pub struct Job<'m>
 {
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub billing_model: Match<'m, BillingModel>,
+
 	#[cfg_attr(feature="serde_support", serde(default))]
 	pub client: Organization<'m>,
 
@@ -24,11 +33,22 @@ pub struct Job<'m>
 	#[cfg_attr(feature="serde_support", serde(default))]
 	pub date_open: Match<'m, NaiveDateTime>,
 
+	/// # Summary
+	///
+	/// Whether every one of the [`Invoice`](clinvoice_data::Invoice)s of the
+	/// [`Job`](clinvoice_data::Job) must match `invoices`, rather than any one of them.
+	#[cfg_attr(feature="serde_support", serde(default))]
+	pub every_invoice: bool,
+
 	#[cfg_attr(feature="serde_support", serde(default))]
 	pub id: Match<'m, Id>,
 
+	/// # Summary
+	///
+	/// Matches the [`Invoice`](clinvoice_data::Invoice)s of the [`Job`](clinvoice_data::Job). Each
+	/// [`Invoice`](clinvoice_data::Invoice) is matched on its own (see `every_invoice`).
 	#[cfg_attr(feature="serde_support", serde(default))]
-	pub invoice: Invoice<'m>,
+	pub invoices: Invoice<'m>,
 
 	#[cfg_attr(feature="serde_support", serde(default))]
 	pub notes: MatchStr<String>,
@@ -40,19 +60,65 @@ pub struct Job<'m>
 	pub timesheets: Timesheet<'m>,
 }
 
+/// # Summary
+///
+/// Get the balance of some `invoice` of a `job`, using the `taxes` which apply to the `job` when the
+/// `invoice` has none of its own.
+///
+/// # Returns
+///
+/// * `None`, if the `invoice` has no taxes of its own and the `taxes` of the `job` are not known.
+fn balance(job: &clinvoice_data::Job, invoice: &clinvoice_data::Invoice, taxes: Option<&Taxes>) -> FinanceResult<Option<Money>>
+{
+	invoice.taxes.as_ref().or(taxes).map(|t| job.invoice_balance(invoice, t)).transpose()
+}
+
 impl Job<'_>
 {
+	/// # Summary
+	///
+	/// Return `true` if the `invoices` of a [`Job`](clinvoice_data::Job) are a match.
+	fn invoices_match(
+		&self,
+		invoices: &[clinvoice_data::Invoice],
+		balance: impl Fn(&clinvoice_data::Invoice) -> FinanceResult<Option<Money>>,
+	) -> Result<bool>
+	{
+		if self.invoices == Invoice::default()
+		{
+			return Ok(true);
+		}
+
+		for invoice in invoices
+		{
+			// One invoice decides the match when it matches, unless every invoice must match.
+			if self.invoices.matches(invoice, &balance)? != self.every_invoice
+			{
+				return Ok(!self.every_invoice);
+			}
+		}
+
+		Ok(self.every_invoice)
+	}
+
 	/// # Summary
 	///
 	/// Return `true` if `job` is a match.
+	///
+	/// # Remarks
+	///
+	/// The taxes of the client are not known here, so the `balance` of an
+	/// [`Invoice`](clinvoice_data::Invoice) which was not issued with taxes of its own is only
+	/// matched by [`Job::matches_view`].
 	pub fn matches(&self, job: &clinvoice_data::Job) -> Result<bool>
 	{
 		Ok(
+			self.billing_model.matches(&job.billing_model) &&
 			self.client.id.matches(&job.client_id) &&
 			self.date_close.matches(&job.date_close.map(|d| d.naive_local())) &&
 			self.date_open.matches(&job.date_open.naive_local()) &&
 			self.id.matches(&job.id) &&
-			self.invoice.matches(&job.invoice) &&
+			self.invoices_match(&job.invoices, |i| balance(job, i, None))? &&
 			self.notes.matches(&job.notes)? &&
 			self.objectives.matches(&job.objectives)? &&
 			self.timesheets.set_matches(&mut job.timesheets.iter())?
@@ -62,17 +128,183 @@ impl Job<'_>
 	/// # Summary
 	///
 	/// Return `true` if `job` is a match.
-	pub fn matches_view(&self, job: &JobView) -> Result<bool>
+	///
+	/// # Parameters
+	///
+	/// * `taxes`, the taxes which apply when neither an [`Invoice`](clinvoice_data::Invoice) nor the
+	///   client of the `job` specifies any.
+	pub fn matches_view(&self, job: &JobView, taxes: &Taxes) -> Result<bool>
 	{
+		let taxes = job.applicable_taxes(None, taxes);
+
 		Ok(
+			self.billing_model.matches(&job.billing_model) &&
 			self.client.matches_view(&job.client)? &&
 			self.date_close.matches(&job.date_close.map(|d| d.naive_local())) &&
 			self.date_open.matches(&job.date_open.naive_local()) &&
 			self.id.matches(&job.id) &&
-			self.invoice.matches(&job.invoice) &&
+			self.invoices_match(&job.invoices, |i| balance(&job.into(), i, Some(taxes)))? &&
 			self.notes.matches(&job.notes)? &&
 			self.objectives.matches(&job.objectives)? &&
 			self.timesheets.set_matches_view(&mut job.timesheets.iter())?
 		)
 	}
 }
+
+#[cfg(test)]
+mod tests
+{
+	use
+	{
+		std::{borrow::Cow::Borrowed, time::Instant},
+
+		super::{BillingModel, Id, Invoice, Job, JobView, Match, Money, Taxes},
+
+		clinvoice_data::
+		{
+			chrono::{Duration, Utc},
+			finance::{Currency, Decimal},
+			EmployeeStatus, InvoiceDate, Payment, Tax,
+			views::{EmployeeView, LocationView, OrganizationView, PersonView, TimesheetView},
+		},
+	};
+
+	/// # Summary
+	///
+	/// A [`JobView`] with an hour of work on an invoice which was issued and paid, and another on an
+	/// invoice which was not issued yet.
+	fn job_view() -> JobView
+	{
+		let issued = Utc::now() - Duration::days(7);
+		let paid = clinvoice_data::Invoice
+		{
+			credit_notes: Vec::new(),
+			date: Some(InvoiceDate {issued, paid: Some(issued)}),
+			id: Id::new_v4(),
+			number: Some("INV-0001".into()),
+			payment_terms: None,
+			payments: vec![Payment {amount: Money::new(20_00, 2, Currency::USD), date: issued, method: "Cash".into(), reference: "".into()}],
+			taxes: Some(Taxes::default()),
+		};
+		let unpaid = clinvoice_data::Invoice {date: None, id: Id::new_v4(), number: None, payments: Vec::new(), taxes: None, ..paid.clone()};
+
+		let client = OrganizationView
+		{
+			bill_to: Default::default(),
+			expense_markup: Default::default(),
+			id: Id::new_v4(),
+			location: LocationView {address: None, id: Id::new_v4(), name: "Earth".into(), outer: None, taxes: None},
+			name: "Big Old Test".into(),
+			overtime: None,
+			payment_methods: Vec::new(),
+			payment_terms: None,
+			tax_ids: Default::default(),
+			taxes: None,
+		};
+
+		let timesheet = |invoice: &clinvoice_data::Invoice, time_begin| TimesheetView
+		{
+			billable: true,
+			employee: EmployeeView
+			{
+				contact_info: Default::default(),
+				employment_changes: Vec::new(),
+				hourly_rate: None,
+				id: Id::new_v4(),
+				organization: client.clone(),
+				person: PersonView {id: Id::new_v4(), name: "Testy McTesterson".into()},
+				status: EmployeeStatus::Employed,
+				title: "Tester".into(),
+			},
+			expenses: Vec::new(),
+			hourly_rate: None,
+			invoice_id: Some(invoice.id),
+			time_begin,
+			time_end: Some(time_begin + Duration::hours(1)),
+			work_notes: "".into(),
+		};
+
+		JobView
+		{
+			adjustments: Vec::new(),
+			bill_to: Default::default(),
+			billing_model: BillingModel::Hourly(Money::new(20_00, 2, Currency::USD)),
+			client: client.clone(),
+			date_close: None,
+			date_open: issued - Duration::days(7),
+			employee_default_rates: Default::default(),
+			employee_rates: Default::default(),
+			expense_markup: Default::default(),
+			id: Id::new_v4(),
+			invoices: vec![paid.clone(), unpaid.clone()],
+			notes: "".into(),
+			objectives: "".into(),
+			overtime: None,
+			rate_changes: Vec::new(),
+			timesheets: vec![timesheet(&paid, issued - Duration::days(1)), timesheet(&unpaid, issued + Duration::days(1))],
+		}
+	}
+
+	#[test]
+	fn matches_invoices()
+	{
+		let job_view = job_view();
+		let job = clinvoice_data::Job::from(&job_view);
+
+		let unpaid = Job
+		{
+			invoices: Invoice {paid_in_full: Match::EqualTo(Borrowed(&false)), ..Default::default()},
+			..Default::default()
+		};
+
+		let paid = Job
+		{
+			invoices: Invoice {paid_in_full: Match::EqualTo(Borrowed(&true)), ..Default::default()},
+			..Default::default()
+		};
+
+		// Issued, but not paid in full: each half matches a different invoice.
+		let issued_unpaid = Job
+		{
+			invoices: Invoice {issued: Match::Not(Match::EqualTo(Borrowed(&None)).into()), ..unpaid.invoices.clone()},
+			..Default::default()
+		};
+
+		let start = Instant::now();
+
+		assert!(unpaid.matches_view(&job_view, &Taxes::default()).unwrap());
+		assert!(paid.matches_view(&job_view, &Taxes::default()).unwrap());
+		assert!(!Job {every_invoice: true, ..unpaid.clone()}.matches_view(&job_view, &Taxes::default()).unwrap());
+		assert!(!issued_unpaid.matches_view(&job_view, &Taxes::default()).unwrap());
+
+		assert!(paid.matches(&job).unwrap());
+		assert!(!Job {every_invoice: true, ..paid}.matches_view(&job_view, &Taxes::default()).unwrap());
+		assert!(!issued_unpaid.matches(&job).unwrap());
+
+		println!("\n>>>>> Job::matches_invoices {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / 7);
+	}
+
+	#[test]
+	fn matches_taxes()
+	{
+		let job_view = job_view();
+		let taxes = Taxes {rates: vec![Tax::Rate {name: "VAT".into(), percent: Decimal::new(10, 0)}], ..Default::default()};
+
+		let owed = Job
+		{
+			invoices: Invoice {balance: Match::EqualTo(Borrowed(&Money::new(22_00, 2, Currency::USD))), ..Default::default()},
+			..Default::default()
+		};
+
+		let start = Instant::now();
+
+		// The unissued invoice is only taxed by the configured taxes.
+		assert!(owed.matches_view(&job_view, &taxes).unwrap());
+		assert!(!owed.matches_view(&job_view, &Taxes::default()).unwrap());
+
+		// Without the taxes of the client, the balance of the unissued invoice is not known.
+		assert!(owed.matches(&(&job_view).into()).unwrap());
+
+		println!("\n>>>>> Job::matches_taxes {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / 3);
+	}
+}
@@ -1,17 +1,25 @@
+pub mod billing;
+pub mod check;
 pub mod create;
+pub mod credit;
+pub mod receive;
 pub mod retrieve;
 pub mod time;
 
 use
 {
+	billing::Billing,
+	check::Check,
 	create::Create,
+	credit::Credit,
+	receive::Receive,
 	retrieve::Retrieve,
 	time::Time,
 
 	crate::{Config, DynResult, StructOpt},
 
-	clinvoice_adapter::data::Updatable,
-	clinvoice_config::Result as ConfigResult,
+	clinvoice_adapter::{data::Updatable, Error as AdapterError, Store},
+	clinvoice_config::{Error as ConfigError, Result as ConfigResult},
 
 	dialoguer::Editor,
 };
@@ -25,8 +33,14 @@ pub const QUERY_PROMPT: &str = "See the documentation of this query at https://g
 #[structopt(name="clinvoice", about="CLInvoice is a tool to help with invoicing from the command line!")]
 pub struct App
 {
-	#[structopt(default_value="default", help="A store from the configuration file which operations should be performed on", long, short)]
-	store: String,
+	#[structopt(
+		default_value="default",
+		help="A store from the configuration file which operations should be performed on\nMay be a glob, and may be given more than once to select several stores",
+		long,
+		number_of_values=1,
+		short,
+	)]
+	store: Vec<String>,
 
 	#[structopt(subcommand)]
 	command: AppCommand,
@@ -35,11 +49,19 @@ pub struct App
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, StructOpt)]
 enum AppCommand
 {
+	Billing(Billing),
+
+	Check(Check),
+
 	#[structopt(about="Edit the configuration file in the default editor")]
 	Config,
 
 	Create(Create),
 
+	Credit(Credit),
+
+	Receive(Receive),
+
 	Retrieve(Retrieve),
 
 	Time(Time),
@@ -62,17 +84,195 @@ impl App
 		Ok(())
 	}
 
+	/// # Summary
+	///
+	/// Whether or not running this [`App`] could modify the contents of its store.
+	fn modifies_store(&self) -> bool
+	{
+		match &self.command
+		{
+			AppCommand::Check(_) | AppCommand::Config => false,
+			AppCommand::Billing(_) | AppCommand::Create(_) | AppCommand::Credit(_) | AppCommand::Receive(_) | AppCommand::Time(_) => true,
+			AppCommand::Retrieve(cmd) => cmd.modifies_store(),
+		}
+	}
+
 	/// # Summary
 	///
 	/// Run the application and parse its provided arguments / flags.
 	pub fn run<'config>(self, config: &'config Config) -> DynResult<'config, ()>
 	{
+		let modifies_store = self.modifies_store();
+
 		match self.command
 		{
+			AppCommand::Billing(cmd) => cmd.run(config, Self::select_stores(config, &self.store, modifies_store)?[0].1),
+			AppCommand::Check(cmd) => cmd.run(config, &Self::select_stores(config, &self.store, modifies_store)?),
 			AppCommand::Config => Self::edit_config(config).map_err(|e| e.into()),
-			AppCommand::Create(cmd) => cmd.run(config, self.store),
-			AppCommand::Retrieve(cmd) => cmd.run(config, self.store),
-			AppCommand::Time(cmd) => cmd.run(config, self.store),
+			AppCommand::Create(cmd) => cmd.run(config, Self::select_stores(config, &self.store, modifies_store)?[0].1),
+			AppCommand::Credit(cmd) => cmd.run(config, Self::select_stores(config, &self.store, modifies_store)?[0].1),
+			AppCommand::Receive(cmd) => cmd.run(config, Self::select_stores(config, &self.store, modifies_store)?[0].1),
+			AppCommand::Retrieve(cmd) => cmd.run(config, Self::select_stores(config, &self.store, modifies_store)?),
+			AppCommand::Time(cmd) => cmd.run(config, Self::select_stores(config, &self.store, modifies_store)?[0].1),
 		}
 	}
+
+	/// # Summary
+	///
+	/// Select the [`Store`]s which match some `patterns`.
+	///
+	/// # Errors
+	///
+	/// When `modifies_store`, the operation is rejected before the user is prompted for anything if
+	/// more than one [`Store`] was selected, or the selected [`Store`] is
+	/// [read-only](AdapterError::ReadOnly).
+	fn select_stores<'config>(config: &'config Config, patterns: &[String], modifies_store: bool)
+		-> DynResult<'config, Vec<(&'config str, &'config Store)>>
+	{
+		let stores = config.get_stores(patterns)?;
+
+		if modifies_store
+		{
+			if stores.len() > 1
+			{
+				return Err(ConfigError::TooManyStores(
+					stores.iter().map(|(name, _)| *name).collect::<Vec<_>>().join(", ")
+				).into());
+			}
+
+			if stores.iter().any(|(_, store)| store.read_only)
+			{
+				return Err(AdapterError::ReadOnly.into());
+			}
+		}
+
+		Ok(stores)
+	}
+}
+
+#[cfg(test)]
+mod tests
+{
+	use
+	{
+		std::time::Instant,
+
+		super::{AdapterError, App, Config, ConfigError, StructOpt},
+	};
+
+	/// # Summary
+	///
+	/// A [`Config`] with two stores which may be written to, and one which is read-only.
+	const CONFIG: &str = r#"
+		[stores]
+		archive = {adapter="Bincode", path="archive/path", read_only=true}
+		client_a = {adapter="Bincode", path="a/path"}
+		client_b = {adapter="Bincode", path="b/path"}
+
+		[employees]
+		default_id = "95d053c5-4be5-45bf-90f6-5e63cb43fb9c"
+
+		[invoices]
+		default_currency = "USD"
+
+		[timesheets]
+		interval = "5min"
+	"#;
+
+	/// # Summary
+	///
+	/// Arguments for every command which could modify the contents of a store.
+	const MODIFYING: &[&[&str]] = &[
+		&["billing", "run"],
+		&["create", "employee", "Janitor"],
+		&["create", "job", "20.00"],
+		&["create", "location", "Arizona"],
+		&["create", "organization", "FooCorp"],
+		&["create", "person", "John"],
+		&["credit"],
+		&["credit", "--refund"],
+		&["receive"],
+		&["receive", "--settle"],
+		&["retrieve", "--delete", "employee"],
+		&["retrieve", "--delete", "job"],
+		&["retrieve", "--delete", "location"],
+		&["retrieve", "--delete", "organization"],
+		&["retrieve", "--delete", "person"],
+		&["retrieve", "--update", "employee"],
+		&["retrieve", "--update", "job"],
+		&["retrieve", "--update", "location"],
+		&["retrieve", "--update", "organization"],
+		&["retrieve", "--update", "person"],
+		&["retrieve", "employee", "--change"],
+		&["retrieve", "job", "--bill-to"],
+		&["retrieve", "job", "--close"],
+		&["retrieve", "job", "--issue"],
+		&["retrieve", "job", "--reopen"],
+		&["retrieve", "location", "--create-inner", "Phoenix"],
+		&["retrieve", "organization", "--bill-to"],
+		&["time", "start"],
+		&["time", "start", "--non-billable"],
+		&["time", "stop"],
+	];
+
+	/// # Summary
+	///
+	/// Arguments for commands which only read the contents of a store.
+	const READING: &[&[&str]] = &[
+		&["check"],
+		&["config"],
+		&["retrieve", "employee"],
+		&["retrieve", "employee", "--default"],
+		&["retrieve", "employee", "--set-default"],
+		&["retrieve", "job"],
+		&["retrieve", "job", "--export", "markdown"],
+		&["retrieve", "job", "--overdue"],
+		&["retrieve", "location"],
+		&["retrieve", "organization", "--issuer"],
+		&["retrieve", "organization", "--set-issuer"],
+		&["retrieve", "person"],
+	];
+
+	/// # Summary
+	///
+	/// Parse the `args` of an [`App`] which operates on the stores matching some `store` pattern.
+	fn app(store: &str, args: &[&str]) -> App
+	{
+		App::from_iter_safe(["clinvoice", "--store", store].iter().chain(args)).unwrap()
+	}
+
+	#[test]
+	fn modifies_store()
+	{
+		let start = Instant::now();
+		MODIFYING.iter().for_each(|args| assert!(app("client_a", args).modifies_store(), "{:?}", args));
+		READING.iter().for_each(|args| assert!(!app("client_a", args).modifies_store(), "{:?}", args));
+		println!("\n>>>>> App::modifies_store {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / (MODIFYING.len() + READING.len()) as u128);
+	}
+
+	#[test]
+	fn select_stores()
+	{
+		let config: Config = toml::from_str(CONFIG).unwrap();
+		let read_only = AdapterError::ReadOnly.to_string();
+		let too_many = ConfigError::TooManyStores("client_a, client_b".into()).to_string();
+
+		let start = Instant::now();
+
+		// Commands which modify the store are rejected by `run` before they can prompt for anything.
+		MODIFYING.iter().for_each(|args|
+		{
+			assert_eq!(app("archive", args).run(&config).unwrap_err().to_string(), read_only, "{:?}", args);
+			assert_eq!(app("client_*", args).run(&config).unwrap_err().to_string(), too_many, "{:?}", args);
+		});
+
+		// Commands which only read may use any number of stores, even if they are read-only.
+		READING.iter().for_each(|args|
+		{
+			let app = app("*", args);
+			assert_eq!(App::select_stores(&config, &app.store, app.modifies_store()).unwrap().len(), 3, "{:?}", args);
+		});
+
+		println!("\n>>>>> App::select_stores {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / (MODIFYING.len() * 2 + READING.len()) as u128);
+	}
 }
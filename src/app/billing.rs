@@ -0,0 +1,97 @@
+use
+{
+	crate::{Config, DynResult, input, StructOpt},
+
+	clinvoice_adapter::
+	{
+		Adapters, Error as AdapterError, Store,
+		data::{JobAdapter, Updatable},
+	},
+	clinvoice_data::
+	{
+		chrono::{DateTime, Local, Utc},
+		BillingModel, Id, Invoice, InvoiceDate, Job,
+	},
+	clinvoice_query as query,
+};
+
+#[cfg(feature="bincode")]
+use clinvoice_adapter_bincode::data::{BincodeEmployee, BincodeJob, BincodeLocation, BincodeOrganization, BincodePerson};
+
+#[cfg(feature="journal")]
+use clinvoice_adapter_journal::data::{JournalEmployee, JournalJob, JournalLocation, JournalOrganization, JournalPerson};
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, StructOpt)]
+#[structopt(about="Bill jobs which recur every period, such as retainers")]
+pub(super) enum Billing
+{
+	#[structopt(about="Issue an invoice for every period of a retainer which has ended, but has not been billed yet")]
+	Run,
+}
+
+impl Billing
+{
+	/// # Summary
+	///
+	/// Execute the constructed command.
+	pub(super) fn run<'err>(self, config: &Config, store: &Store) -> DynResult<'err, ()>
+	{
+		macro_rules! run
+		{
+			($emp: ident, $job: ident, $loc: ident, $org: ident, $per: ident) =>
+			{{
+				let issued = Utc::now();
+				let mut retainers = input::util::job::views::<$emp, $job, $loc, $org, $per>(&query::Job::default(), &config.invoices.taxes, store)?
+					.into_iter()
+					.filter(|j| matches!(j.billing_model, BillingModel::Retainer(_)));
+
+				retainers.try_for_each(|j| -> DynResult<()>
+				{
+					let client_id = if config.invoices.number_per_client { Some(j.client.id) } else { None };
+					let mut job = Job::from(&j);
+
+					while let Some((begin, end)) = job.next_retainer_period().filter(|(_, end)| *end <= issued)
+					{
+						let invoice = Invoice
+						{
+							credit_notes: Vec::new(),
+							date: Some(InvoiceDate {issued, paid: None}),
+							id: Id::new_v4(),
+							number: Some(config.invoices.number(issued, $job::next_invoice_seq(client_id, store)?)),
+							payment_terms: Some(j.applicable_payment_terms(None, config.invoices.payment_terms)),
+							payments: Vec::new(),
+							taxes: Some(j.applicable_taxes(None, &config.invoices.taxes).clone()),
+						};
+
+						println!("Issued {} to {} for {} – {}",
+							invoice.number.as_deref().unwrap_or_default(),
+							j.client.name,
+							DateTime::<Local>::from(begin).naive_local(),
+							DateTime::<Local>::from(end).naive_local(),
+						);
+
+						job.bill(invoice, issued);
+
+						// The next invoice number depends on this one having been stored.
+						$job {job: &job, store}.update()?;
+					}
+
+					Ok(())
+				})?;
+			}};
+		}
+
+		match store.adapter
+		{
+			#[cfg(feature="bincode")]
+			Adapters::Bincode => run!(BincodeEmployee, BincodeJob, BincodeLocation, BincodeOrganization, BincodePerson),
+
+			#[cfg(feature="journal")]
+			Adapters::Journal => run!(JournalEmployee, JournalJob, JournalLocation, JournalOrganization, JournalPerson),
+
+			_ => return Err(AdapterError::FeatureNotFound(store.adapter).into()),
+		};
+
+		Ok(())
+	}
+}
@@ -0,0 +1,98 @@
+use
+{
+	crate::{Config, DynResult, input, StructOpt},
+
+	clinvoice_adapter::{Adapters, Error as AdapterError, Store},
+	clinvoice_data::views::EmployeeView,
+};
+
+#[cfg(feature="bincode")]
+use clinvoice_adapter_bincode::data::{BincodeEmployee, BincodeLocation, BincodeOrganization, BincodePerson};
+
+#[cfg(feature="journal")]
+use clinvoice_adapter_journal::data::{JournalEmployee, JournalLocation, JournalOrganization, JournalPerson};
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, StructOpt)]
+#[structopt(about="Check the records in a store for problems, such as contact information which is not valid")]
+pub(super) struct Check {}
+
+impl Check
+{
+	/// # Summary
+	///
+	/// Report every piece of contact information of some `employees` which is not
+	/// [valid](input::util::contact::validate), or which is not written the way it would be if it
+	/// were entered now. Each report is tagged with the `store_name`, if there is one.
+	///
+	/// # Returns
+	///
+	/// The number of problems which were reported.
+	fn contact_info(employees: &[EmployeeView], default_region: Option<&str>, store_name: Option<&str>) -> usize
+	{
+		let mut problems = 0;
+
+		employees.iter().for_each(|employee|
+		{
+			let mut labels: Vec<_> = employee.contact_info.keys().collect();
+			labels.sort();
+
+			labels.into_iter().for_each(|label|
+			{
+				let contact = &employee.contact_info[label];
+				let problem = match input::util::contact::validate(contact.clone(), default_region)
+				{
+					Ok(normalized) if normalized != *contact => format!("\"{}\" should be written as \"{}\"", contact, normalized),
+					Ok(_) => return,
+					Err(e) => e.to_string(),
+				};
+
+				problems += 1;
+				match store_name
+				{
+					Some(name) => println!("[{}] {}, {} — {}: {}", name, employee.person.name, employee.title, label, problem),
+					_ => println!("{}, {} — {}: {}", employee.person.name, employee.title, label, problem),
+				};
+			});
+		});
+
+		problems
+	}
+
+	pub(super) fn run<'err>(self, config: &Config, stores: &[(&str, &Store)]) -> DynResult<'err, ()>
+	{
+		let default_region = config.contacts.default_region.as_deref();
+		let mut problems = 0;
+
+		macro_rules! check
+		{
+			($store_name: ident, $store: ident => $emp: ident, $loc: ident, $org: ident, $per: ident) =>
+			{{
+				let employees = input::util::employee::views::<$emp, $loc, $org, $per>(&Default::default(), $store)?;
+				problems += Self::contact_info(&employees, default_region, if stores.len() > 1 { Some($store_name) } else { None });
+			}};
+		}
+
+		for (store_name, store) in stores.iter().copied()
+		{
+			match store.adapter
+			{
+				#[cfg(feature="bincode")]
+				Adapters::Bincode => check!(store_name, store => BincodeEmployee, BincodeLocation, BincodeOrganization, BincodePerson),
+
+				#[cfg(feature="journal")]
+				Adapters::Journal => check!(store_name, store => JournalEmployee, JournalLocation, JournalOrganization, JournalPerson),
+
+				_ => return Err(AdapterError::FeatureNotFound(store.adapter).into()),
+			};
+		}
+
+		match problems
+		{
+			0 => println!("No problems were found"),
+			1 => println!("1 problem was found"),
+			_ => println!("{} problems were found", problems),
+		};
+
+		Ok(())
+	}
+}
@@ -12,12 +12,15 @@ use
 	{
 		chrono::{Datelike, DateTime, Local, Timelike, TimeZone, Utc},
 		finance::{Currency, Decimal, Money},
-		EmployeeStatus, Location,
+		BillingModel, EmployeeStatus, Location, Milestone, Recurrence, Retainer, Rollover,
 	},
 };
 
 #[cfg(feature="bincode")]
-use clinvoice_adapter_bincode::data::{BincodeEmployee, BincodeJob, BincodeLocation, BincodeOrganization, BincodePerson, Error as BincodeError};
+use clinvoice_adapter_bincode::data::{BincodeEmployee, BincodeJob, BincodeLocation, BincodeOrganization, BincodePerson};
+
+#[cfg(feature="journal")]
+use clinvoice_adapter_journal::data::{JournalEmployee, JournalJob, JournalLocation, JournalOrganization, JournalPerson};
 
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, StructOpt)]
 #[structopt(about="Record information information with CLInvoice")]
@@ -33,11 +36,20 @@ pub(super) enum Create
 	#[structopt(about="Create a new job record")]
 	Job
 	{
-		#[structopt(help="The currency which the hourly rate is stated in (e.g. 'USD')\nDefaults to the value set in your config", long, short)]
+		#[structopt(help="The currency which the amount is stated in (e.g. 'USD')\nDefaults to the value set in your config", long, short)]
 		currency: Option<Currency>,
 
-		#[structopt(help="The amount of money charged per hour for this job (e.g. 12.00)")]
-		hourly_rate: Decimal,
+		#[structopt(help="Charge the amount once for the whole job, rather than per hour", conflicts_with="milestones", long, short)]
+		fixed_price: bool,
+
+		#[structopt(help="Split the amount into milestones, which are charged for once they are complete, rather than per hour", conflicts_with="retainer", long, short)]
+		milestones: bool,
+
+		#[structopt(help="Charge the amount every period as a retainer, which covers some hours of work, rather than per hour", conflicts_with="fixed-price", long, short)]
+		retainer: bool,
+
+		#[structopt(help="The amount of money charged per hour for this job (e.g. 12.00)\nWhen billing a fixed price or milestones, the amount charged for the whole job\nWhen billing a retainer, the amount charged every period")]
+		amount: Decimal,
 
 		#[structopt(help="The year that the job was created (e.g. 2021)\nDefaults to current year", requires("month"))]
 		year: Option<i32>,
@@ -79,7 +91,7 @@ pub(super) enum Create
 
 impl Create
 {
-	fn create_employee<'err, E, L, O, P>(title: String, store: &Store) -> DynResult<'err, ()> where
+	fn create_employee<'err, E, L, O, P>(title: String, default_region: Option<&str>, store: &Store) -> DynResult<'err, ()> where
 		E : EmployeeAdapter,
 		L : LocationAdapter,
 		O : OrganizationAdapter,
@@ -101,7 +113,7 @@ impl Create
 		let person_views = input::util::person::retrieve_views::<&str, P>("Query the `Person` who this `Employee` is", true, store)?;
 		let person = input::select_one(&person_views, "Which `Person` is this `Employee`?")?;
 
-		let contact_info = input::util::contact::menu::<L>(store)?;
+		let contact_info = input::util::contact::menu::<L>(default_region, store)?;
 		let employee_status = input::select_one(
 			&[EmployeeStatus::Employed, EmployeeStatus::NotEmployed, EmployeeStatus::Representative],
 			"What is the status of the employee?",
@@ -120,7 +132,7 @@ impl Create
 	}
 
 	fn create_job<'err, J, L, O>(
-		hourly_rate: Money,
+		billing_model: BillingModel,
 		year: Option<i32>,
 		month: Option<u32>,
 		day: Option<u32>,
@@ -146,6 +158,29 @@ impl Create
 
 		let objectives = input::edit_markdown("* List your objectives\n* All markdown syntax works")?;
 
+		let billing_model = match billing_model
+		{
+			BillingModel::Milestones {currency, milestones} => BillingModel::Milestones
+			{
+				currency,
+				milestones: match input::edit(&milestones, "Split the amount into the milestones of this job")
+				{
+					Ok(m) => m,
+					Err(input::Error::NotEdited) => milestones,
+					Err(e) => return Err(e.into()),
+				},
+			},
+			BillingModel::Retainer(retainer) => BillingModel::Retainer(
+				match input::edit(&retainer, "Set the period, included hours, overage rate, and rollover of this retainer")
+				{
+					Ok(r) => r,
+					Err(input::Error::NotEdited) => retainer,
+					Err(e) => return Err(e.into()),
+				},
+			),
+			b => b,
+		};
+
 		J::create(
 			client.into(),
 			DateTime::<Utc>::from(
@@ -170,7 +205,7 @@ impl Create
 					date.and_hms(hour.unwrap_or_else(|| now.hour()), minute.unwrap_or_else(|| now.minute()), 0)
 				}
 			}),
-			hourly_rate,
+			billing_model,
 			objectives,
 			store,
 		)?;
@@ -213,45 +248,78 @@ impl Create
 		Ok(())
 	}
 
-	pub(super) fn run<'config>(self, config: &'config Config, store_name: String) -> DynResult<'config, ()>
+	pub(super) fn run<'config>(self, config: &'config Config, store: &Store) -> DynResult<'config, ()>
 	{
-		let store = config.get_store(&store_name).expect("Storage name not known");
-
-		match store.adapter
+		macro_rules! create
 		{
-			#[cfg(feature="bincode")]
-			Adapters::Bincode => match self
+			($emp: ident, $job: ident, $loc: ident, $org: ident, $per: ident) =>
 			{
-				Self::Employee {title} =>
-					Self::create_employee::<BincodeEmployee, BincodeLocation, BincodeOrganization, BincodePerson>(title, store),
+				match self
+				{
+					Self::Employee {title} =>
+						Self::create_employee::<$emp, $loc, $org, $per>(title, config.contacts.default_region.as_deref(), store),
 
-				Self::Job {currency, hourly_rate, year, month, day, hour, minute} =>
-					Self::create_job::<BincodeJob, BincodeLocation, BincodeOrganization>(
-						Money
+					Self::Job {currency, fixed_price, milestones, retainer, amount, year, month, day, hour, minute} =>
+					{
+						let currency = currency.unwrap_or(config.invoices.default_currency);
+						let billing_model = if fixed_price
 						{
-							amount: hourly_rate,
-							currency: currency.unwrap_or(config.invoices.default_currency),
-						},
-						year, month, day, hour, minute,
-						store,
-					),
-
-				Self::Location {names} =>
-				{
-					fn create_inner(location: &Location, name: String, store: &Store) -> Result<Location, BincodeError>
+							BillingModel::FixedPrice(Money {amount, currency})
+						}
+						else if milestones
+						{
+							BillingModel::Milestones
+							{
+								currency,
+								milestones: vec![Milestone {amount, date_complete: None, invoice_id: None, name: "Completion".into()}],
+							}
+						}
+						else if retainer
+						{
+							BillingModel::Retainer(Retainer
+							{
+								amount: Money {amount, currency},
+								included_hours: Decimal::default(),
+								overage_rate: Money {amount: Decimal::default(), currency},
+								period: Recurrence::Monthly,
+								periods_billed: Vec::new(),
+								rollover: Rollover::default(),
+							})
+						}
+						else
+						{
+							BillingModel::Hourly(Money {amount, currency})
+						};
+
+						Self::create_job::<$job, $loc, $org>(billing_model, year, month, day, hour, minute, store)
+					},
+
+					Self::Location {names} =>
 					{
-						BincodeLocation {location, store}.create_inner(name)
-					}
+						fn create_inner(location: &Location, name: String, store: &Store) -> Result<Location, <$loc<'static, 'static> as LocationAdapter>::Error>
+						{
+							$loc {location, store}.create_inner(name)
+						}
 
-					Self::create_location::<BincodeLocation>(create_inner, names, store).map_err(|e| e.into())
-				},
+						Self::create_location::<$loc>(create_inner, names, store).map_err(|e| e.into())
+					},
 
-				Self::Organization {name} =>
-					Self::create_organization::<BincodeLocation, BincodeOrganization>(name, store),
+					Self::Organization {name} =>
+						Self::create_organization::<$loc, $org>(name, store),
 
-				Self::Person {name} =>
-					BincodePerson::create(name, store).and(Ok(())).map_err(|e| e.into()),
-			},
+					Self::Person {name} =>
+						$per::create(name, store).and(Ok(())).map_err(|e| e.into()),
+				}
+			};
+		}
+
+		match store.adapter
+		{
+			#[cfg(feature="bincode")]
+			Adapters::Bincode => create!(BincodeEmployee, BincodeJob, BincodeLocation, BincodeOrganization, BincodePerson),
+
+			#[cfg(feature="journal")]
+			Adapters::Journal => create!(JournalEmployee, JournalJob, JournalLocation, JournalOrganization, JournalPerson),
 
 			_ => return Err(Error::FeatureNotFound(store.adapter).into()),
 		}?;
@@ -0,0 +1,155 @@
+use
+{
+	crate::{Config, DynResult, input, StructOpt},
+
+	clinvoice_adapter::
+	{
+		Adapters, Error as AdapterError, Store,
+		data::{Error as DataError, JobAdapter, Updatable},
+	},
+	clinvoice_data::
+	{
+		chrono::Utc,
+		CreditNote, Id, Job, Payment,
+		views::JobView,
+	},
+};
+
+#[cfg(feature="bincode")]
+use clinvoice_adapter_bincode::data::{BincodeEmployee, BincodeJob, BincodeLocation, BincodeOrganization, BincodePerson};
+
+#[cfg(feature="journal")]
+use clinvoice_adapter_journal::data::{JournalEmployee, JournalJob, JournalLocation, JournalOrganization, JournalPerson};
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, StructOpt)]
+#[structopt(about="Issue a credit note which corrects an invoice that was already issued")]
+pub(super) struct Credit
+{
+	#[structopt(help="Also record a refund of whatever the client overpaid once the credit note is issued", long, short)]
+	pub refund: bool,
+}
+
+impl Credit
+{
+	/// # Summary
+	///
+	/// Issue a [`CreditNote`] against one of the issued [`Invoice`]s of some `job`.
+	///
+	/// # Returns
+	///
+	/// The [`CreditNote`] which was issued, so that it may be numbered once it has been confirmed.
+	///
+	/// # Remarks
+	///
+	/// If the [`CreditNote`] leaves nothing to pay on an [`Invoice`] which has not been paid, the
+	/// [`Invoice`] is marked as paid.
+	fn credit<'err, 'job>(&self, config: &Config, job: &'job mut JobView) -> DynResult<'err, &'job mut CreditNote>
+	{
+		let issued: Vec<_> = job.invoices.iter().filter(|i| i.date.is_some()).cloned().collect();
+		let selected = input::select_one(&issued, "Which `Invoice` is being corrected?")?;
+		let index = job.invoices.iter().position(|i| i.id == selected.id).unwrap();
+
+		let taxes = job.applicable_taxes(Some(&selected), &config.invoices.taxes);
+		let date = Utc::now();
+		let default = CreditNote
+		{
+			date,
+			id: Id::new_v4(),
+			lines: Job::from(&*job).credit_lines(&selected, taxes)?,
+			number: "".into(),
+			reason: "".into(),
+		};
+
+		let credit_note = match input::edit(&default, format!("Describe what is credited against {} (leave the `number` empty to use the next one)", selected))
+		{
+			Ok(c) => c,
+			Err(input::Error::NotEdited) => default,
+			Err(e) => return Err(e.into()),
+		};
+
+		let date = credit_note.date;
+		job.invoices[index].credit_notes.push(credit_note);
+
+		let invoice = &job.invoices[index];
+		let balance = Job::from(&*job).invoice_balance(invoice, job.applicable_taxes(Some(invoice), &config.invoices.taxes))?;
+
+		if self.refund && balance.amount < 0.into()
+		{
+			let default = Payment
+			{
+				amount: balance,
+				date: Utc::now(),
+				method: "".into(),
+				reference: "".into(),
+			};
+
+			let refund = match input::edit(&default, format!("Record the refund of what was overpaid on {}", invoice))
+			{
+				Ok(p) => p,
+				Err(input::Error::NotEdited) => default,
+				Err(e) => return Err(e.into()),
+			};
+
+			job.invoices[index].payments.push(refund);
+		}
+
+		if balance.amount <= 0.into()
+		{
+			if let Some(invoice_date) = job.invoices[index].date.as_mut().filter(|d| d.paid.is_none())
+			{
+				invoice_date.paid = Some(date);
+			}
+		}
+
+		Ok(job.invoices[index].credit_notes.last_mut().unwrap())
+	}
+
+	/// # Summary
+	///
+	/// Execute the constructed command.
+	pub(super) fn run<'err>(self, config: &Config, store: &Store) -> DynResult<'err, ()>
+	{
+		macro_rules! retrieve
+		{
+			($emp: ident, $job: ident, $loc: ident, $org: ident, $per: ident) =>
+			{{
+				let job_results_view: Vec<_> = input::util::job::retrieve_views::<&str, $emp, $job, $loc, $org, $per>(
+					"Query the `Job` which is being corrected",
+					true,
+					&config.invoices.taxes,
+					store,
+				)?.into_iter().filter(|j| j.invoices.iter().any(|i| i.date.is_some())).collect();
+
+				if job_results_view.is_empty()
+				{
+					return Err(DataError::NoData(format!("`{}`s with an issued `{}`", stringify!(Job), stringify!(Invoice))).into());
+				}
+
+				let mut selected_job = input::select_one(&job_results_view, "Select the job which is being corrected")?;
+				let client_id = if config.invoices.number_per_client { Some(selected_job.client.id) } else { None };
+				let credit_note = self.credit(config, &mut selected_job)?;
+
+				// The number is only taken once nothing else can be cancelled, so that none are skipped.
+				if credit_note.number.is_empty()
+				{
+					credit_note.number = config.invoices.credit_note_number(credit_note.date, $job::next_credit_note_seq(client_id, store)?);
+				}
+
+				$job {job: &(selected_job.into()), store}.update()?;
+			}};
+		}
+
+		match store.adapter
+		{
+			#[cfg(feature="bincode")]
+			Adapters::Bincode => retrieve!(BincodeEmployee, BincodeJob, BincodeLocation, BincodeOrganization, BincodePerson),
+
+			#[cfg(feature="journal")]
+			Adapters::Journal => retrieve!(JournalEmployee, JournalJob, JournalLocation, JournalOrganization, JournalPerson),
+
+			_ => return Err(AdapterError::FeatureNotFound(store.adapter).into()),
+		};
+
+		Ok(())
+	}
+}
@@ -0,0 +1,133 @@
+use
+{
+	crate::{Config, DynResult, input, StructOpt},
+
+	clinvoice_adapter::
+	{
+		Adapters, Error as AdapterError, Store,
+		data::{Error as DataError, Updatable},
+	},
+	clinvoice_data::
+	{
+		chrono::Utc,
+		Invoice, Job, Payment,
+		views::JobView,
+	},
+};
+
+#[cfg(feature="bincode")]
+use clinvoice_adapter_bincode::data::{BincodeEmployee, BincodeJob, BincodeLocation, BincodeOrganization, BincodePerson};
+
+#[cfg(feature="journal")]
+use clinvoice_adapter_journal::data::{JournalEmployee, JournalJob, JournalLocation, JournalOrganization, JournalPerson};
+
+#[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, StructOpt)]
+#[structopt(about="Record a payment which was received towards an invoice")]
+pub(super) struct Receive
+{
+	#[structopt(help="Mark the invoice as paid even if the payment does not cover its balance (e.g. to write off the rest)", long, short)]
+	pub settle: bool,
+}
+
+impl Receive
+{
+	/// # Summary
+	///
+	/// Record a [`Payment`] towards one of the issued [`Invoice`]s of some `job` which still has a
+	/// balance due.
+	///
+	/// # Remarks
+	///
+	/// If the [`Payment`] covers the balance of the [`Invoice`], the [`Invoice`] is marked as paid.
+	fn receive<'err>(&self, config: &Config, job: &mut JobView) -> DynResult<'err, ()>
+	{
+		let outstanding = Self::outstanding(config, job)?;
+		let selected = input::select_one(&outstanding, "Which `Invoice` was paid?")?;
+		let index = job.invoices.iter().position(|i| i.id == selected.id).unwrap();
+
+		let balance = Job::from(&*job).invoice_balance(&selected, job.applicable_taxes(Some(&selected), &config.invoices.taxes))?;
+		let default = Payment
+		{
+			amount: balance,
+			date: Utc::now(),
+			method: "".into(),
+			reference: "".into(),
+		};
+
+		let payment = match input::edit(&default, format!("Record the payment towards {} which was received", selected))
+		{
+			Ok(p) => p,
+			Err(input::Error::NotEdited) => default,
+			Err(e) => return Err(e.into()),
+		};
+
+		let date = payment.date;
+		job.invoices[index].payments.push(payment);
+
+		let invoice = &job.invoices[index];
+		if self.settle || Job::from(&*job).invoice_balance(invoice, job.applicable_taxes(Some(invoice), &config.invoices.taxes))?.amount <= 0.into()
+		{
+			if let Some(invoice_date) = job.invoices[index].date.as_mut()
+			{
+				invoice_date.paid = Some(date);
+			}
+		}
+
+		Ok(())
+	}
+
+	/// # Summary
+	///
+	/// Get the [`Invoice`]s of some `job` which have been issued, but not paid.
+	fn outstanding<'err>(config: &Config, job: &JobView) -> DynResult<'err, Vec<Invoice>>
+	{
+		let outstanding: Vec<_> = job.invoices.iter().filter(|i| i.date.as_ref().map(|d| d.paid.is_none()).unwrap_or(false)).cloned().collect();
+
+		if outstanding.is_empty()
+		{
+			return Err(DataError::NoData(format!("outstanding `{}`s on {}", stringify!(Invoice), job.id)).into());
+		}
+
+		// Make sure that the balance of each invoice can be calculated before asking for a payment.
+		outstanding.iter().try_for_each(|i| Job::from(job).invoice_balance(i, job.applicable_taxes(Some(i), &config.invoices.taxes)).map(|_| ()))?;
+
+		Ok(outstanding)
+	}
+
+	/// # Summary
+	///
+	/// Execute the constructed command.
+	pub(super) fn run<'err>(self, config: &Config, store: &Store) -> DynResult<'err, ()>
+	{
+		macro_rules! retrieve
+		{
+			($emp: ident, $job: ident, $loc: ident, $org: ident, $per: ident) =>
+			{{
+				let job_results_view: Vec<_> = input::util::job::retrieve_views::<&str, $emp, $job, $loc, $org, $per>(
+					"Query the `Job` which was paid for",
+					true,
+					&config.invoices.taxes,
+					store,
+				)?.into_iter().filter(|j| j.invoices.iter().any(|i| i.date.as_ref().map(|d| d.paid.is_none()).unwrap_or(false))).collect();
+
+				let mut selected_job = input::select_one(&job_results_view, "Select the job which was paid for")?;
+				self.receive(config, &mut selected_job)?;
+
+				$job {job: &(selected_job.into()), store}.update()?;
+			}};
+		}
+
+		match store.adapter
+		{
+			#[cfg(feature="bincode")]
+			Adapters::Bincode => retrieve!(BincodeEmployee, BincodeJob, BincodeLocation, BincodeOrganization, BincodePerson),
+
+			#[cfg(feature="journal")]
+			Adapters::Journal => retrieve!(JournalEmployee, JournalJob, JournalLocation, JournalOrganization, JournalPerson),
+
+			_ => return Err(AdapterError::FeatureNotFound(store.adapter).into()),
+		};
+
+		Ok(())
+	}
+}
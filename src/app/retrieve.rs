@@ -1,17 +1,28 @@
 use
 {
 	core::fmt::Display,
-	std::{error::Error, fs},
+	std::{error::Error, fs, path::{Path, PathBuf}},
 
+	std::{borrow::Cow::Owned, collections::{BTreeMap, BTreeSet, HashSet}},
+
+	super::QUERY_PROMPT,
 	crate::{Config, DynResult, input, StructOpt},
 
 	clinvoice_adapter::
 	{
-		Adapters, Error as AdapterError,
-		data::{Deletable, Error as DataError, LocationAdapter, Updatable},
+		Adapters, Error as AdapterError, Store,
+		data::{Deletable, Error as DataError, JobAdapter, LocationAdapter, Updatable},
+	},
+	clinvoice_config::Error as ConfigError,
+	clinvoice_data::
+	{
+		chrono::Utc,
+		finance::{Currency, Decimal, Money},
+		Attachment, EmploymentChange, Id, Invoice, InvoiceDate, Job, Location, Overtime, Taxes,
+		views::{JobView, RestorableSerde},
 	},
-	clinvoice_data::{chrono::Utc, Location, views::RestorableSerde},
 	clinvoice_export::Target,
+	clinvoice_query as query,
 
 	serde::{de::DeserializeOwned, Serialize},
 };
@@ -19,6 +30,9 @@ use
 #[cfg(feature="bincode")]
 use clinvoice_adapter_bincode::data::{BincodeEmployee, BincodeJob, BincodeLocation, BincodeOrganization, BincodePerson};
 
+#[cfg(feature="journal")]
+use clinvoice_adapter_journal::data::{JournalEmployee, JournalJob, JournalLocation, JournalOrganization, JournalPerson};
+
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, StructOpt)]
 #[structopt(about="Retrieve information that was recorded with CLInvoice")]
 pub(super) struct Retrieve
@@ -42,6 +56,9 @@ pub(super) enum RetrieveCommand
 	#[structopt(about="Retrieve existing records about employees")]
 	Employee
 	{
+		#[structopt(help="Select employees to record a change to their status or title, such as a promotion or leaving the organization", long, short)]
+		change: bool,
+
 		#[structopt(help="Retrieve the default employee as specified in your configuration", long, short)]
 		default: bool,
 
@@ -52,12 +69,24 @@ pub(super) enum RetrieveCommand
 	#[structopt(about="Retrieve existing records about job")]
 	Job
 	{
+		#[structopt(help="Select jobs to set who their invoices are addressed to, and where they are sent, instead of their client", long, short)]
+		bill_to: bool,
+
 		#[structopt(help="Select jobs to be closed", long, short)]
 		close: bool,
 
 		#[structopt(help="Export retrieved entities to the specified format\nSupported: markdown", long, short)]
 		export: Option<Target>,
 
+		#[structopt(help="Select jobs to issue the next invoice for, billing all of their unbilled work", long, short)]
+		issue: bool,
+
+		#[structopt(help="Report the overdue invoices of retrieved jobs, sorted by how many days past due they are", long, short)]
+		overdue: bool,
+
+		#[structopt(help="Copy the receipts attached to the expenses of exported invoices into a folder beside each one. See -e", long)]
+		receipts: bool,
+
 		#[structopt(help="Select jobs to be reopened", long, short)]
 		reopen: bool,
 	},
@@ -70,7 +99,20 @@ pub(super) enum RetrieveCommand
 	},
 
 	#[structopt(about="Retrieve existing records about organizations")]
-	Organization,
+	Organization
+	{
+		#[structopt(help="Select organizations to set who invoices are addressed to, and where they are sent, when they are a client", long, short)]
+		bill_to: bool,
+
+		#[structopt(help="Select organizations to replace the holidays of their overtime with the dates in a calendar file", long, parse(from_os_str))]
+		holidays: Option<PathBuf>,
+
+		#[structopt(help="Retrieve the organization which issues invoices, as specified in your configuration", long, short)]
+		issuer: bool,
+
+		#[structopt(help="Set one of the organizations as the one which issues invoices in your configuration", long, short)]
+		set_issuer: bool,
+	},
 
 	#[structopt(about="Retrieve existing records about people")]
 	Person,
@@ -78,6 +120,21 @@ pub(super) enum RetrieveCommand
 
 impl Retrieve
 {
+	/// # Summary
+	///
+	/// Whether or not this command could modify the contents of a store.
+	pub(super) fn modifies_store(&self) -> bool
+	{
+		self.delete || self.update || match &self.command
+		{
+			RetrieveCommand::Employee {change, ..} => *change,
+			RetrieveCommand::Job {bill_to, close, issue, reopen, ..} => *bill_to || *close || *issue || *reopen,
+			RetrieveCommand::Location {create_inner} => !create_inner.is_empty(),
+			RetrieveCommand::Organization {bill_to, holidays, ..} => *bill_to || holidays.is_some(),
+			_ => false,
+		}
+	}
+
 	/// # Summary
 	///
 	/// Delete some `entities`
@@ -88,7 +145,128 @@ impl Retrieve
 		T : Clone + Display,
 	{
 		let selection = input::select(entities, "Select the entities you want to delete")?;
-		selection.into_iter().try_for_each(|entity| delete_entity(entity)).map_err(|e| e.into())
+		selection.into_iter().try_for_each(delete_entity).map_err(|e| e.into())
+	}
+
+	/// # Summary
+	///
+	/// Add each [`Invoice`] of some `jobs` which is past its due date, but has not been paid, to a
+	/// `report` alongside the number of days which it is past due.
+	///
+	/// Each line of the `report` is tagged with the `store_name` it came from if there is one.
+	fn overdue<'err>(jobs: &[JobView], default_taxes: &Taxes, store_name: Option<&str>, report: &mut Vec<(i64, String)>)
+		-> DynResult<'err, ()>
+	{
+		let now = Utc::now();
+		let tag = store_name.map(|name| format!("[{}] ", name)).unwrap_or_default();
+
+		jobs.iter().try_for_each(|j| j.invoices.iter()
+			.filter(|i| i.date.as_ref().map(|d| d.paid.is_none()).unwrap_or(false))
+			.filter_map(|i| i.date_due().filter(|due| *due < now).map(|due| (i, due)))
+			.try_for_each(|(i, due)| -> DynResult<'err, ()>
+			{
+				let days = now.signed_duration_since(due).num_days();
+				let balance = Job::from(j).invoice_balance(i, j.applicable_taxes(Some(i), default_taxes))?;
+				report.push((days, format!("{}Invoice {} for {} (Job #{}): {} days past due, {} owed",
+					tag,
+					i.number.clone().unwrap_or_else(|| i.id.to_string()),
+					j.client.name,
+					j.id,
+					days,
+					balance,
+				)));
+
+				Ok(())
+			})
+		)
+	}
+
+	/// # Summary
+	///
+	/// Print some `entities`, tagging each with the `store_name` it came from if there is one.
+	fn print<T>(entities: &[T], store_name: Option<&str>) where
+		T : Display,
+	{
+		match store_name
+		{
+			Some(name) => entities.iter().for_each(|e| println!("[{}] {}", name, e)),
+			_ => entities.iter().for_each(|e| println!("{}", e)),
+		};
+	}
+
+	/// # Summary
+	///
+	/// Copy the [`Attachment`]s of the billable expenses of some `job` which were billed on the
+	/// [`Invoice`] with some `invoice_id` (or have not been billed, if there is none) into a
+	/// `directory`. Nothing is created when there are no such [`Attachment`]s.
+	///
+	/// `read_attachment` determines how the contents of each [`Attachment`] are read.
+	fn receipts<'err, E>(job: &JobView, invoice_id: Option<Id>, directory: &str, read_attachment: impl Fn(&Attachment) -> Result<Vec<u8>, E>)
+		-> DynResult<'err, ()>
+	where
+		E : Error + 'err,
+	{
+		let attachments: BTreeSet<_> = job.timesheets.iter()
+			.filter(|t| t.invoice_id == invoice_id)
+			.flat_map(|t| t.expenses.iter().filter(|e| e.billable))
+			.flat_map(|e| e.attachments.iter())
+			.collect();
+
+		if attachments.is_empty() { return Ok(()); }
+
+		fs::create_dir_all(directory)?;
+
+		let mut names = HashSet::new();
+		attachments.into_iter().try_for_each(|attachment| -> DynResult<'err, ()>
+		{
+			// Different receipts may have the same name, so any after the first are told apart by their hash.
+			let name = if names.insert(attachment.name.as_str()) { attachment.name.clone() }
+			else { format!("{}-{}", &attachment.hash[..8], attachment.name) };
+
+			fs::write(Path::new(directory).join(name), read_attachment(attachment)?)?;
+			Ok(())
+		})
+	}
+
+	/// # Summary
+	///
+	/// Add the total amount owed on some `jobs` to the running `totals`, which are kept separately
+	/// for each [`Currency`]. What is owed on each [`Invoice`] is its
+	/// [balance](Job::invoice_balance), so that payments and credit notes are accounted for.
+	///
+	/// The `default_taxes` are charged on any [`Invoice`] of a [`Job`] which has no [`Taxes`] of
+	/// its own, as well as on the work which has not been billed yet.
+	fn total<'err>(jobs: &[JobView], default_taxes: &Taxes, totals: &mut BTreeMap<Currency, Money>) -> DynResult<'err, ()>
+	{
+		jobs.iter().try_for_each(|j| -> DynResult<'err, ()>
+		{
+			let job = Job::from(j);
+			j.invoices.iter().map(Some).chain(Some(None)).try_for_each(|invoice| -> DynResult<'err, ()>
+			{
+				let taxes = j.applicable_taxes(invoice, default_taxes);
+				let owed = match invoice
+				{
+					Some(i) => job.invoice_balance(i, taxes)?,
+					_ => job.invoice_totals(None, taxes)?.gross,
+				};
+
+				totals.entry(owed.currency).or_insert_with(|| Money::new(0, 2, owed.currency)).amount += owed.amount;
+				Ok(())
+			})
+		})
+	}
+
+	/// # Summary
+	///
+	/// Add the [billable hours](Job::billable_hours) and [hours](Job::hours) worked on some `jobs`
+	/// to the running `utilization`.
+	fn utilization(jobs: &[JobView], utilization: &mut (Decimal, Decimal))
+	{
+		jobs.iter().map(Job::from).for_each(|job|
+		{
+			utilization.0 += job.billable_hours();
+			utilization.1 += job.hours();
+		});
 	}
 
 	/// # Summary
@@ -116,30 +294,49 @@ impl Retrieve
 
 	/// # Summary
 	///
-	/// Execute the constructed command.
-	pub(super) fn run<'err>(self, config: &Config, store_name: String) -> DynResult<'err, ()>
+	/// Execute the constructed command on each of the `stores`.
+	///
+	/// # Remarks
+	///
+	/// When there is more than one [`Store`], the query is only asked for once, each result is
+	/// tagged with the name of the [`Store`] it came from, and the total amount owed on [`Job`]s is
+	/// aggregated across all of them.
+	pub(super) fn run<'err>(self, config: &Config, stores: Vec<(&str, &Store)>) -> DynResult<'err, ()>
 	{
-		let store = config.get_store(&store_name).expect("Storage name not known");
-
-		let adapter_not_enabled = || -> DynResult<'err, ()>
+		let adapter_not_enabled = |store: &Store| -> DynResult<'err, ()>
 		{
 			Err(AdapterError::FeatureNotFound(store.adapter).into())
 		};
 
+		let tag = |store_name| if stores.len() > 1 { Some(store_name) } else { None };
+
 		match self.command
 		{
-			RetrieveCommand::Employee {default, set_default} =>
+			RetrieveCommand::Employee {change, default, set_default} =>
 			{
+				if set_default && stores.len() > 1
+				{
+					return Err(ConfigError::TooManyStores(
+						stores.iter().map(|(name, _)| *name).collect::<Vec<_>>().join(", ")
+					).into());
+				}
+
+				let query = match default
+				{
+					true => query::Employee
+					{
+						id: query::Match::EqualTo(Owned(config.employees.default_id)),
+						..Default::default()
+					},
+					_ => input::edit_default(format!("Query the `Employee` you are looking for\n{}employees", QUERY_PROMPT))?,
+				};
+
 				macro_rules! retrieve
 				{
-					($emp: ident, $loc: ident, $org: ident, $per: ident) =>
+					($store_name: ident, $store: ident => $emp: ident, $loc: ident, $org: ident, $per: ident) =>
 					{{
-						let results_view = input::util::employee::retrieve_views::<&str, $emp, $loc, $org, $per>(
-							if default { Some(config.employees.default_id) } else { None },
-							"Query the `Employee` you are looking for",
-							false,
-							store,
-						)?;
+						let store = $store;
+						let results_view = input::util::employee::views::<$emp, $loc, $org, $per>(&query, store)?;
 
 						if self.delete
 						{
@@ -148,7 +345,48 @@ impl Retrieve
 
 						if self.update
 						{
-							Self::update(&results_view, |e| $emp {employee: &(e.into()), store}.update())?;
+							// The history of an employee's status and title is only changed with `--change`.
+							Self::update(&results_view, |mut e|
+							{
+								if let Some(original) = results_view.iter().find(|o| o.id == e.id)
+								{
+									if e.employment_changes != original.employment_changes || e.status != original.status || e.title != original.title
+									{
+										eprintln!("The status and title of {} were not changed. Use `--change` to record a change to them.", original.person.name);
+									}
+
+									e.employment_changes = original.employment_changes.clone();
+									e.status = original.status;
+									e.title = original.title.clone();
+								}
+
+								$emp {employee: &(e.into()), store}.update()
+							})?;
+						}
+
+						if change
+						{
+							let selected = input::select(&results_view, "Select the Employees whose status or title changed")?;
+							selected.into_iter().try_for_each(|mut e| -> DynResult<()>
+							{
+								let now = Utc::now();
+								let default = EmploymentChange
+								{
+									effective_from: now,
+									status: e.status_on(now),
+									title: e.title_on(now).into(),
+								};
+
+								e.employment_changes.push(match input::edit(&default, format!("Record the change to the status or title of {}", e.person.name))
+								{
+									Ok(c) => c,
+									Err(input::Error::NotEdited) => return Ok(()),
+									Err(err) => return Err(err.into()),
+								});
+
+								$emp {employee: &(e.into()), store}.update()?;
+								Ok(())
+							})?;
 						}
 
 						if set_default
@@ -162,33 +400,41 @@ impl Retrieve
 
 							new_config.update()?;
 						}
-						else if !(self.delete || self.update)
+						else if !(change || self.delete || self.update)
 						{
-							results_view.iter().for_each(|e| println!("{}", e));
+							Self::print(&results_view, tag($store_name));
 						}
 					}};
 				}
 
-				match store.adapter
+				for (store_name, store) in stores.iter().copied()
 				{
-					#[cfg(feature="bincode")]
-					Adapters::Bincode => retrieve!(BincodeEmployee, BincodeLocation, BincodeOrganization, BincodePerson),
+					match store.adapter
+					{
+						#[cfg(feature="bincode")]
+						Adapters::Bincode => retrieve!(store_name, store => BincodeEmployee, BincodeLocation, BincodeOrganization, BincodePerson),
 
-					_ => return adapter_not_enabled(),
-				};
+						#[cfg(feature="journal")]
+						Adapters::Journal => retrieve!(store_name, store => JournalEmployee, JournalLocation, JournalOrganization, JournalPerson),
+
+						_ => return adapter_not_enabled(store),
+					};
+				}
 			},
 
-			RetrieveCommand::Job {close, export, reopen} =>
+			RetrieveCommand::Job {bill_to, close, export, issue, overdue, receipts, reopen} =>
 			{
+				let query: query::Job = input::edit_default(format!("Query the `Job` you are looking for\n{}jobs", QUERY_PROMPT))?;
+				let mut overdue_invoices = Vec::new();
+				let mut totals = BTreeMap::new();
+				let mut utilization = (Decimal::default(), Decimal::default());
+
 				macro_rules! retrieve
 				{
-					($emp: ident, $job: ident, $loc: ident, $org: ident, $per: ident) =>
+					($store_name: ident, $store: ident => $emp: ident, $job: ident, $loc: ident, $org: ident, $per: ident) =>
 					{{
-						let results_view = input::util::job::retrieve_views::<&str, $emp, $job, $loc, $org, $per>(
-							"Query the `Job` you are looking for",
-							false,
-							store,
-						)?;
+						let store = $store;
+						let results_view = input::util::job::views::<$emp, $job, $loc, $org, $per>(&query, &config.invoices.taxes, store)?;
 
 						if self.delete
 						{
@@ -200,6 +446,17 @@ impl Retrieve
 							Self::update(&results_view, |j| $job {job: &(j.into()), store}.update())?;
 						}
 
+						if bill_to
+						{
+							let selected = input::select(&results_view, "Select the Jobs you want to set who their invoices are addressed to")?;
+							selected.into_iter().try_for_each(|mut j| -> DynResult<()>
+							{
+								j.bill_to = input::util::bill_to::menu::<$emp, $loc, $org, $per>(j.client.id, store)?;
+								$job {job: &(j.into()), store}.update()?;
+								Ok(())
+							})?;
+						}
+
 						if close
 						{
 							let unclosed: Vec<_> = results_view.iter().filter(|j| j.date_close.is_none()).cloned().collect();
@@ -222,38 +479,148 @@ impl Retrieve
 							})?;
 						}
 
-						if let Some(target) = export
+						if issue
+						{
+							let issued = Utc::now();
+							let unbilled: Vec<_> = results_view.iter().filter(|j| Job::from(*j).has_unbilled_work(issued)).cloned().collect();
+							let selected = input::select(&unbilled, "Select the Jobs you want to issue the next invoice for")?;
+							selected.into_iter().try_for_each(|j|
+							{
+								let client_id = if config.invoices.number_per_client { Some(j.client.id) } else { None };
+								let invoice = Invoice
+								{
+									credit_notes: Vec::new(),
+									date: Some(InvoiceDate {issued, paid: None}),
+									id: Id::new_v4(),
+									number: Some(config.invoices.number(issued, $job::next_invoice_seq(client_id, store)?)),
+									payment_terms: Some(j.applicable_payment_terms(None, config.invoices.payment_terms)),
+									payments: Vec::new(),
+									taxes: Some(j.applicable_taxes(None, &config.invoices.taxes).clone()),
+								};
+
+								let mut job = Job::from(j);
+								job.bill(invoice, issued);
+								$job {job: &job, store}.update()
+							})?;
+						}
+
+						if let Some(target) = &export
 						{
-							input::select(&results_view, "Select which Jobs you want to export")?.into_iter().try_for_each(|job| -> DynResult<()>
+							let prompt = match tag($store_name)
 							{
-								let exported = target.export_job(&job)?;
-								fs::write(format!("{}--{}{}", job.client.name.replace(' ', "-"), job.id, target.extension()), exported)?;
+								Some(name) => format!("Select which Jobs you want to export from {}", name),
+								_ => "Select which Jobs you want to export".into(),
+							};
+
+							// The organization which issues invoices is expected to be in the same store as the jobs it issues them for.
+							let issuer = match config.organizations.issuer_id
+							{
+								Some(id) => input::util::organization::views::<$loc, $org>(
+									&query::Organization {id: query::Match::EqualTo(Owned(id)), ..Default::default()},
+									store,
+								)?.into_iter().next(),
+								_ => None,
+							};
+
+							input::select(&results_view, prompt)?.into_iter().try_for_each(|job| -> DynResult<()>
+							{
+								let bill_to = input::util::bill_to::view::<$emp, $loc, $org, $per>(&job.applicable_bill_to(), store)?;
+								let client = job.client.name.replace(' ', "-");
+
+								// One document per invoice and credit note, and a draft for whatever has not been billed yet.
+								job.invoices.iter().try_for_each(|invoice| -> DynResult<()>
+								{
+									let exported = target.export_invoice(&job, Some(invoice), issuer.as_ref(), bill_to.as_ref(), &config.invoices.taxes)?;
+									let name = format!("{}--{}", client, invoice.number.clone().unwrap_or_else(|| invoice.id.to_string()).replace(['/', ' '], "-"));
+									fs::write(format!("{}{}", name, target.extension()), exported)?;
+
+									if receipts
+									{
+										Self::receipts(&job, Some(invoice.id), &format!("{}--receipts", name), |a| $job::read_attachment(a, store))?;
+									}
+
+									invoice.credit_notes.iter().try_for_each(|credit_note|
+									{
+										let exported = target.export_credit_note(&job, invoice, credit_note, issuer.as_ref(), bill_to.as_ref(), &config.invoices.taxes);
+										fs::write(format!("{}--{}{}", client, credit_note.number.replace(['/', ' '], "-"), target.extension()), exported)
+									})?;
+
+									Ok(())
+								})?;
+
+								if job.invoices.is_empty() || Job::from(&job).has_unbilled_work(Utc::now())
+								{
+									let exported = target.export_invoice(&job, None, issuer.as_ref(), bill_to.as_ref(), &config.invoices.taxes)?;
+									let name = format!("{}--{}--unbilled", client, job.id);
+									fs::write(format!("{}{}", name, target.extension()), exported)?;
+
+									if receipts
+									{
+										Self::receipts(&job, None, &format!("{}--receipts", name), |a| $job::read_attachment(a, store))?;
+									}
+								}
+
 								Ok(())
 							})?;
 						}
-						else if !(close || self.delete || reopen || self.update)
+						else if overdue
+						{
+							Self::overdue(&results_view, &config.invoices.taxes, tag($store_name), &mut overdue_invoices)?;
+						}
+						else if !(bill_to || close || self.delete || issue || reopen || self.update)
 						{
-							results_view.iter().for_each(|j| println!("{}", j));
+							Self::print(&results_view.iter().map(|j| j.with_totals(&config.invoices.taxes)).collect::<Vec<_>>(), tag($store_name));
+							Self::total(&results_view, &config.invoices.taxes, &mut totals)?;
+							Self::utilization(&results_view, &mut utilization);
 						}
 					}};
 				}
 
-				match store.adapter
+				for (store_name, store) in stores.iter().copied()
 				{
-					#[cfg(feature="bincode")]
-					Adapters::Bincode => retrieve!(BincodeEmployee, BincodeJob, BincodeLocation, BincodeOrganization, BincodePerson),
+					match store.adapter
+					{
+						#[cfg(feature="bincode")]
+						Adapters::Bincode => retrieve!(store_name, store => BincodeEmployee, BincodeJob, BincodeLocation, BincodeOrganization, BincodePerson),
 
-					_ => return adapter_not_enabled(),
-				};
+						#[cfg(feature="journal")]
+						Adapters::Journal => retrieve!(store_name, store => JournalEmployee, JournalJob, JournalLocation, JournalOrganization, JournalPerson),
+
+						_ => return adapter_not_enabled(store),
+					};
+				}
+
+				// Invoices which are the most overdue come first.
+				overdue_invoices.sort_by(|(days1, _), (days2, _)| days2.cmp(days1));
+				overdue_invoices.into_iter().for_each(|(_, report)| println!("{}", report));
+
+				if stores.len() > 1 && !totals.is_empty()
+				{
+					println!("Total Amount Owed across {} stores:", stores.len());
+					totals.values().for_each(|total| println!("\t{}", total));
+				}
+
+				let (mut billable, mut hours) = utilization;
+				if !hours.is_zero()
+				{
+					let mut percent = billable / hours * Decimal::new(100, 0);
+					percent.rescale(0);
+					billable.rescale(2);
+					hours.rescale(2);
+					println!("Billable Utilization: {} of {} hours ({}%)", billable, hours, percent);
+				}
 			},
 
 			RetrieveCommand::Location {create_inner} =>
 			{
+				let query: query::Location = input::edit_default(format!("Query the `Location` you are looking for\n{}locations", QUERY_PROMPT))?;
+
 				macro_rules! retrieve
 				{
-					($loc: ident) =>
+					($store_name: ident, $store: ident => $loc: ident) =>
 					{{
-						let results_view = input::util::location::retrieve_views::<&str, $loc>("Query the `Location` you are looking for", false, store)?;
+						let store = $store;
+						let results_view = input::util::location::views::<$loc>(&query, store)?;
 
 						if self.delete
 						{
@@ -269,7 +636,7 @@ impl Retrieve
 						if let Some(name) = create_inner.last()
 						{
 							let location = input::select_one(&results_view, format!("Select the outer Location of {}", name))?;
-							create_inner.into_iter().rev().try_fold(location.into(),
+							create_inner.iter().rev().cloned().try_fold(location.into(),
 								|loc: Location, name: String| -> Result<Location, <$loc as LocationAdapter>::Error>
 								{
 									$loc {location: &(loc.into()), store}.create_inner(name)
@@ -278,31 +645,56 @@ impl Retrieve
 						}
 						else if !(self.delete || self.update)
 						{
-							results_view.iter().for_each(|l| println!("{}", l));
+							Self::print(&results_view, tag($store_name));
 						}
 					}};
 				}
 
-				match store.adapter
+				for (store_name, store) in stores.iter().copied()
 				{
-					#[cfg(feature="bincode")]
-					Adapters::Bincode => retrieve!(BincodeLocation),
+					match store.adapter
+					{
+						#[cfg(feature="bincode")]
+						Adapters::Bincode => retrieve!(store_name, store => BincodeLocation),
 
-					_ => return adapter_not_enabled(),
-				};
+						#[cfg(feature="journal")]
+						Adapters::Journal => retrieve!(store_name, store => JournalLocation),
+
+						_ => return adapter_not_enabled(store),
+					};
+				}
 			},
 
-			RetrieveCommand::Organization =>
+			RetrieveCommand::Organization {bill_to, ref holidays, issuer, set_issuer} =>
 			{
+				if set_issuer && stores.len() > 1
+				{
+					return Err(ConfigError::TooManyStores(
+						stores.iter().map(|(name, _)| *name).collect::<Vec<_>>().join(", ")
+					).into());
+				}
+
+				// The calendar is read once, and its dates are stored with each organization.
+				let holidays = holidays.as_deref().map(Overtime::read_holidays).transpose()?;
+
+				let query = match issuer
+				{
+					true => query::Organization
+					{
+						id: query::Match::EqualTo(Owned(config.organizations.issuer_id.ok_or_else(||
+							DataError::NoData(format!("`{}` which issues invoices", stringify!(Organization)))
+						)?)),
+						..Default::default()
+					},
+					_ => input::edit_default(format!("Query the `Organization` you are looking for\n{}organizations", QUERY_PROMPT))?,
+				};
+
 				macro_rules! retrieve
 				{
-					($loc: ident, $org: ident) =>
+					($store_name: ident, $store: ident => $emp: ident, $loc: ident, $org: ident, $per: ident) =>
 					{{
-						let results_view = input::util::organization::retrieve_views::<&str, $loc, $org>(
-							"Query the `Organization` you are looking for",
-							false,
-							store,
-						)?;
+						let store = $store;
+						let results_view = input::util::organization::views::<$loc, $org>(&query, store)?;
 
 						if self.delete
 						{
@@ -313,29 +705,71 @@ impl Retrieve
 						{
 							Self::update(&results_view, |o| $org {organization: &(o.into()), store}.update())?;
 						}
-						else if !self.delete
+
+						if bill_to
 						{
-							results_view.iter().for_each(|o| println!("{}", o));
+							let selected = input::select(&results_view, "Select the Organizations you want to set who invoices are addressed to")?;
+							selected.into_iter().try_for_each(|mut o| -> DynResult<()>
+							{
+								o.bill_to = input::util::bill_to::menu::<$emp, $loc, $org, $per>(o.id, store)?;
+								$org {organization: &(o.into()), store}.update()?;
+								Ok(())
+							})?;
+						}
+
+						if let Some(holidays) = &holidays
+						{
+							let selected = input::select(&results_view, "Select the Organizations whose holidays are in the calendar")?;
+							selected.into_iter().try_for_each(|mut o|
+							{
+								o.overtime.get_or_insert_with(Default::default).holidays = holidays.clone();
+								$org {organization: &(o.into()), store}.update()
+							})?;
+						}
+
+						if set_issuer
+						{
+							let mut new_config = config.clone();
+							new_config.organizations.issuer_id = Some(match results_view.len() > 1
+							{
+								false => results_view.first().ok_or_else(|| DataError::NoData(format!("`{}`", stringify!(Organization))))?.id,
+								_ => input::select_one(&results_view, "Which `Organization` issues invoices?")?.id,
+							});
+
+							new_config.update()?;
+						}
+						else if !(bill_to || holidays.is_some() || self.delete || self.update)
+						{
+							Self::print(&results_view, tag($store_name));
 						}
 					}};
 				}
 
-				match store.adapter
+				for (store_name, store) in stores.iter().copied()
 				{
-					#[cfg(feature="bincode")]
-					Adapters::Bincode => retrieve!(BincodeLocation, BincodeOrganization),
+					match store.adapter
+					{
+						#[cfg(feature="bincode")]
+						Adapters::Bincode => retrieve!(store_name, store => BincodeEmployee, BincodeLocation, BincodeOrganization, BincodePerson),
 
-					_ => return adapter_not_enabled(),
-				};
+						#[cfg(feature="journal")]
+						Adapters::Journal => retrieve!(store_name, store => JournalEmployee, JournalLocation, JournalOrganization, JournalPerson),
+
+						_ => return adapter_not_enabled(store),
+					};
+				}
 			},
 
 			RetrieveCommand::Person =>
 			{
+				let query: query::Person = input::edit_default(format!("Query the `Person` you are looking for\n{}persons", QUERY_PROMPT))?;
+
 				macro_rules! retrieve
 				{
-					($per: ident) =>
+					($store_name: ident, $store: ident => $per: ident) =>
 					{{
-						let results_view = input::util::person::retrieve_views::<&str, $per>("Query the `Person` you are looking for", false, store)?;
+						let store = $store;
+						let results_view = input::util::person::views::<$per>(&query, store)?;
 
 						if self.delete
 						{
@@ -348,18 +782,24 @@ impl Retrieve
 						}
 						else if !self.delete
 						{
-							results_view.iter().for_each(|p| println!("{}", p));
+							Self::print(&results_view, tag($store_name));
 						}
 					}};
 				}
 
-				match store.adapter
+				for (store_name, store) in stores.iter().copied()
 				{
-					#[cfg(feature="bincode")]
-					Adapters::Bincode => retrieve!(BincodePerson),
+					match store.adapter
+					{
+						#[cfg(feature="bincode")]
+						Adapters::Bincode => retrieve!(store_name, store => BincodePerson),
 
-					_ => return adapter_not_enabled(),
-				};
+						#[cfg(feature="journal")]
+						Adapters::Journal => retrieve!(store_name, store => JournalPerson),
+
+						_ => return adapter_not_enabled(store),
+					};
+				}
 			},
 		};
 
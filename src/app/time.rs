@@ -8,12 +8,13 @@ use
 
 	clinvoice_adapter::
 	{
-		Adapters, Error as AdapterError,
-		data::{Error as DataError, Updatable},
+		Adapters, Error as AdapterError, Store,
+		data::{Error as DataError, JobAdapter, Updatable},
 	},
 	clinvoice_data::
 	{
-		chrono::{Duration, DurationRound, Utc},
+		EmployeeStatus,
+		chrono::{DateTime, Duration, DurationRound, Local, Utc},
 		views::{EmployeeView, JobView, TimesheetView},
 	},
 };
@@ -21,6 +22,9 @@ use
 #[cfg(feature="bincode")]
 use clinvoice_adapter_bincode::data::{BincodeEmployee, BincodeJob, BincodeLocation, BincodeOrganization, BincodePerson};
 
+#[cfg(feature="journal")]
+use clinvoice_adapter_journal::data::{JournalEmployee, JournalJob, JournalLocation, JournalOrganization, JournalPerson};
+
 #[derive(Clone, Debug, Eq, Hash, Ord, PartialEq, PartialOrd, StructOpt)]
 #[structopt(about="Time information that was recorded with CLInvoice")]
 pub(super) struct Time
@@ -36,7 +40,11 @@ pub(super) struct Time
 pub(super) enum TimeCommand
 {
 	#[structopt(about="Start working on a `Job`")]
-	Start,
+	Start
+	{
+		#[structopt(help="Do work which the client is not billed for, e.g. an internal meeting", long, short)]
+		non_billable: bool,
+	},
 
 	#[structopt(about="Stop working on a `Job`")]
 	Stop,
@@ -44,19 +52,42 @@ pub(super) enum TimeCommand
 
 impl Time
 {
-	fn start(employee: EmployeeView, job: &mut JobView)
+	fn start(employee: EmployeeView, billable: bool, job: &mut JobView) -> input::Result<()>
 	{
+		let time_begin = Utc::now();
+
+		if employee.status_on(time_begin) == EmployeeStatus::NotEmployed
+		{
+			return Err(input::Error::NotEmployed(
+				employee.person.name,
+				DateTime::<Local>::from(time_begin).naive_local().to_string(),
+			));
+		}
+
+		// Employees bill at their default rate unless a rate is set for them on the job.
+		if let Some(rate) = employee.hourly_rate
+		{
+			job.employee_default_rates.entry(employee.id).or_insert(rate);
+		}
+
 		job.timesheets.push(TimesheetView
 		{
+			billable,
 			employee,
 			expenses: Vec::new(),
-			time_begin: Utc::now(),
+			hourly_rate: None,
+			invoice_id: None,
+			time_begin,
 			time_end: None,
 			work_notes: "* Work which was done goes here\n* Supports markdown formatting".into(),
-		})
+		});
+
+		Ok(())
 	}
 
-	fn stop<'err>(config: &Config, default: bool, job: &mut JobView) -> DynResult<'err, ()>
+	fn stop<'err, J>(config: &Config, default: bool, job: &mut JobView, store: &Store) -> DynResult<'err, ()> where
+		J : JobAdapter,
+		<J as JobAdapter>::Error : 'err,
 	{
 		let index =
 		{
@@ -81,7 +112,7 @@ impl Time
 
 		job.timesheets[index].work_notes = input::edit_markdown(&job.timesheets[index].work_notes)?;
 
-		input::util::expense::menu(&mut job.timesheets[index].expenses, config.invoices.default_currency)?;
+		input::util::expense::menu::<J>(&mut job.timesheets[index].expenses, &config.expenses, config.invoices.default_currency, store)?;
 
 		// Stop time on the `Job` AFTER requiring users to enter information. Users shouldn't enter things for free ;)
 		let interval = Duration::from_std(config.timesheets.interval)?;
@@ -110,10 +141,8 @@ impl Time
 	/// # Summary
 	///
 	/// Execute the constructed command.
-	pub(super) fn run<'err>(self, config: &Config, store_name: String) -> DynResult<'err, ()>
+	pub(super) fn run<'err>(self, config: &Config, store: &Store) -> DynResult<'err, ()>
 	{
-		let store = config.get_store(&store_name).expect("Storage name not known");
-
 		macro_rules! retrieve
 		{
 			($emp: ident, $job: ident, $loc: ident, $org: ident, $per: ident) =>
@@ -121,6 +150,7 @@ impl Time
 				let job_results_view: Vec<_> = input::util::job::retrieve_views::<&str, $emp, $job, $loc, $org, $per>(
 					"Query the `Job` which you are working on",
 					false,
+					&config.invoices.taxes,
 					store,
 				)?.into_iter().filter(|j| j.date_close.is_none()).collect();
 
@@ -128,7 +158,7 @@ impl Time
 
 				match self.command
 				{
-					TimeCommand::Start =>
+					TimeCommand::Start {non_billable} =>
 					{
 						let results_view = input::util::employee::retrieve_views::<&str, $emp, $loc, $org, $per>(
 							if self.default { Some(config.employees.default_id) } else { None },
@@ -139,10 +169,10 @@ impl Time
 
 						let selected = input::select_one(&results_view, format!("Select the `Employee` who is doing the work"))?;
 
-						Self::start(selected, &mut selected_job)
+						Self::start(selected, !non_billable, &mut selected_job)?
 					},
 
-					TimeCommand::Stop => Self::stop(config, self.default, &mut selected_job)?,
+					TimeCommand::Stop => Self::stop::<$job>(config, self.default, &mut selected_job, store)?,
 				};
 
 				$job {job: &(selected_job.into()), store}.update()?;
@@ -154,6 +184,9 @@ impl Time
 			#[cfg(feature="bincode")]
 			Adapters::Bincode => retrieve!(BincodeEmployee, BincodeJob, BincodeLocation, BincodeOrganization, BincodePerson),
 
+			#[cfg(feature="journal")]
+			Adapters::Journal => retrieve!(JournalEmployee, JournalJob, JournalLocation, JournalOrganization, JournalPerson),
+
 			_ => return Err(AdapterError::FeatureNotFound(store.adapter).into()),
 		};
 
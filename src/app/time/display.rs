@@ -10,7 +10,7 @@ impl Display for TimeCommand
 	{
 		match self
 		{
-			Self::Start => write!(formatter, "start"),
+			Self::Start {..} => write!(formatter, "start"),
 			Self::Stop => write!(formatter, "stop"),
 		}
 	}
@@ -17,6 +17,12 @@ pub enum Error
 	#[error("{0}")]
 	Data(#[from] data::Error),
 
+	/// # Summary
+	///
+	/// Some [contact information](clinvoice_data::Contact) was not valid, for the reason given.
+	#[error("Invalid contact information: {0}")]
+	InvalidContact(String),
+
 	#[error("{0}")]
 	Io(#[from] io::Error),
 
@@ -26,6 +32,13 @@ pub enum Error
 	#[error("The text was not edited")]
 	NotEdited,
 
+	/// # Summary
+	///
+	/// An [`Employee`](clinvoice_data::Employee) was selected to do work at a time when they were
+	/// not [employed](clinvoice_data::EmployeeStatus::NotEmployed).
+	#[error("{0} was not employed at {1}")]
+	NotEmployed(String, String),
+
 	#[error("{0}")]
 	Yaml(#[from] yaml::Error),
 }
@@ -1,3 +1,4 @@
+pub mod bill_to;
 pub mod contact;
 pub mod employee;
 pub mod expense;
@@ -9,8 +10,9 @@ pub mod person;
 
 /// # Summary
 ///
-/// Transforms some `$result` into a view using a `$query`. Meant to capture `Error`s and report
-/// them rather than discarding them; only non-matches are discarded.
+/// Transforms some `$result` into a view using a `$query`, and any other `$arg`s it needs to match.
+/// Meant to capture `Error`s and report them rather than discarding them; only non-matches are
+/// discarded.
 ///
 /// # Returns
 ///
@@ -22,11 +24,11 @@ pub mod person;
 #[macro_export]
 macro_rules! filter_map_view
 {
-	($query: ident, $result: ident) =>
+	($query: ident, $result: ident $(, $arg: expr)*) =>
 	{
 		match $result
 		{
-			Ok(val) => match $query.matches_view(&val)
+			Ok(val) => match $query.matches_view(&val $(, $arg)*)
 			{
 				Ok(matches) if matches => Some(Ok(val)),
 				Err(e) => Some(Err(DataError::from(e).into())),
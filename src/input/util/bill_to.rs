@@ -0,0 +1,148 @@
+use
+{
+	std::borrow::Cow::Owned,
+
+	super::{employee, location, menu},
+	crate::{DynResult, input},
+
+	clinvoice_adapter::
+	{
+		data::{EmployeeAdapter, LocationAdapter, OrganizationAdapter, PersonAdapter},
+		Store,
+	},
+	clinvoice_data::
+	{
+		BillingContact, BillTo, EmployeeStatus, Id,
+		views::{BillingContactView, BillToView},
+	},
+	clinvoice_query as query,
+};
+
+/// # Summary
+///
+/// Show a menu for choosing who [`Invoice`](clinvoice_data::Invoice)s are addressed to at the
+/// [`Organization`](clinvoice_data::Organization) with some `organization_id`, and where they are
+/// sent. The contact may be one of its [representatives](EmployeeStatus::Representative), or
+/// someone who is not recorded as an [`Employee`](clinvoice_data::Employee).
+///
+/// # Errors
+///
+/// Will error whenever [`input::select_one`], [`input::text`], [`employee::views`], or
+/// [`location::retrieve_views`] does.
+///
+/// # Panics
+///
+/// If a user manages to select a contact (e.g. `OTHER`) which is unaccounted for. This is
+/// __theoretically not possible__ but must be present to account for the case of an
+/// unrecoverable state of the program.
+pub fn menu<'err, E, L, O, P>(organization_id: Id, store: &Store) -> DynResult<'err, BillTo> where
+	E : EmployeeAdapter,
+	L : LocationAdapter,
+	O : OrganizationAdapter,
+	P : PersonAdapter,
+
+	<E as EmployeeAdapter>::Error : 'err +
+		From<<L as LocationAdapter>::Error> +
+		From<<O as OrganizationAdapter>::Error> +
+		From<<P as PersonAdapter>::Error>,
+	<L as LocationAdapter>::Error : 'err,
+	<O as OrganizationAdapter>::Error : 'err,
+	<P as PersonAdapter>::Error : 'err,
+{
+	const NONE: &str = "No one in particular";
+	const OTHER: &str = "Someone else";
+	const REPRESENTATIVE: &str = "A representative of the client";
+	const CONTACTS: [&str; 3] = [REPRESENTATIVE, OTHER, NONE];
+
+	let contact = match input::select_one(&CONTACTS, "Who should invoices be addressed to?")?
+	{
+		NONE => None,
+		OTHER => Some(BillingContact::Other
+		{
+			name: input::text(None, "What is the name of the person or department?")?,
+			email: match menu::confirm("Should invoices be sent to an email address?")?
+			{
+				true => Some(input::text(None, "What is the email address?")?),
+				_ => None,
+			},
+		}),
+		REPRESENTATIVE =>
+		{
+			let representatives = employee::views::<E, L, O, P>(
+				&query::Employee
+				{
+					organization: query::Organization {id: query::Match::EqualTo(Owned(organization_id)), ..Default::default()},
+					status: query::Match::EqualTo(Owned(EmployeeStatus::Representative)),
+					..Default::default()
+				},
+				store,
+			)?;
+
+			Some(BillingContact::Employee(input::select_one(&representatives, "Which representative should invoices be addressed to?")?.id))
+		},
+		_ => unreachable!("Unknown contact. This should not have happened, please file an issue at https://github.com/Iron-E/clinvoice/issues"),
+	};
+
+	let location_id = match menu::confirm("Should invoices be sent to a different location than the client's?")?
+	{
+		true =>
+		{
+			let locations = location::retrieve_views::<&str, L>("Query the `Location` which invoices should be sent to", true, store)?;
+			Some(input::select_one(&locations, "Which `Location` should invoices be sent to?")?.id)
+		},
+		_ => None,
+	};
+
+	Ok(BillTo {contact, location_id})
+}
+
+/// # Summary
+///
+/// Retrieve the [`Employee`](clinvoice_data::Employee) and [`Location`](clinvoice_data::Location)
+/// which some `bill_to` refers to from the specified `store`.
+///
+/// # Returns
+///
+/// * `Ok(None)` if the `bill_to` [is empty](BillTo::is_empty).
+/// * `Ok(Some(_))` otherwise, without any part which could not be found (e.g. because it was deleted).
+///
+/// # Errors
+///
+/// * If the [`employee::views`] or [`location::views`] operations fail, their error is forwarded.
+pub fn view<'err, E, L, O, P>(bill_to: &BillTo, store: &Store) -> DynResult<'err, Option<BillToView>> where
+	E : EmployeeAdapter,
+	L : LocationAdapter,
+	O : OrganizationAdapter,
+	P : PersonAdapter,
+
+	<E as EmployeeAdapter>::Error : 'err +
+		From<<L as LocationAdapter>::Error> +
+		From<<O as OrganizationAdapter>::Error> +
+		From<<P as PersonAdapter>::Error>,
+	<L as LocationAdapter>::Error : 'err,
+	<O as OrganizationAdapter>::Error : 'err,
+	<P as PersonAdapter>::Error : 'err,
+{
+	if bill_to.is_empty()
+	{
+		return Ok(None);
+	}
+
+	let contact = match &bill_to.contact
+	{
+		Some(BillingContact::Employee(id)) => employee::views::<E, L, O, P>(
+			&query::Employee {id: query::Match::EqualTo(Owned(*id)), ..Default::default()},
+			store,
+		)?.into_iter().next().map(|e| BillingContactView::Employee(e.into())),
+		Some(BillingContact::Other {email, name}) => Some(BillingContactView::Other {email: email.clone(), name: name.clone()}),
+		_ => None,
+	};
+
+	let location = match bill_to.location_id
+	{
+		Some(id) => location::views::<L>(&query::Location {id: query::Match::EqualTo(Owned(id)), ..Default::default()}, store)?.into_iter().next(),
+		_ => None,
+	};
+
+	Ok(Some(BillToView {contact, location}))
+}
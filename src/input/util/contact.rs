@@ -8,23 +8,33 @@ use
 
 	clinvoice_adapter::{data::LocationAdapter, Store},
 	clinvoice_data::views::ContactView,
+
+	email_address::EmailAddress,
+	phonenumber::{country, Mode},
+	url::Url,
 };
 
 /// # Summary
 ///
-/// Show a menu for adding [contact information](clinvoice_data::Contact).
+/// Show a menu for adding [contact information](clinvoice_data::Contact). Contact information
+/// which is not [valid](validate) is asked for again.
 ///
 /// # Errors
 ///
 /// Will error whenever [`input::select_one`] or [`input::text`] does.
-fn add_menu<'err, L>(contact_info: &mut HashMap<String, ContactView>, store: &Store) -> DynResult<'err, ()> where
+fn add_menu<'err, L>(contact_info: &mut HashMap<String, ContactView>, default_region: Option<&str>, store: &Store)
+	-> DynResult<'err, ()>
+where
 	L : LocationAdapter,
 	<L as LocationAdapter>::Error : 'err,
 {
 	const ADDRESS: &str = "Address";
 	const EMAIL: &str = "Email";
+	const FAX: &str = "Fax";
+	const MESSAGING: &str = "Messaging";
 	const PHONE: &str = "Phone";
-	const ALL_CONTACT_TYPES: [&str; 3] = [ADDRESS, EMAIL, PHONE];
+	const WEBSITE: &str = "Website";
+	const ALL_CONTACT_TYPES: [&str; 6] = [ADDRESS, EMAIL, FAX, MESSAGING, PHONE, WEBSITE];
 
 	/// # Summary
 	///
@@ -44,19 +54,23 @@ fn add_menu<'err, L>(contact_info: &mut HashMap<String, ContactView>, store: &St
 
 	/// # Summary
 	///
-	/// Collect necessary pieces of contact information and insert them into the `contact_info`.
-	macro_rules! insert
+	/// `prompt` the user for some text which is turned into a piece of contact information by
+	/// `contact`, until it is [valid](validate).
+	fn get_valid(prompt: &str, contact: impl Fn(String) -> ContactView, default_region: Option<&str>)
+		-> input::Result<ContactView>
 	{
-		($variant: ident, $var: ident) =>
+		loop
 		{
-			let label = get_label(&$var)?;
-			let export = get_export(&$var)?;
-			contact_info.insert(label, ContactView::$variant {$var, export});
-		};
+			match validate(contact(input::text(None, prompt)?), default_region)
+			{
+				Err(input::Error::InvalidContact(reason)) => eprintln!("{}", reason),
+				result => return result,
+			};
+		}
 	}
 
 	let contact_type = input::select_one(&ALL_CONTACT_TYPES, "Select which type of contact info to add")?;
-	match contact_type
+	let mut contact = match contact_type
 	{
 		ADDRESS =>
 		{
@@ -66,27 +80,60 @@ fn add_menu<'err, L>(contact_info: &mut HashMap<String, ContactView>, store: &St
 				store,
 			)?;
 
-			let location = input::select_one(&locations, "Select the location to add")?;
-			insert!(Address, location);
-		}
+			ContactView::Address {location: input::select_one(&locations, "Select the location to add")?, export: false}
+		},
 
-		EMAIL =>
-		{
-			let email = input::text(None, "Enter an email address (e.g. `foo@gmail.com`)")?;
-			insert!(Email, email);
-		}
+		EMAIL => get_valid(
+			"Enter an email address (e.g. `foo@gmail.com`)",
+			|email| ContactView::Email {email, export: false},
+			default_region,
+		)?,
+
+		FAX => get_valid(
+			"Enter a fax number (e.g. `+1 201-555-0123`)",
+			|fax| ContactView::Fax {fax, export: false},
+			default_region,
+		)?,
 
-		PHONE =>
+		MESSAGING =>
 		{
-			let phone = input::text(None, "Enter a phone number (e.g. `600-555-5555`)")?;
-			insert!(Phone, phone);
-		}
+			let service: String = input::text(None, "Enter the messaging service (e.g. `Signal`)")?;
+			let handle = input::text(None, format!("Enter the handle on {}", service))?;
+			ContactView::Messaging {handle, service, export: false}
+		},
+
+		PHONE => get_valid(
+			"Enter a phone number (e.g. `+1 201-555-0123`)",
+			|phone| ContactView::Phone {phone, export: false},
+			default_region,
+		)?,
+
+		WEBSITE => get_valid(
+			"Enter a website (e.g. `https://foo.io`)",
+			|url| ContactView::Website {url, export: false},
+			default_region,
+		)?,
 
 		_ => unreachable!("Unkown contact type. This should not have happened, please file an issue at https://github.com/Iron-E/clinvoice/issues"),
 	};
 
+	let label = get_label(&contact)?;
+	let export_contact = get_export(&contact)?;
+	match &mut contact
+	{
+		ContactView::Address {export, ..} |
+		ContactView::Email {export, ..} |
+		ContactView::Fax {export, ..} |
+		ContactView::Messaging {export, ..} |
+		ContactView::Phone {export, ..} |
+		ContactView::Website {export, ..} => *export = export_contact,
+	};
+
+	contact_info.insert(label, contact);
+
 	Ok(())
 }
+
 /// # Summary
 ///
 /// Show a menu for deleting [contact information](clinvoice_data::Contact).
@@ -111,13 +158,14 @@ fn delete_menu(contact_info: &mut HashMap<String, ContactView>) -> input::Result
 
 /// # Summary
 ///
-/// Show a menu for editing [contact information](clinvoice_data::Contact).
+/// Show a menu for editing [contact information](clinvoice_data::Contact). Edits which are not
+/// [valid](validate) are discarded.
 ///
 /// # Errors
 ///
 /// Will error whenever [`input::edit_and_restore`] and [`input::select_one`] does,
-/// but will ignore [`input::Error::NotEdited`].
-fn edit_menu(contact_info: &mut HashMap<String, ContactView>) -> input::Result<()>
+/// but will ignore [`input::Error::NotEdited`] and [`input::Error::InvalidContact`].
+fn edit_menu(contact_info: &mut HashMap<String, ContactView>, default_region: Option<&str>) -> input::Result<()>
 {
 	if contact_info.is_empty() { return Ok(()); }
 
@@ -134,14 +182,20 @@ fn edit_menu(contact_info: &mut HashMap<String, ContactView>) -> input::Result<(
 		return Ok(());
 	}
 
-	// We allow users to edit email addresses and phone numebrs during this process, but not addresses.
+	// We allow users to edit email addresses, phone numbers, etc. during this process, but not addresses.
 	// Users can only ever relabel an address, thus we have to gate addresses for below.
-	if matches!(contact_info[&selected_key], ContactView::Email {email: _, export: _} | ContactView::Phone {phone: _, export: _})
+	if !matches!(contact_info[&selected_key], ContactView::Address {location: _, export: _})
 	{
 		match input::edit_and_restore(&contact_info[&selected_key], format!("Please edit the {}", selected_key))
+			.and_then(|edit| validate(edit, default_region))
 		{
 			Ok(edit) => contact_info.insert(typed_key, edit),
 			Err(input::Error::NotEdited) => None,
+			Err(input::Error::InvalidContact(reason)) =>
+			{
+				eprintln!("{}", reason);
+				return Ok(());
+			},
 			Err(e) => return Err(e),
 		};
 	}
@@ -163,7 +217,8 @@ fn edit_menu(contact_info: &mut HashMap<String, ContactView>) -> input::Result<(
 
 /// # Summary
 ///
-/// Show a menu for creating [contact information](clinvoice_data::Contact).
+/// Show a menu for creating [contact information](clinvoice_data::Contact). Phone and fax
+/// numbers without a country calling code are assumed to be in the `default_region`.
 ///
 /// # Errors
 ///
@@ -174,7 +229,7 @@ fn edit_menu(contact_info: &mut HashMap<String, ContactView>) -> input::Result<(
 /// If a user manages to select an action (e.g. `ADD`, `CONTINUE`, `DELETE`) which is unaccounted
 /// for. This is __theoretically not possible__ but must be present to account for the case of an
 /// unrecoverable state of the program.
-pub fn menu<'err, L>(store: &Store) -> DynResult<'err, HashMap<String, ContactView>> where
+pub fn menu<'err, L>(default_region: Option<&str>, store: &Store) -> DynResult<'err, HashMap<String, ContactView>> where
 	L : LocationAdapter,
 	<L as LocationAdapter>::Error : 'err,
 {
@@ -185,13 +240,123 @@ pub fn menu<'err, L>(store: &Store) -> DynResult<'err, HashMap<String, ContactVi
 		let action = input::select_one(&menu::ALL_ACTIONS, "\nThis is the menu for creating contact information\nWhat would you like to do?")?;
 		match action
 		{
-			menu::ADD => add_menu::<L>(&mut contact_info, store)?,
+			menu::ADD => add_menu::<L>(&mut contact_info, default_region, store)?,
 			menu::CONTINUE => break,
 			menu::DELETE => delete_menu(&mut contact_info)?,
-			menu::EDIT => edit_menu(&mut contact_info)?,
+			menu::EDIT => edit_menu(&mut contact_info, default_region)?,
 			_ => unreachable!("Unknown action. This should not have happened, please file an issue at https://github.com/Iron-E/clinvoice/issues"),
 		};
 	}
 
 	Ok(contact_info)
 }
+
+/// # Summary
+///
+/// Format some phone `number` as E.164, assuming that it is in the `default_region` if it has no
+/// country calling code.
+///
+/// # Errors
+///
+/// An [`input::Error::InvalidContact`] if the `number` is not valid, or the `default_region` is
+/// not an ISO 3166-1 alpha-2 code.
+fn normalize_phone(number: &str, default_region: Option<&str>) -> input::Result<String>
+{
+	let region = default_region.map(|region| region.to_uppercase().parse::<country::Id>().map_err(|_|
+		input::Error::InvalidContact(format!("\"{}\" is not a country code, so it cannot be the default region", region))
+	)).transpose()?;
+
+	match phonenumber::parse(region, number)
+	{
+		Ok(parsed) if parsed.is_valid() => Ok(parsed.format().mode(Mode::E164).to_string()),
+		Ok(_) => Err(input::Error::InvalidContact(format!("\"{}\" is not a valid phone number", number))),
+		Err(e) => Err(input::Error::InvalidContact(format!("\"{}\" is not a valid phone number: {}", number, e))),
+	}
+}
+
+/// # Summary
+///
+/// Check that some `contact` is valid, and normalize it:
+///
+/// * email addresses must be RFC 5322 compliant,
+/// * phone and fax numbers are formatted as E.164, assuming that they are in the `default_region`
+///   if they have no country calling code, and
+/// * websites must be `http` or `https` URLs.
+///
+/// # Errors
+///
+/// An [`input::Error::InvalidContact`] which explains why the `contact` is not valid.
+pub fn validate(contact: ContactView, default_region: Option<&str>) -> input::Result<ContactView>
+{
+	Ok(match contact
+	{
+		ContactView::Email {email, export} => match EmailAddress::is_valid(email.trim())
+		{
+			true => ContactView::Email {email: email.trim().into(), export},
+			_ => return Err(input::Error::InvalidContact(format!("\"{}\" is not a valid email address", email))),
+		},
+		ContactView::Fax {fax, export} => ContactView::Fax {fax: normalize_phone(&fax, default_region)?, export},
+		ContactView::Phone {phone, export} => ContactView::Phone {phone: normalize_phone(&phone, default_region)?, export},
+		ContactView::Website {url, export} => match Url::parse(url.trim())
+		{
+			// The URL is kept as it was written, since `Url` would add a trailing `/` to it.
+			Ok(parsed) if matches!(parsed.scheme(), "http" | "https") && parsed.has_host() => ContactView::Website {url: url.trim().into(), export},
+			_ => return Err(input::Error::InvalidContact(format!("\"{}\" is not a valid website", url))),
+		},
+		contact => contact,
+	})
+}
+
+#[cfg(test)]
+mod tests
+{
+	use
+	{
+		std::time::Instant,
+
+		super::{ContactView, input},
+	};
+
+	#[test]
+	fn normalize_phone()
+	{
+		let start = Instant::now();
+		assert_eq!(super::normalize_phone("+1 201-555-0123", None).unwrap(), "+12015550123");
+		assert_eq!(super::normalize_phone("(201) 555-0123", Some("us")).unwrap(), "+12015550123");
+		assert!(matches!(super::normalize_phone("(201) 555-0123", None), Err(input::Error::InvalidContact(_))));
+		assert!(matches!(super::normalize_phone("+1 201-555-0123", Some("Not a Region")), Err(input::Error::InvalidContact(_))));
+		assert!(matches!(super::normalize_phone("+1 555-0123", None), Err(input::Error::InvalidContact(_))));
+		println!("\n>>>>> contact::normalize_phone {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / 5);
+	}
+
+	#[test]
+	fn validate()
+	{
+		let start = Instant::now();
+		assert_eq!(
+			super::validate(ContactView::Email {email: " foo@bar.io ".into(), export: true}, None).unwrap(),
+			ContactView::Email {email: "foo@bar.io".into(), export: true},
+		);
+		assert!(matches!(
+			super::validate(ContactView::Email {email: "foo@".into(), export: false}, None),
+			Err(input::Error::InvalidContact(_)),
+		));
+		assert_eq!(
+			super::validate(ContactView::Fax {fax: "201-555-0123".into(), export: false}, Some("US")).unwrap(),
+			ContactView::Fax {fax: "+12015550123".into(), export: false},
+		);
+		assert_eq!(
+			super::validate(ContactView::Phone {phone: "+1 201-555-0123".into(), export: false}, None).unwrap(),
+			ContactView::Phone {phone: "+12015550123".into(), export: false},
+		);
+		assert_eq!(
+			super::validate(ContactView::Website {url: "https://foo.io".into(), export: false}, None).unwrap(),
+			ContactView::Website {url: "https://foo.io".into(), export: false},
+		);
+		assert!(matches!(
+			super::validate(ContactView::Website {url: "ftp://foo.io".into(), export: false}, None),
+			Err(input::Error::InvalidContact(_)),
+		));
+		println!("\n>>>>> contact::validate {}us <<<<<\n", Instant::now().duration_since(start).as_micros() / 6);
+	}
+}
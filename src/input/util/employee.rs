@@ -55,15 +55,43 @@ where
 		_ => input::edit_default(format!("{}\n{}employees", prompt, QUERY_PROMPT))?,
 	};
 
-	let results = E::retrieve(&query, &store)?;
-	let results_view: Result<Vec<_>, _> = results.into_iter().map(|e|
-		E::into_view::<L, O, P>(e, &store)
-	).filter_map(|result| filter_map_view!(query, result)).collect();
+	let results_view = views::<E, L, O, P>(&query, store);
 
 	if retry_on_empty && results_view.as_ref().map(|r| r.is_empty()).unwrap_or(false) && menu::retry_query()?
 	{
 		return retrieve_views::<D, E, L, O, P>(default_id, prompt, true, store);
 	}
 
+	results_view
+}
+
+/// # Summary
+///
+/// Retrieve every [`EmployeeView`] which matches some `query` from the specified `store`.
+///
+/// # Errors
+///
+/// * If the [retrieval][retrieve] operation fails, its error is forwarded.
+///
+/// [retrieve]: clinvoice_adapter::data::EmployeeAdapter::retrieve
+pub fn views<'err, E, L, O, P>(query: &query::Employee, store: &Store) -> DynResult<'err, Vec<EmployeeView>> where
+	E : EmployeeAdapter,
+	L : LocationAdapter,
+	O : OrganizationAdapter,
+	P : PersonAdapter,
+
+	<E as EmployeeAdapter>::Error : 'err +
+		From<<L as LocationAdapter>::Error> +
+		From<<O as OrganizationAdapter>::Error> +
+		From<<P as PersonAdapter>::Error>,
+	<L as LocationAdapter>::Error : 'err,
+	<O as OrganizationAdapter>::Error : 'err,
+	<P as PersonAdapter>::Error : 'err,
+{
+	let results = E::retrieve(query, store)?;
+	let results_view: Result<Vec<_>, _> = results.into_iter().map(|e|
+		E::into_view::<L, O, P>(e, store)
+	).filter_map(|result| filter_map_view!(query, result)).collect();
+
 	results_view.map_err(|e| e.into())
 }
@@ -1,63 +1,134 @@
 use
 {
-	super::menu::{ADD, ALL_ACTIONS, CONTINUE, DELETE, EDIT},
-	crate::input,
+	std::{collections::BTreeMap, path::Path},
 
+	super::menu::{ADD, CONTINUE, DELETE, EDIT},
+	crate::{DynResult, input},
+
+	clinvoice_adapter::{data::JobAdapter, Store},
+	clinvoice_config::Expenses,
 	clinvoice_data::
 	{
 		finance::{Currency, Money},
-		Expense, ExpenseCategory,
+		Expense, ExpenseKind,
 	},
 };
 
 /// # Summary
 ///
-/// Show a menu for adding [contact information](clinvoice_data::Contact).
+/// Show a menu for adding [contact information](clinvoice_data::Contact), in one of the
+/// configured `categories`. When there are mileage or per diem rates configured, the cost may be
+/// calculated from them instead of being entered directly.
+///
+/// # Errors
+///
+/// Will error whenever [`input::select_one`], [`input::text`], or [`select_rate`] does.
+fn add_menu(expenses: &mut Vec<Expense>, config: &Expenses, default_currency: Currency) -> input::Result<()>
+{
+	const COST: &str = "Enter the cost";
+	const MILEAGE: &str = "Calculate the cost from a distance travelled";
+	const PER_DIEM: &str = "Calculate the cost from a number of days";
+
+	let category = input::select_one(&config.categories, "Select which type of `Expense` to add")?;
+
+	let mut methods = vec![COST];
+	if !config.mileage_rates.is_empty() { methods.push(MILEAGE); }
+	if !config.per_diem_rates.is_empty() { methods.push(PER_DIEM); }
+
+	let method = match methods.len()
+	{
+		1 => COST,
+		_ => input::select_one(&methods, format!("How is the cost of the {} calculated?", category.name))?,
+	};
+
+	let kind = match method
+	{
+		MILEAGE => ExpenseKind::Mileage
+		{
+			rate: select_rate(&config.mileage_rates, "Select a mileage rate")?,
+			distance: input::text(None, format!("How many {} were travelled?", config.distance_unit))?,
+			unit: config.distance_unit,
+		},
+		PER_DIEM => ExpenseKind::PerDiem
+		{
+			rate: select_rate(&config.per_diem_rates, "Select a per diem rate")?,
+			days: input::text(None, "How many days were spent travelling?")?,
+		},
+		_ => ExpenseKind::Cost,
+	};
+
+	let cost = match kind.cost()
+	{
+		Some(cost) => cost,
+		None => input::edit(&Money::new(20_00, 2, default_currency), format!("What is the cost of the {}?", category.name))?,
+	};
+
+	let description = input::edit_markdown(&format!("* Describe the {}\n* All markdown syntax is valid", category.name))?;
+	expenses.push(Expense {attachments: Vec::new(), billable: true, category, cost, description, kind});
+
+	Ok(())
+}
+
+/// # Summary
+///
+/// Show a menu for attaching a file (e.g. a receipt) to one of some `expenses`. The file is copied
+/// into the `store`.
 ///
 /// # Errors
 ///
-/// Will error whenever [`input::select_one`] or [`input::text`] does.
-fn add_menu(expenses: &mut Vec<Expense>, default_currency: Currency) -> input::Result<()>
+/// Will error whenever [`input::select_one`], [`input::text`], or [`JobAdapter::attach`] does.
+fn attach_menu<'err, J>(expenses: &mut [Expense], store: &Store) -> DynResult<'err, ()> where
+	J : JobAdapter,
+	<J as JobAdapter>::Error : 'err,
 {
-	const ALL_EXPENSE_CATEGORIES: [ExpenseCategory; 6] =
-	[
-		ExpenseCategory::Food,
-		ExpenseCategory::Item,
-		ExpenseCategory::Other,
-		ExpenseCategory::Service,
-		ExpenseCategory::Software,
-		ExpenseCategory::Travel,
-	];
-
-	let category = input::select_one(&ALL_EXPENSE_CATEGORIES, "Select which type of `Expense` to add")?;
-	let cost = input::edit(&Money::new(20_00, 2, default_currency), format!("What is the cost of the {}?", category))?;
-	let description = input::edit_markdown(&format!("* Describe the {}\n* All markdown syntax is valid", category))?;
-	expenses.push(Expense {category, cost, description});
+	if !expenses.is_empty()
+	{
+		let attach_to = input::select_one(expenses, "Select an expense to attach a file to")?;
+		let path: String = input::text(None, "Enter the path of the file to attach (e.g. `/home/user/receipt.pdf`)")?;
+		let attachment = J::attach(Path::new(&path), store)?;
+
+		let attach_index = expenses.iter().position(|e| *e == attach_to).unwrap_or_default();
+		if !expenses[attach_index].attachments.contains(&attachment)
+		{
+			expenses[attach_index].attachments.push(attachment);
+		}
+	}
 
 	Ok(())
 }
 
 /// # Summary
 ///
-/// Show a menu for creating [contact information](clinvoice_data::Contact).
+/// Show a menu for creating [contact information](clinvoice_data::Contact). New expenses are
+/// entered according to the `config`, and files which are attached to them are copied into the
+/// `store`.
 ///
 /// # Errors
 ///
-/// Will error whenever [`input::select_one`], [`add_menu`], [`delete_menu`], or [`edit_menu`] does.
+/// Will error whenever [`input::select_one`], [`add_menu`], [`attach_menu`], [`delete_menu`], or
+/// [`edit_menu`] does.
 ///
 /// # Panics
 ///
 /// If a user manages to select an action (e.g. `ADD`, `CONTINUE`, `DELETE`) which is unaccounted
 /// for. This is __theoretically not possible__ but must be present to account for the case of an
 /// unrecoverable state of the program.
-pub fn menu(expenses: &mut Vec<Expense>, default_currency: Currency) -> input::Result<()>
+pub fn menu<'err, J>(expenses: &mut Vec<Expense>, config: &Expenses, default_currency: Currency, store: &Store)
+	-> DynResult<'err, ()>
+where
+	J : JobAdapter,
+	<J as JobAdapter>::Error : 'err,
 {
+	const ATTACH: &str = "Attach";
+	const ALL_ACTIONS: [&str; 5] = [ADD, ATTACH, CONTINUE, DELETE, EDIT];
+
 	loop
 	{
 		let action = input::select_one(&ALL_ACTIONS, "\nThis is the menu for entering expenses\nWhat would you like to do?")?;
 		match action
 		{
-			ADD => add_menu(expenses, default_currency)?,
+			ADD => add_menu(expenses, config, default_currency)?,
+			ATTACH => attach_menu::<J>(expenses, store)?,
 			CONTINUE => return Ok(()),
 			DELETE => delete_menu(expenses)?,
 			EDIT => edit_menu(expenses)?,
@@ -77,7 +148,7 @@ fn delete_menu(expenses: &mut Vec<Expense>) -> input::Result<()>
 {
 	if !expenses.is_empty()
 	{
-		let remove = input::select_one(&expenses, "Select an expense to remove")?;
+		let remove = input::select_one(expenses, "Select an expense to remove")?;
 
 		expenses.remove(expenses.iter().enumerate().fold(0, |i, enumeration|
 			if &remove == enumeration.1 { enumeration.0 }
@@ -96,20 +167,24 @@ fn delete_menu(expenses: &mut Vec<Expense>) -> input::Result<()>
 ///
 /// Will error whenever [`input::edit_and_restore`] and [`input::select_one`] does,
 /// but will ignore [`input::Error::NotEdited`].
-fn edit_menu(expenses: &mut Vec<Expense>) -> input::Result<()>
+fn edit_menu(expenses: &mut [Expense]) -> input::Result<()>
 {
 	if !expenses.is_empty()
 	{
-		let edit = input::select_one(&expenses, "Select an expense to edit")?;
+		let edit = input::select_one(expenses, "Select an expense to edit")?;
 
 		let edit_index = expenses.iter().enumerate().fold(0, |i, enumeration|
 			if &edit == enumeration.1 { enumeration.0 }
 			else { i }
 		);
 
-		match input::edit(&edit, format!("Add any changes desired to the {}", edit.category))
+		match input::edit(&edit, format!("Add any changes desired to the {}", edit.category.name))
 		{
-			Ok(edited) => { expenses[edit_index] = edited; }
+			Ok(mut edited) =>
+			{
+				if let Some(cost) = edited.kind.cost() { edited.cost = cost; }
+				expenses[edit_index] = edited;
+			},
 			Err(input::Error::NotEdited) => (),
 			Err(e) => return Err(e),
 		};
@@ -117,3 +192,23 @@ fn edit_menu(expenses: &mut Vec<Expense>) -> input::Result<()>
 
 	Ok(())
 }
+
+/// # Summary
+///
+/// `prompt` the user to select one of some named `rates`, unless there is only one to choose
+/// from.
+///
+/// # Errors
+///
+/// Will error whenever [`input::select_one`] does.
+fn select_rate(rates: &BTreeMap<String, Money>, prompt: &str) -> input::Result<Money>
+{
+	if rates.len() == 1
+	{
+		return Ok(rates.values().copied().next().unwrap());
+	}
+
+	let names: Vec<_> = rates.iter().map(|(name, rate)| format!("{} ({})", name, rate)).collect();
+	let selected = input::select_one(&names, prompt)?;
+	Ok(rates.values().zip(&names).find(|(_, name)| **name == selected).map(|(rate, _)| *rate).unwrap())
+}
@@ -10,7 +10,7 @@ use
 		data::{Error as DataError, EmployeeAdapter, JobAdapter, LocationAdapter, OrganizationAdapter, PersonAdapter},
 		Store,
 	},
-	clinvoice_data::views::JobView,
+	clinvoice_data::{Taxes, views::JobView},
 	clinvoice_query as query,
 };
 
@@ -27,7 +27,9 @@ use
 ///
 /// [L_retrieve]: clinvoice_adapter::data::LocationAdapter::retrieve
 /// [location]: clinvoice_data::Location
-pub fn retrieve_views<'err, D, E, J, L, O, P>(prompt: D, retry_on_empty: bool, store: &Store) -> DynResult<'err, Vec<JobView>> where
+pub fn retrieve_views<'err, D, E, J, L, O, P>(prompt: D, retry_on_empty: bool, taxes: &Taxes, store: &Store)
+	-> DynResult<'err, Vec<JobView>>
+where
 	D : Display,
 	E : EmployeeAdapter,
 	J : JobAdapter,
@@ -43,15 +45,47 @@ pub fn retrieve_views<'err, D, E, J, L, O, P>(prompt: D, retry_on_empty: bool, s
 {
 	let query: query::Job = input::edit_default(format!("{}\n{}jobs", prompt, QUERY_PROMPT))?;
 
-	let results = J::retrieve(&query, &store)?;
-	let results_view: Result<Vec<_>, _> =results.into_iter().map(|j|
-		J::into_view::<E, L, O, P>(j, &store)
-	).filter_map(|result| filter_map_view!(query, result)).collect();
+	let results_view = views::<E, J, L, O, P>(&query, taxes, store);
 
 	if retry_on_empty && results_view.as_ref().map(|r| r.is_empty()).unwrap_or(false) && menu::retry_query()?
 	{
-		return retrieve_views::<D, E, J, L, O, P>(prompt, true, store);
+		return retrieve_views::<D, E, J, L, O, P>(prompt, true, taxes, store);
 	}
 
+	results_view
+}
+
+/// # Summary
+///
+/// Retrieve every [`JobView`] which matches some `query` from the specified `store`.
+///
+/// # Parameters
+///
+/// * `taxes`, the taxes which apply to a [`JobView`] when neither its invoices nor its client
+///   specify any.
+///
+/// # Errors
+///
+/// * If the [retrieval][retrieve] operation fails, its error is forwarded.
+///
+/// [retrieve]: clinvoice_adapter::data::JobAdapter::retrieve
+pub fn views<'err, E, J, L, O, P>(query: &query::Job, taxes: &Taxes, store: &Store) -> DynResult<'err, Vec<JobView>> where
+	E : EmployeeAdapter,
+	J : JobAdapter,
+	L : LocationAdapter,
+	O : OrganizationAdapter,
+	P : PersonAdapter,
+
+	<E as EmployeeAdapter>::Error :
+		From<<L as LocationAdapter>::Error> +
+		From<<O as OrganizationAdapter>::Error> +
+		From<<P as PersonAdapter>::Error>,
+	<J as JobAdapter>::Error : 'err + From<<E as EmployeeAdapter>::Error>,
+{
+	let results = J::retrieve(query, store)?;
+	let results_view: Result<Vec<_>, _> =results.into_iter().map(|j|
+		J::into_view::<E, L, O, P>(j, store)
+	).filter_map(|result| filter_map_view!(query, result, taxes)).collect();
+
 	results_view.map_err(|e| e.into())
 }
@@ -35,15 +35,34 @@ pub fn retrieve_views<'err, D, L>(prompt: D, retry_on_empty: bool, store: &Store
 {
 	let query: query::Location = input::edit_default(format!("{}\n{}locations", prompt, QUERY_PROMPT))?;
 
-	let results = L::retrieve(&query, &store)?;
-	let results_view: Result<Vec<_>, _> =results.into_iter().map(|l|
-		L::into_view(l, &store)
-	).filter_map(|result| filter_map_view!(query, result)).collect();
+	let results_view = views::<L>(&query, store);
 
 	if retry_on_empty && results_view.as_ref().map(|r| r.is_empty()).unwrap_or(false) && menu::retry_query()?
 	{
 		return retrieve_views::<D, L>(prompt, true, store);
 	}
 
+	results_view
+}
+
+/// # Summary
+///
+/// Retrieve every [`LocationView`] which matches some `query` from the specified `store`.
+///
+/// # Errors
+///
+/// * If the [retrieval][retrieve] operation fails, its error is forwarded.
+///
+/// [retrieve]: clinvoice_adapter::data::LocationAdapter::retrieve
+pub fn views<'err, L>(query: &query::Location, store: &Store) -> DynResult<'err, Vec<LocationView>> where
+	L : LocationAdapter,
+
+	<L as LocationAdapter>::Error : 'err,
+{
+	let results = L::retrieve(query, store)?;
+	let results_view: Result<Vec<_>, _> =results.into_iter().map(|l|
+		L::into_view(l, store)
+	).filter_map(|result| filter_map_view!(query, result)).collect();
+
 	results_view.map_err(|e| e.into())
 }
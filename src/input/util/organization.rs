@@ -37,15 +37,36 @@ pub fn retrieve_views<'err, D, L, O>(prompt: D, retry_on_empty: bool, store: &St
 {
 	let query: query::Organization = input::edit_default(format!("{}\n{}organizations", prompt, QUERY_PROMPT))?;
 
-	let results = O::retrieve(&query, &store)?;
-	let results_view: Result<Vec<_>, _> = results.into_iter().map(|o| O::into_view::<L>(o, &store)).filter_map(|result|
-		filter_map_view!(query, result)
-	).collect();
+	let results_view = views::<L, O>(&query, store);
 
 	if retry_on_empty && results_view.as_ref().map(|r| r.is_empty()).unwrap_or(false) && menu::retry_query()?
 	{
 		return retrieve_views::<D, L, O>(prompt, true, store);
 	}
 
+	results_view
+}
+
+/// # Summary
+///
+/// Retrieve every [`OrganizationView`] which matches some `query` from the specified `store`.
+///
+/// # Errors
+///
+/// * If the [retrieval][retrieve] operation fails, its error is forwarded.
+///
+/// [retrieve]: clinvoice_adapter::data::OrganizationAdapter::retrieve
+pub fn views<'err, L, O>(query: &query::Organization, store: &Store) -> DynResult<'err, Vec<OrganizationView>> where
+	L : LocationAdapter,
+	O : OrganizationAdapter,
+
+	<L as LocationAdapter>::Error : 'err,
+	<O as OrganizationAdapter>::Error : 'err,
+{
+	let results = O::retrieve(query, store)?;
+	let results_view: Result<Vec<_>, _> = results.into_iter().map(|o| O::into_view::<L>(o, store)).filter_map(|result|
+		filter_map_view!(query, result)
+	).collect();
+
 	results_view.map_err(|e| e.into())
 }
@@ -31,7 +31,31 @@ pub fn retrieve_views<'err, D, P>(prompt: D, retry_on_empty: bool, store: &Store
 {
 	let query: query::Person = input::edit_default(format!("{}\n{}persons", prompt, QUERY_PROMPT))?;
 
-	let results = P::retrieve(&query, &store)?;
+	let results_view = views::<P>(&query, store);
+
+	if retry_on_empty && results_view.as_ref().map(|r| r.is_empty()).unwrap_or(false) && menu::retry_query()?
+	{
+		return retrieve_views::<D, P>(prompt, true, store);
+	}
+
+	results_view
+}
+
+/// # Summary
+///
+/// Retrieve every [`PersonView`] which matches some `query` from the specified `store`.
+///
+/// # Errors
+///
+/// * If the [retrieval][retrieve] operation fails, its error is forwarded.
+///
+/// [retrieve]: clinvoice_adapter::data::PersonAdapter::retrieve
+pub fn views<'err, P>(query: &query::Person, store: &Store) -> DynResult<'err, Vec<PersonView>> where
+	P : PersonAdapter,
+
+	<P as PersonAdapter>::Error : 'err,
+{
+	let results = P::retrieve(query, store)?;
 	let results_view: Result<Vec<_>, _> = results.into_iter().map(PersonView::from).filter_map(|view| match query.matches_view(&view)
 	{
 		Ok(b) if b => Some(Ok(view)),
@@ -39,10 +63,5 @@ pub fn retrieve_views<'err, D, P>(prompt: D, retry_on_empty: bool, store: &Store
 		_ => None,
 	}).collect();
 
-	if retry_on_empty && results_view.as_ref().map(|r| r.is_empty()).unwrap_or(false) && menu::retry_query()?
-	{
-		return retrieve_views::<D, P>(prompt, true, store);
-	}
-
 	results_view.map_err(|e| e.into())
 }
@@ -1,65 +0,0 @@
-mod util;
-
-use
-{
-	std::time::Instant,
-
-	clinvoice_adapter::data::LocationAdapter,
-	clinvoice_adapter_bincode::data::BincodeLocation,
-	clinvoice_data::views::LocationView,
-};
-
-#[test]
-fn to_view()
-{
-	util::temp_store(|store|
-	{
-		let earth = BincodeLocation::create("Earth".into(), &store).unwrap();
-
-		let usa = BincodeLocation
-		{
-			location: &earth,
-			store,
-		}.create_inner("USA".into()).unwrap();
-
-		let arizona = BincodeLocation
-		{
-			location: &usa,
-			store,
-		}.create_inner("Arizona".into()).unwrap();
-
-		let phoenix = BincodeLocation
-		{
-			location: &arizona,
-			store,
-		}.create_inner("Phoenix".into()).unwrap();
-
-		let phoenix_view = LocationView
-		{
-			id: phoenix.id,
-			name: phoenix.name.clone(),
-			outer: Some(LocationView
-			{
-				id: arizona.id,
-				name: arizona.name,
-				outer: Some(LocationView
-				{
-					id: usa.id,
-					name: usa.name,
-					outer: Some(LocationView
-					{
-						id: earth.id,
-						name: earth.name,
-						outer: None,
-					}.into()),
-				}.into()),
-			}.into()),
-		};
-
-		let start = Instant::now();
-		let phoenix_view_result = BincodeLocation::into_view(phoenix, store);
-		println!("\n>>>>> BincodeLocation::to_view {}us <<<<<\n", Instant::now().duration_since(start).as_micros());
-
-		assert_eq!(phoenix_view, phoenix_view_result.unwrap());
-	});
-}